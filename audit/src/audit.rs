@@ -0,0 +1,172 @@
+use crate::simulation::{extreme_seeds, SeedGenerator, TriggerSimulator};
+use crate::statistics::{chi_square_uniform, ChiSquare};
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+pub const DEFAULT_NB_DRAWS: usize = 10_000;
+pub const DEFAULT_SEED: u64 = 0x5052_495A_45;
+pub const DEFAULT_SIGNIFICANCE: f64 = 0.01;
+pub const DEFAULT_PLAYER_COUNTS: [usize; 12] = [1, 2, 3, 4, 5, 7, 10, 13, 16, 32, 64, 100];
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditConfig {
+    // Numbers of players of the simulated instances, each one being drawn nb_draws times
+    pub player_counts: Vec<usize>,
+    pub nb_draws: usize,
+    // Seed of the block random seed generator
+    pub seed: u64,
+    // Family-wise significance level, split between the player counts (Bonferroni correction)
+    pub significance: f64,
+}
+
+// Empirical distribution of the winning tickets for a number of players
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub nb_players: usize,
+    // Number of wins of each ticket, ticket N at index N - 1
+    pub ticket_counts: Vec<u64>,
+    // Draws returning a ticket outside 1..=nb_players
+    pub nb_out_of_range: u64,
+    // Draws for which the trigger failed
+    pub nb_failed: u64,
+    pub chi_square: ChiSquare,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeCase {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditReport {
+    pub config: AuditConfig,
+    pub distributions: Vec<Distribution>,
+    pub edge_cases: Vec<EdgeCase>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl Default for AuditConfig {
+    fn default() -> Self {
+        return AuditConfig {
+            player_counts: DEFAULT_PLAYER_COUNTS.to_vec(),
+            nb_draws: DEFAULT_NB_DRAWS,
+            seed: DEFAULT_SEED,
+            significance: DEFAULT_SIGNIFICANCE,
+        };
+    }
+}
+
+impl AuditConfig {
+    // Significance level applied to each chi-square test
+    pub fn test_significance(&self) -> f64 {
+        return self.significance / self.player_counts.len().max(1) as f64;
+    }
+}
+
+impl Distribution {
+    pub fn nb_draws(&self) -> u64 {
+        return self.ticket_counts.iter().sum::<u64>() + self.nb_out_of_range + self.nb_failed;
+    }
+}
+
+impl AuditReport {
+    pub fn passed(&self) -> bool {
+        return self.distributions.iter().all(|distribution| distribution.passed == true) && self.edge_cases.iter().all(|edge_case| edge_case.passed == true);
+    }
+
+    pub fn distribution(&self, nb_players: usize) -> Option<&Distribution> {
+        return self.distributions.iter().find(|distribution| distribution.nb_players == nb_players);
+    }
+}
+
+// Runs the trigger of every simulated instance with the same sequence of block random seeds
+pub fn run_audit(config: &AuditConfig) -> AuditReport {
+    assert!(config.player_counts.iter().all(|nb_players| *nb_players > 0), "Instances without player are won by their sponsor, nothing to draw");
+
+    let mut simulator = TriggerSimulator::new(&config.player_counts);
+    let mut distributions: Vec<Distribution> = Vec::new();
+
+    for nb_players in simulator.player_counts() {
+        let mut seed_generator = SeedGenerator::new(config.seed);
+        let mut ticket_counts: Vec<u64> = vec![0; nb_players];
+        let mut nb_out_of_range: u64 = 0;
+        let mut nb_failed: u64 = 0;
+
+        for _ in 0..config.nb_draws {
+            match simulator.draw(nb_players, &seed_generator.next_seed()) {
+                Some(ticket) if (1..=nb_players).contains(&ticket) => ticket_counts[ticket - 1] += 1,
+                Some(_) => nb_out_of_range += 1,
+                None => nb_failed += 1,
+            }
+        }
+
+        let chi_square = chi_square_uniform(&ticket_counts);
+        let passed: bool = nb_out_of_range == 0 && nb_failed == 0 && chi_square.p_value >= config.test_significance();
+
+        distributions.push(Distribution {
+            nb_players: nb_players,
+            ticket_counts: ticket_counts,
+            nb_out_of_range: nb_out_of_range,
+            nb_failed: nb_failed,
+            chi_square: chi_square,
+            passed: passed,
+        });
+    }
+
+    let edge_cases = check_edge_cases(&mut simulator, &distributions);
+
+    return AuditReport { config: config.clone(), distributions: distributions, edge_cases: edge_cases };
+}
+
+fn check_edge_cases(simulator: &mut TriggerSimulator, distributions: &[Distribution]) -> Vec<EdgeCase> {
+    let mut edge_cases: Vec<EdgeCase> = Vec::new();
+
+    // A single player always wins
+    if let Some(distribution) = distributions.iter().find(|distribution| distribution.nb_players == 1) {
+        edge_cases.push(EdgeCase {
+            name: String::from("single player always wins"),
+            passed: distribution.ticket_counts[0] == distribution.nb_draws(),
+            detail: format!("ticket 1 won {} of {} draws", distribution.ticket_counts[0], distribution.nb_draws()),
+        });
+    }
+
+    // First & last tickets are reachable, the upper bound of the range is exclusive
+    for distribution in distributions.iter().filter(|distribution| distribution.nb_players > 1) {
+        let last_ticket_wins: u64 = distribution.ticket_counts[distribution.nb_players - 1];
+        edge_cases.push(EdgeCase {
+            name: format!("first & last tickets are drawn with {} players", distribution.nb_players),
+            passed: distribution.ticket_counts[0] > 0 && last_ticket_wins > 0,
+            detail: format!("ticket 1 won {} times, ticket {} won {} times", distribution.ticket_counts[0], distribution.nb_players, last_ticket_wins),
+        });
+    }
+
+    // Seeds with little entropy still draw a valid ticket
+    for (seed_name, random_seed) in extreme_seeds().iter() {
+        let mut invalid_draws: Vec<String> = Vec::new();
+
+        for distribution in distributions.iter() {
+            match simulator.draw(distribution.nb_players, random_seed) {
+                Some(ticket) if (1..=distribution.nb_players).contains(&ticket) => {},
+                Some(ticket) => invalid_draws.push(format!("ticket {} with {} players", ticket, distribution.nb_players)),
+                None => invalid_draws.push(format!("trigger failed with {} players", distribution.nb_players)),
+            }
+        }
+
+        edge_cases.push(EdgeCase {
+            name: format!("valid ticket for seed {}", seed_name),
+            passed: invalid_draws.is_empty(),
+            detail: if invalid_draws.is_empty() { String::from("all player counts") } else { invalid_draws.join(", ") },
+        });
+    }
+
+    return edge_cases;
+}
@@ -0,0 +1,17 @@
+////////////////////////////////////////////////////////////////////
+// Randomness audit : runs the trigger of the contract in the debug
+// environment over many block random seeds & player counts, then
+// tests the distribution of winning tickets
+////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////
+// Modules & uses
+////////////////////////////////////////////////////////////////////
+mod statistics;
+mod simulation;
+mod audit;
+mod report;
+
+pub use statistics::{chi_square_p_value, chi_square_uniform, ln_gamma, upper_regularized_gamma, ChiSquare};
+pub use simulation::{extreme_seeds, RandomSeed, SeedGenerator, TriggerSimulator, RANDOM_SEED_LENGTH};
+pub use audit::{run_audit, AuditConfig, AuditReport, Distribution, EdgeCase, DEFAULT_NB_DRAWS, DEFAULT_PLAYER_COUNTS, DEFAULT_SEED, DEFAULT_SIGNIFICANCE};
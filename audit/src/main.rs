@@ -0,0 +1,38 @@
+use prize_audit::{run_audit, AuditConfig};
+use std::env;
+use std::fs;
+use std::process;
+
+// Usage : prize-audit [<nb draws per player count>] [<report file>], the report is printed if no file is given
+fn main() {
+    let mut config = AuditConfig::default();
+
+    if let Some(nb_draws) = env::args().nth(1) {
+        config.nb_draws = match nb_draws.parse::<usize>() {
+            Ok(nb_draws) => nb_draws,
+            Err(_) => {
+                eprintln!("Invalid number of draws : {}", nb_draws);
+                process::exit(2);
+            },
+        };
+    }
+
+    let report = run_audit(&config);
+    let markdown = report.to_markdown();
+
+    match env::args().nth(2) {
+        Some(path) => match fs::write(&path, &markdown) {
+            Ok(()) => println!("Report written to {}", path),
+            Err(error) => {
+                eprintln!("Cannot write to {} : {}", path, error);
+                process::exit(2);
+            },
+        },
+        None => print!("{}", markdown),
+    }
+
+    // Failed audit is reported by the exit code, for CI
+    if report.passed() == false {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::audit::{AuditReport, Distribution};
+use std::fmt::Write;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+
+// Player counts above this limit are summarized by their least & most drawn tickets
+const MAX_DETAILED_TICKETS: usize = 10;
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+fn status(passed: bool) -> &'static str {
+    if passed == true {
+        return "PASS";
+    }
+    return "FAIL";
+}
+
+// Returns : wins of each ticket, or the extreme tickets for large player counts
+fn ticket_summary(distribution: &Distribution) -> String {
+    if distribution.nb_players <= MAX_DETAILED_TICKETS {
+        let counts: Vec<String> = distribution.ticket_counts.iter().map(|count| count.to_string()).collect();
+        return counts.join(" / ");
+    }
+
+    let mut least_drawn: (usize, u64) = (1, u64::MAX);
+    let mut most_drawn: (usize, u64) = (1, 0);
+    for (index, count) in distribution.ticket_counts.iter().enumerate() {
+        if *count < least_drawn.1 {
+            least_drawn = (index + 1, *count);
+        }
+        if *count > most_drawn.1 {
+            most_drawn = (index + 1, *count);
+        }
+    }
+
+    return format!("min #{} : {}, max #{} : {}", least_drawn.0, least_drawn.1, most_drawn.0, most_drawn.1);
+}
+
+impl AuditReport {
+    // Markdown report, ready to be published
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+
+        let _ = writeln!(markdown, "# Winner distribution audit");
+        let _ = writeln!(markdown);
+        let _ = writeln!(markdown, "The `prize` endpoint (`trigger`) draws the winning ticket with `rand.next_usize_in_range(1, nb_players + 1)`.");
+        let _ = writeln!(markdown, "Each instance below has been triggered {} times in the elrond-wasm-debug environment, with block random seeds generated from seed `{:#x}`.", self.config.nb_draws, self.config.seed);
+        let _ = writeln!(markdown, "Each trigger is reverted, so the same ended instance is drawn again with the next seed.");
+        let _ = writeln!(markdown);
+        let _ = writeln!(markdown, "Uniformity is checked with a chi-square goodness of fit test, at a {} significance level split between the {} player counts (p-value threshold {:.2e}).", self.config.significance, self.distributions.len(), self.config.test_significance());
+        let _ = writeln!(markdown);
+        let _ = writeln!(markdown, "**Result : {}**", status(self.passed()));
+        let _ = writeln!(markdown);
+
+        let _ = writeln!(markdown, "## Distribution of winning tickets");
+        let _ = writeln!(markdown);
+        let _ = writeln!(markdown, "| Players | Draws | Wins per ticket | Out of range | Failed | Chi-square | DoF | p-value | Status |");
+        let _ = writeln!(markdown, "|---:|---:|---|---:|---:|---:|---:|---:|---|");
+        for distribution in self.distributions.iter() {
+            let _ = writeln!(
+                markdown,
+                "| {} | {} | {} | {} | {} | {:.3} | {} | {:.4} | {} |",
+                distribution.nb_players,
+                distribution.nb_draws(),
+                ticket_summary(distribution),
+                distribution.nb_out_of_range,
+                distribution.nb_failed,
+                distribution.chi_square.statistic,
+                distribution.chi_square.degrees_of_freedom,
+                distribution.chi_square.p_value,
+                status(distribution.passed)
+            );
+        }
+        let _ = writeln!(markdown);
+
+        let _ = writeln!(markdown, "## Edge cases");
+        let _ = writeln!(markdown);
+        let _ = writeln!(markdown, "| Check | Detail | Status |");
+        let _ = writeln!(markdown, "|---|---|---|");
+        for edge_case in self.edge_cases.iter() {
+            let _ = writeln!(markdown, "| {} | {} | {} |", edge_case.name, edge_case.detail, status(edge_case.passed));
+        }
+
+        return markdown;
+    }
+}
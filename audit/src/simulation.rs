@@ -0,0 +1,154 @@
+use elrond_wasm::types::{Address, BigUint, ManagedAddress, ManagedBuffer, OptionalValue, TokenIdentifier};
+use elrond_wasm_debug::num_bigint::BigUint as RustBigUint;
+use elrond_wasm_debug::testing_framework::{BlockchainStateWrapper, ContractObjWrapper, StateChange};
+use elrond_wasm_debug::DebugApi;
+use prize::instance::InstanceModule;
+use prize::parameter::ParameterModule;
+use prize::Prize;
+use std::collections::BTreeMap;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+pub const RANDOM_SEED_LENGTH: usize = 48;
+const DURATION_IN_S: u64 = 60;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+pub type RandomSeed = [u8; RANDOM_SEED_LENGTH];
+
+type PrizeContract = ContractObjWrapper<prize::ContractObj<DebugApi>, fn() -> prize::ContractObj<DebugApi>>;
+
+// Deterministic block random seeds (splitmix64), so that published results can be reproduced
+#[derive(Debug, Clone)]
+pub struct SeedGenerator {
+    state: u64,
+}
+
+// Contract deployed in the debug environment with one ended instance per player count
+pub struct TriggerSimulator {
+    blockchain: BlockchainStateWrapper,
+    contract: PrizeContract,
+    owner: Address,
+    // IID of the instance played by each number of players
+    iids: BTreeMap<usize, u32>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl SeedGenerator {
+    pub fn new(seed: u64) -> Self {
+        return SeedGenerator { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return z ^ (z >> 31);
+    }
+
+    pub fn next_seed(&mut self) -> RandomSeed {
+        let mut random_seed: RandomSeed = [0u8; RANDOM_SEED_LENGTH];
+
+        for chunk in random_seed.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_be_bytes());
+        }
+
+        return random_seed;
+    }
+}
+
+// Returns : seeds with little entropy, most likely to reveal a biased range mapping
+pub fn extreme_seeds() -> Vec<(String, RandomSeed)> {
+    let mut seeds: Vec<(String, RandomSeed)> = vec![
+        (String::from("all bytes 0x00"), [0x00; RANDOM_SEED_LENGTH]),
+        (String::from("all bytes 0xFF"), [0xFF; RANDOM_SEED_LENGTH]),
+    ];
+
+    for position in [0, RANDOM_SEED_LENGTH / 2, RANDOM_SEED_LENGTH - 1].iter() {
+        let mut random_seed: RandomSeed = [0x00; RANDOM_SEED_LENGTH];
+        random_seed[*position] = 0x01;
+        seeds.push((format!("single 0x01 byte at position {}", position), random_seed));
+    }
+
+    return seeds;
+}
+
+impl TriggerSimulator {
+    // Players play each instance in turn, ticket N belongs to the Nth player
+    pub fn new(player_counts: &[usize]) -> Self {
+        let mut blockchain = BlockchainStateWrapper::new();
+        let owner = blockchain.create_user_account(&RustBigUint::from(1_000_000u32));
+        let max_nb_players: usize = player_counts.iter().cloned().max().unwrap_or(0);
+        let players: Vec<Address> = (0..max_nb_players).map(|_| blockchain.create_user_account(&RustBigUint::from(0u32))).collect();
+        let contract: PrizeContract = blockchain.create_sc_account(&RustBigUint::from(0u32), Some(&owner), prize::contract_obj as fn() -> prize::ContractObj<DebugApi>, "output/prize.wasm");
+
+        // Default fee policy is free entry & no jackpot, the winning ticket is the first draw of the trigger
+        let nb_instances: u32 = player_counts.len() as u32;
+        let _ = blockchain.execute_tx(&owner, &contract, &RustBigUint::from(0u32), |sc| {
+            let _ = sc.init();
+            let _ = sc.set_param_nb_max_instances_per_sponsor(nb_instances);
+            let _ = sc.set_param_manual_claim(true);
+            return StateChange::Commit;
+        });
+
+        let mut iids: BTreeMap<usize, u32> = BTreeMap::new();
+        for nb_players in player_counts.iter() {
+            let mut iid: u32 = 0;
+            let _ = blockchain.execute_tx(&owner, &contract, &RustBigUint::from(1u32), |sc| {
+                let (result, created_iid) = sc
+                    .create_instance(TokenIdentifier::egld(), 0, BigUint::from(1u32), DURATION_IN_S, ManagedBuffer::from(&b"audit"[..]), ManagedBuffer::new(), ManagedBuffer::new(), ManagedBuffer::new(), ManagedBuffer::new(), ManagedBuffer::new(), ManagedBuffer::new(), ManagedBuffer::new(), false, 0)
+                    .into_tuple();
+                assert!(result.is_ok(), "Instance creation failed");
+                iid = created_iid.into_option().unwrap();
+                return StateChange::Commit;
+            });
+
+            for player in players.iter().take(*nb_players) {
+                let _ = blockchain.execute_tx(player, &contract, &RustBigUint::from(0u32), |sc| {
+                    assert!(sc.play(BigUint::zero(), iid, OptionalValue::None).into_tuple().0.is_ok(), "Play failed");
+                    return StateChange::Commit;
+                });
+            }
+
+            iids.insert(*nb_players, iid);
+        }
+
+        // All instances are ended and ready to be triggered
+        blockchain.set_block_timestamp(DURATION_IN_S + 1);
+
+        return TriggerSimulator { blockchain: blockchain, contract: contract, owner: owner, iids: iids };
+    }
+
+    pub fn player_counts(&self) -> Vec<usize> {
+        return self.iids.keys().cloned().collect();
+    }
+
+    // Runs the trigger endpoint with the given block random seed, then reverts it so that the instance can be drawn again
+    // Returns : winning ticket, None if the trigger failed
+    pub fn draw(&mut self, nb_players: usize, random_seed: &RandomSeed) -> Option<usize> {
+        let iid: u32 = *self.iids.get(&nb_players).expect("No instance for this number of players");
+        let mut winning_ticket: Option<usize> = None;
+
+        self.blockchain.set_block_random_seed(Box::new(*random_seed));
+
+        let _ = self.blockchain.execute_tx(&self.owner, &self.contract, &RustBigUint::from(0u32), |sc| {
+            if sc.trigger(iid).is_ok() {
+                let winner_info = sc.instance_state_mapper().get(&iid).unwrap().winner_info;
+
+                // Winner address is only set by a successful draw
+                if winner_info.address != ManagedAddress::zero() {
+                    winning_ticket = Some(winner_info.ticket_number);
+                }
+            }
+            return StateChange::Revert;
+        });
+
+        return winning_ticket;
+    }
+}
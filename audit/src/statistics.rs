@@ -0,0 +1,131 @@
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const MAX_ITERATIONS: usize = 1_000;
+const EPSILON: f64 = 1e-14;
+const TINY: f64 = 1e-300;
+
+// Lanczos approximation coefficients (g = 7, n = 9)
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Pearson's chi-square goodness of fit test against the uniform distribution
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChiSquare {
+    pub statistic: f64,
+    pub degrees_of_freedom: usize,
+    // Probability of a statistic at least as large for a uniform draw
+    pub p_value: f64,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+
+// Counts are the number of draws of each possible outcome, all outcomes being expected equally likely
+pub fn chi_square_uniform(counts: &[u64]) -> ChiSquare {
+    let nb_draws: u64 = counts.iter().sum();
+
+    // A single outcome, or no draw at all, cannot deviate from uniformity
+    if counts.len() < 2 || nb_draws == 0 {
+        return ChiSquare { statistic: 0.0, degrees_of_freedom: counts.len().saturating_sub(1), p_value: 1.0 };
+    }
+
+    let expected: f64 = nb_draws as f64 / counts.len() as f64;
+    let statistic: f64 = counts.iter().map(|count| (*count as f64 - expected).powi(2) / expected).sum();
+    let degrees_of_freedom: usize = counts.len() - 1;
+
+    return ChiSquare { statistic: statistic, degrees_of_freedom: degrees_of_freedom, p_value: chi_square_p_value(statistic, degrees_of_freedom) };
+}
+
+// Returns : upper tail probability of the chi-square distribution
+pub fn chi_square_p_value(statistic: f64, degrees_of_freedom: usize) -> f64 {
+    if degrees_of_freedom == 0 || statistic <= 0.0 {
+        return 1.0;
+    }
+    return upper_regularized_gamma(degrees_of_freedom as f64 / 2.0, statistic / 2.0);
+}
+
+pub fn ln_gamma(x: f64) -> f64 {
+    // Reflection formula for small arguments
+    if x < 0.5 {
+        return (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let mut sum: f64 = LANCZOS_COEFFICIENTS[0];
+    for (index, coefficient) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        sum += coefficient / (x + index as f64);
+    }
+    let t: f64 = x + 7.5;
+
+    return 0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln();
+}
+
+// Q(a, x) = Γ(a, x) / Γ(a), series for x < a + 1, continued fraction otherwise
+pub fn upper_regularized_gamma(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    if x < a + 1.0 {
+        return 1.0 - lower_regularized_gamma_series(a, x);
+    }
+    return upper_regularized_gamma_continued_fraction(a, x);
+}
+
+fn lower_regularized_gamma_series(a: f64, x: f64) -> f64 {
+    let mut term: f64 = 1.0 / a;
+    let mut sum: f64 = term;
+
+    for n in 1..MAX_ITERATIONS {
+        term *= x / (a + n as f64);
+        sum += term;
+        if term.abs() < sum.abs() * EPSILON {
+            break;
+        }
+    }
+
+    return sum * (-x + a * x.ln() - ln_gamma(a)).exp();
+}
+
+// Modified Lentz's method
+fn upper_regularized_gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    let mut b: f64 = x + 1.0 - a;
+    let mut c: f64 = 1.0 / TINY;
+    let mut d: f64 = 1.0 / b;
+    let mut h: f64 = d;
+
+    for n in 1..MAX_ITERATIONS {
+        let an: f64 = -(n as f64) * (n as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta: f64 = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    return (-x + a * x.ln() - ln_gamma(a)).exp() * h;
+}
@@ -0,0 +1,75 @@
+use prize_audit::*;
+
+fn assert_close(value: f64, expected: f64, tolerance: f64) {
+    assert!((value - expected).abs() < tolerance, "{} is not close to {}", value, expected);
+}
+
+#[test]
+fn chi_square_critical_values() {
+    // 5 % critical values of the chi-square distribution
+    assert_close(chi_square_p_value(3.841_458_820_694_124, 1), 0.05, 1e-9);
+    assert_close(chi_square_p_value(5.991_464_547_107_979, 2), 0.05, 1e-9);
+    assert_close(chi_square_p_value(16.918_977_604_620_448, 9), 0.05, 1e-9);
+    assert_close(chi_square_p_value(123.225_2, 99), 0.05, 1e-5);
+    assert_close(ln_gamma(5.0), 24f64.ln(), 1e-12);
+    assert_close(upper_regularized_gamma(1.0, 2.0), (-2f64).exp(), 1e-12);
+}
+
+#[test]
+fn chi_square_uniform_counts() {
+    let uniform = chi_square_uniform(&[250, 250, 250, 250]);
+    assert_eq!(uniform.statistic, 0.0);
+    assert_eq!(uniform.degrees_of_freedom, 3);
+    assert_eq!(uniform.p_value, 1.0);
+
+    // Last ticket never drawn
+    let biased = chi_square_uniform(&[334, 333, 333, 0]);
+    assert!(biased.p_value < 1e-50);
+
+    // Single ticket cannot be biased
+    assert_eq!(chi_square_uniform(&[1_000]).p_value, 1.0);
+}
+
+#[test]
+fn seed_generator_is_reproducible() {
+    let mut generator = SeedGenerator::new(DEFAULT_SEED);
+    let first_seed: RandomSeed = generator.next_seed();
+
+    assert_eq!(SeedGenerator::new(DEFAULT_SEED).next_seed(), first_seed);
+    assert_ne!(generator.next_seed(), first_seed);
+    assert_ne!(SeedGenerator::new(DEFAULT_SEED + 1).next_seed(), first_seed);
+    assert_eq!(extreme_seeds()[0].1, [0u8; RANDOM_SEED_LENGTH]);
+}
+
+#[test]
+fn trigger_draws_valid_tickets() {
+    let mut simulator = TriggerSimulator::new(&[1, 3]);
+    let mut generator = SeedGenerator::new(DEFAULT_SEED);
+
+    for _ in 0..50 {
+        let random_seed = generator.next_seed();
+        assert_eq!(simulator.draw(1, &random_seed), Some(1));
+
+        let ticket = simulator.draw(3, &random_seed).unwrap();
+        assert!(ticket >= 1 && ticket <= 3);
+    }
+}
+
+#[test]
+fn audit_report() {
+    let config = AuditConfig { player_counts: vec![1, 2, 3, 7], nb_draws: 700, ..AuditConfig::default() };
+    let report = run_audit(&config);
+
+    assert_eq!(report.distributions.len(), 4);
+    assert_eq!(report.distribution(1).unwrap().ticket_counts, vec![700]);
+    for distribution in report.distributions.iter() {
+        assert_eq!(distribution.nb_draws(), 700);
+        assert_eq!(distribution.nb_out_of_range, 0);
+        assert_eq!(distribution.nb_failed, 0);
+    }
+    for edge_case in report.edge_cases.iter() {
+        assert!(edge_case.passed, "{} : {}", edge_case.name, edge_case.detail);
+    }
+    assert!(report.passed());
+    assert!(report.to_markdown().contains("**Result : PASS**"));
+}
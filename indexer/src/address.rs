@@ -0,0 +1,62 @@
+use bech32::{FromBase32, ToBase32, Variant};
+use std::fmt;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const ADDRESS_HRP: &str = "erd";
+pub const ADDRESS_LENGTH: usize = 32;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Account or contract address, as indexed in the event topics
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Address(pub [u8; ADDRESS_LENGTH]);
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl Address {
+    pub fn zero() -> Self {
+        return Address([0u8; ADDRESS_LENGTH]);
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != ADDRESS_LENGTH {
+            return None;
+        }
+
+        let mut address = [0u8; ADDRESS_LENGTH];
+        address.copy_from_slice(bytes);
+
+        return Some(Address(address));
+    }
+
+    // Returns : None if the string is not an erd1... address
+    pub fn from_bech32(bech32_address: &str) -> Option<Self> {
+        let (hrp, data, variant) = bech32::decode(bech32_address).ok()?;
+        if hrp != ADDRESS_HRP || variant != Variant::Bech32 {
+            return None;
+        }
+
+        return Address::from_bytes(&Vec::<u8>::from_base32(&data).ok()?);
+    }
+
+    pub fn to_bech32(&self) -> String {
+        return bech32::encode(ADDRESS_HRP, self.0.to_base32(), Variant::Bech32).unwrap();
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.to_bech32());
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.to_bech32());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,644 @@
+use crate::address::Address;
+use crate::topic::{DecodeError, TopicReader};
+use num_bigint::BigUint;
+use prize::EventCategory;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Contract events, fields in topics order (schema version 3)
+#[derive(Clone, PartialEq)]
+pub enum PrizeEvent {
+    // Events occuring when log status changes
+    SetLogEnableStatus {
+        epoch: u64,
+        enable: bool,
+    },
+    SetLogCategoryStatus {
+        category: EventCategory,
+        epoch: u64,
+        enable: bool,
+    },
+
+    // Events occuring during instance lifecycle
+    CreateInstance {
+        iid: u32,
+        sponsor_address: Address,
+        epoch: u64,
+        token_identifier: String,
+        token_nonce: u64,
+        token_amount: BigUint,
+        duration_in_s: u64,
+        pseudo: String,
+        charity_id: u32,
+        premium: bool,
+    },
+    Play {
+        iid: u32,
+        player_address: Address,
+        epoch: u64,
+        ticket_number: usize,
+        fees: BigUint,
+    },
+    Trigger {
+        iid: u32,
+        winner_address: Address,
+        epoch: u64,
+        ticket_number: usize,
+    },
+    JackpotWon {
+        iid: u32,
+        winner_address: Address,
+        epoch: u64,
+        jackpot: BigUint,
+    },
+    AutoClaimPrize {
+        iid: u32,
+        winner_address: Address,
+        epoch: u64,
+    },
+    ManualClaimPrize {
+        iid: u32,
+        winner_address: Address,
+        epoch: u64,
+    },
+    CleanClaim {
+        iid: u32,
+        sponsor_address: Address,
+        winner_address: Address,
+        epoch: u64,
+        ticket_number: usize,
+        number_of_players: usize,
+        token_identifier: String,
+        token_nonce: u64,
+        token_amount: BigUint,
+    },
+
+    // Events occuring during player & sponsor actions
+    PlayerDonation {
+        iid: u32,
+        player_address: Address,
+        epoch: u64,
+        donation: BigUint,
+    },
+    UsePromoCode {
+        iid: u32,
+        player_address: Address,
+        epoch: u64,
+        discount: BigUint,
+    },
+    AddPromoCodes {
+        iid: u32,
+        epoch: u64,
+        nb_promo_codes: usize,
+        discount: BigUint,
+    },
+    AddDonation {
+        iid: u32,
+        charity_id: u32,
+        epoch: u64,
+        donation_amount: BigUint,
+    },
+    AwardLoyaltyPoints {
+        address: Address,
+        epoch: u64,
+        points: u64,
+    },
+    RedeemLoyaltyPoints {
+        address: Address,
+        epoch: u64,
+        points: u64,
+    },
+    SetReferrer {
+        address: Address,
+        referrer_address: Address,
+        epoch: u64,
+    },
+
+    // Events occuring when funds leave the SC
+    SendRewards {
+        iid: u32,
+        epoch: u64,
+        rewards: BigUint,
+    },
+    ClaimFees {
+        beneficiary_address: Address,
+        epoch: u64,
+        fee_amount: BigUint,
+    },
+    ClaimLinkRewards {
+        link_address: Address,
+        epoch: u64,
+        reward_amount: BigUint,
+    },
+    ClaimDonations {
+        epoch: u64,
+        donations_amount: BigUint,
+    },
+    ClaimCharityDonations {
+        charity_id: u32,
+        epoch: u64,
+        donations_amount: BigUint,
+    },
+    DepositBond {
+        sponsor_address: Address,
+        epoch: u64,
+        token_identifier: String,
+        bond_amount: BigUint,
+    },
+    WithdrawBond {
+        sponsor_address: Address,
+        epoch: u64,
+        token_identifier: String,
+        bond_amount: BigUint,
+    },
+
+    // Events reporting pools amounts
+    FeePoolInfo {
+        epoch: u64,
+        fees: BigUint,
+    },
+    JackpotPoolInfo {
+        epoch: u64,
+        jackpot_pool: BigUint,
+    },
+    PotInfo {
+        iid: u32,
+        epoch: u64,
+        pot: BigUint,
+    },
+    RewardPoolInfo {
+        iid: u32,
+        epoch: u64,
+        reward_pool: BigUint,
+    },
+
+    // Events occuring during setup
+    SetPremium {
+        iid: u32,
+        epoch: u64,
+        premium_status: bool,
+    },
+    DisableInstance {
+        iid: u32,
+        epoch: u64,
+        disable_status: bool,
+    },
+    SlashBond {
+        iid: u32,
+        sponsor_address: Address,
+        epoch: u64,
+        token_identifier: String,
+        slashed_amount: BigUint,
+        to_charity: bool,
+    },
+    SetBondPolicy {
+        epoch: u64,
+        token_identifier: String,
+        bond_amount: BigUint,
+    },
+    SetFeePolicy {
+        epoch: u64,
+        fee_amount_egld: BigUint,
+        sponsor_reward_percent: u8,
+        link_reward_percent: u8,
+        link2_reward_percent: u8,
+    },
+    MigrateIndexes {
+        epoch: u64,
+        first_iid: u32,
+        next_iid: u32,
+        nb_plays: u32,
+        complete: bool,
+    },
+    MigrateFeePolicy {
+        iid: u32,
+        epoch: u64,
+        fee_amount_egld: BigUint,
+        sponsor_reward_percent: u8,
+        link_reward_percent: u8,
+        link2_reward_percent: u8,
+    },
+    SetFeeBeneficiary {
+        address: Address,
+        epoch: u64,
+        weight: u32,
+    },
+    SetCharity {
+        charity_id: u32,
+        address: Address,
+        epoch: u64,
+        name: String,
+        enabled: bool,
+    },
+    SetJackpotParams {
+        epoch: u64,
+        fee_percent: u8,
+        chance: u32,
+    },
+    SetLoyaltyPolicy {
+        epoch: u64,
+        points_per_egld: u64,
+        points_per_win: u64,
+        free_entry_cost: u64,
+    },
+    SetParamDuration {
+        epoch: u64,
+        duration_min: u64,
+        duration_max: u64,
+    },
+    SetParamSponsorInfoMaxLength {
+        epoch: u64,
+        length_max: u32,
+    },
+    SetParamNbMaxInstancesPerSponsor {
+        epoch: u64,
+        nb_instances_max: u32,
+    },
+    SetParamPotWinnerPercent {
+        epoch: u64,
+        winner_percent: u8,
+    },
+    SetParamManualClaim {
+        epoch: u64,
+        manual_claim: bool,
+    },
+    AddAddrBlacklist {
+        address: Address,
+        epoch: u64,
+    },
+    RmAddrBlacklist {
+        address: Address,
+        epoch: u64,
+    },
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl PrizeEvent {
+
+    // Returns : None if the identifier is not an event of the contract
+    pub fn decode(identifier: &str, reader: &mut TopicReader) -> Result<Option<PrizeEvent>, DecodeError> {
+        let event = match identifier {
+            "set_log_enable_status" => PrizeEvent::SetLogEnableStatus {
+                epoch: reader.read_u64()?,
+                enable: reader.read_bool()?,
+            },
+            "set_log_category_status" => PrizeEvent::SetLogCategoryStatus {
+                category: reader.read_top_decode()?,
+                epoch: reader.read_u64()?,
+                enable: reader.read_bool()?,
+            },
+            "create_instance" => PrizeEvent::CreateInstance {
+                iid: reader.read_u32()?,
+                sponsor_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                token_identifier: reader.read_string()?,
+                token_nonce: reader.read_u64()?,
+                token_amount: reader.read_big_uint()?,
+                duration_in_s: reader.read_u64()?,
+                pseudo: reader.read_string()?,
+                charity_id: reader.read_u32()?,
+                premium: reader.read_bool()?,
+            },
+            "play" => PrizeEvent::Play {
+                iid: reader.read_u32()?,
+                player_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                ticket_number: reader.read_usize()?,
+                fees: reader.read_big_uint()?,
+            },
+            "trigger" => PrizeEvent::Trigger {
+                iid: reader.read_u32()?,
+                winner_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                ticket_number: reader.read_usize()?,
+            },
+            "jackpot_won" => PrizeEvent::JackpotWon {
+                iid: reader.read_u32()?,
+                winner_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                jackpot: reader.read_big_uint()?,
+            },
+            "auto_claim_prize" => PrizeEvent::AutoClaimPrize {
+                iid: reader.read_u32()?,
+                winner_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+            },
+            "manual_claim_prize" => PrizeEvent::ManualClaimPrize {
+                iid: reader.read_u32()?,
+                winner_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+            },
+            "clean_claim" => PrizeEvent::CleanClaim {
+                iid: reader.read_u32()?,
+                sponsor_address: reader.read_address()?,
+                winner_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                ticket_number: reader.read_usize()?,
+                number_of_players: reader.read_usize()?,
+                token_identifier: reader.read_string()?,
+                token_nonce: reader.read_u64()?,
+                token_amount: reader.read_big_uint()?,
+            },
+            "player_donation" => PrizeEvent::PlayerDonation {
+                iid: reader.read_u32()?,
+                player_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                donation: reader.read_big_uint()?,
+            },
+            "use_promo_code" => PrizeEvent::UsePromoCode {
+                iid: reader.read_u32()?,
+                player_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                discount: reader.read_big_uint()?,
+            },
+            "add_promo_codes" => PrizeEvent::AddPromoCodes {
+                iid: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                nb_promo_codes: reader.read_usize()?,
+                discount: reader.read_big_uint()?,
+            },
+            "add_donation" => PrizeEvent::AddDonation {
+                iid: reader.read_u32()?,
+                charity_id: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                donation_amount: reader.read_big_uint()?,
+            },
+            "award_loyalty_points" => PrizeEvent::AwardLoyaltyPoints {
+                address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                points: reader.read_u64()?,
+            },
+            "redeem_loyalty_points" => PrizeEvent::RedeemLoyaltyPoints {
+                address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                points: reader.read_u64()?,
+            },
+            "set_referrer" => PrizeEvent::SetReferrer {
+                address: reader.read_address()?,
+                referrer_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+            },
+            "send_rewards" => PrizeEvent::SendRewards {
+                iid: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                rewards: reader.read_big_uint()?,
+            },
+            "claim_fees" => PrizeEvent::ClaimFees {
+                beneficiary_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                fee_amount: reader.read_big_uint()?,
+            },
+            "claim_link_rewards" => PrizeEvent::ClaimLinkRewards {
+                link_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                reward_amount: reader.read_big_uint()?,
+            },
+            "claim_donations" => PrizeEvent::ClaimDonations {
+                epoch: reader.read_u64()?,
+                donations_amount: reader.read_big_uint()?,
+            },
+            "claim_charity_donations" => PrizeEvent::ClaimCharityDonations {
+                charity_id: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                donations_amount: reader.read_big_uint()?,
+            },
+            "deposit_bond" => PrizeEvent::DepositBond {
+                sponsor_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                token_identifier: reader.read_string()?,
+                bond_amount: reader.read_big_uint()?,
+            },
+            "withdraw_bond" => PrizeEvent::WithdrawBond {
+                sponsor_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                token_identifier: reader.read_string()?,
+                bond_amount: reader.read_big_uint()?,
+            },
+            "fee_pool_info" => PrizeEvent::FeePoolInfo {
+                epoch: reader.read_u64()?,
+                fees: reader.read_big_uint()?,
+            },
+            "jackpot_pool_info" => PrizeEvent::JackpotPoolInfo {
+                epoch: reader.read_u64()?,
+                jackpot_pool: reader.read_big_uint()?,
+            },
+            "pot_info" => PrizeEvent::PotInfo {
+                iid: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                pot: reader.read_big_uint()?,
+            },
+            "reward_pool_info" => PrizeEvent::RewardPoolInfo {
+                iid: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                reward_pool: reader.read_big_uint()?,
+            },
+            "set_premium" => PrizeEvent::SetPremium {
+                iid: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                premium_status: reader.read_bool()?,
+            },
+            "disable_instance" => PrizeEvent::DisableInstance {
+                iid: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                disable_status: reader.read_bool()?,
+            },
+            "slash_bond" => PrizeEvent::SlashBond {
+                iid: reader.read_u32()?,
+                sponsor_address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                token_identifier: reader.read_string()?,
+                slashed_amount: reader.read_big_uint()?,
+                to_charity: reader.read_bool()?,
+            },
+            "set_bond_policy" => PrizeEvent::SetBondPolicy {
+                epoch: reader.read_u64()?,
+                token_identifier: reader.read_string()?,
+                bond_amount: reader.read_big_uint()?,
+            },
+            "set_fee_policy" => PrizeEvent::SetFeePolicy {
+                epoch: reader.read_u64()?,
+                fee_amount_egld: reader.read_big_uint()?,
+                sponsor_reward_percent: reader.read_u8()?,
+                link_reward_percent: reader.read_u8()?,
+                link2_reward_percent: reader.read_u8()?,
+            },
+            "migrate_indexes" => PrizeEvent::MigrateIndexes {
+                epoch: reader.read_u64()?,
+                first_iid: reader.read_u32()?,
+                next_iid: reader.read_u32()?,
+                nb_plays: reader.read_u32()?,
+                complete: reader.read_bool()?,
+            },
+            "migrate_fee_policy" => PrizeEvent::MigrateFeePolicy {
+                iid: reader.read_u32()?,
+                epoch: reader.read_u64()?,
+                fee_amount_egld: reader.read_big_uint()?,
+                sponsor_reward_percent: reader.read_u8()?,
+                link_reward_percent: reader.read_u8()?,
+                link2_reward_percent: reader.read_u8()?,
+            },
+            "set_fee_beneficiary" => PrizeEvent::SetFeeBeneficiary {
+                address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                weight: reader.read_u32()?,
+            },
+            "set_charity" => PrizeEvent::SetCharity {
+                charity_id: reader.read_u32()?,
+                address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+                name: reader.read_string()?,
+                enabled: reader.read_bool()?,
+            },
+            "set_jackpot_params" => PrizeEvent::SetJackpotParams {
+                epoch: reader.read_u64()?,
+                fee_percent: reader.read_u8()?,
+                chance: reader.read_u32()?,
+            },
+            "set_loyalty_policy" => PrizeEvent::SetLoyaltyPolicy {
+                epoch: reader.read_u64()?,
+                points_per_egld: reader.read_u64()?,
+                points_per_win: reader.read_u64()?,
+                free_entry_cost: reader.read_u64()?,
+            },
+            "set_param_duration" => PrizeEvent::SetParamDuration {
+                epoch: reader.read_u64()?,
+                duration_min: reader.read_u64()?,
+                duration_max: reader.read_u64()?,
+            },
+            "set_param_sponsor_info_max_length" => PrizeEvent::SetParamSponsorInfoMaxLength {
+                epoch: reader.read_u64()?,
+                length_max: reader.read_u32()?,
+            },
+            "set_param_nb_max_instances_per_sponsor" => PrizeEvent::SetParamNbMaxInstancesPerSponsor {
+                epoch: reader.read_u64()?,
+                nb_instances_max: reader.read_u32()?,
+            },
+            "set_param_pot_winner_percent" => PrizeEvent::SetParamPotWinnerPercent {
+                epoch: reader.read_u64()?,
+                winner_percent: reader.read_u8()?,
+            },
+            "set_param_manual_claim" => PrizeEvent::SetParamManualClaim {
+                epoch: reader.read_u64()?,
+                manual_claim: reader.read_bool()?,
+            },
+            "add_addr_blacklist" => PrizeEvent::AddAddrBlacklist {
+                address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+            },
+            "rm_addr_blacklist" => PrizeEvent::RmAddrBlacklist {
+                address: reader.read_address()?,
+                epoch: reader.read_u64()?,
+            },
+            _ => return Ok(None),
+        };
+        reader.finish()?;
+
+        return Ok(Some(event));
+    }
+
+    // Event identifier, as logged by the contract
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            PrizeEvent::SetLogEnableStatus { .. } => "set_log_enable_status",
+            PrizeEvent::SetLogCategoryStatus { .. } => "set_log_category_status",
+            PrizeEvent::CreateInstance { .. } => "create_instance",
+            PrizeEvent::Play { .. } => "play",
+            PrizeEvent::Trigger { .. } => "trigger",
+            PrizeEvent::JackpotWon { .. } => "jackpot_won",
+            PrizeEvent::AutoClaimPrize { .. } => "auto_claim_prize",
+            PrizeEvent::ManualClaimPrize { .. } => "manual_claim_prize",
+            PrizeEvent::CleanClaim { .. } => "clean_claim",
+            PrizeEvent::PlayerDonation { .. } => "player_donation",
+            PrizeEvent::UsePromoCode { .. } => "use_promo_code",
+            PrizeEvent::AddPromoCodes { .. } => "add_promo_codes",
+            PrizeEvent::AddDonation { .. } => "add_donation",
+            PrizeEvent::AwardLoyaltyPoints { .. } => "award_loyalty_points",
+            PrizeEvent::RedeemLoyaltyPoints { .. } => "redeem_loyalty_points",
+            PrizeEvent::SetReferrer { .. } => "set_referrer",
+            PrizeEvent::SendRewards { .. } => "send_rewards",
+            PrizeEvent::ClaimFees { .. } => "claim_fees",
+            PrizeEvent::ClaimLinkRewards { .. } => "claim_link_rewards",
+            PrizeEvent::ClaimDonations { .. } => "claim_donations",
+            PrizeEvent::ClaimCharityDonations { .. } => "claim_charity_donations",
+            PrizeEvent::DepositBond { .. } => "deposit_bond",
+            PrizeEvent::WithdrawBond { .. } => "withdraw_bond",
+            PrizeEvent::FeePoolInfo { .. } => "fee_pool_info",
+            PrizeEvent::JackpotPoolInfo { .. } => "jackpot_pool_info",
+            PrizeEvent::PotInfo { .. } => "pot_info",
+            PrizeEvent::RewardPoolInfo { .. } => "reward_pool_info",
+            PrizeEvent::SetPremium { .. } => "set_premium",
+            PrizeEvent::DisableInstance { .. } => "disable_instance",
+            PrizeEvent::SlashBond { .. } => "slash_bond",
+            PrizeEvent::SetBondPolicy { .. } => "set_bond_policy",
+            PrizeEvent::SetFeePolicy { .. } => "set_fee_policy",
+            PrizeEvent::MigrateIndexes { .. } => "migrate_indexes",
+            PrizeEvent::MigrateFeePolicy { .. } => "migrate_fee_policy",
+            PrizeEvent::SetFeeBeneficiary { .. } => "set_fee_beneficiary",
+            PrizeEvent::SetCharity { .. } => "set_charity",
+            PrizeEvent::SetJackpotParams { .. } => "set_jackpot_params",
+            PrizeEvent::SetLoyaltyPolicy { .. } => "set_loyalty_policy",
+            PrizeEvent::SetParamDuration { .. } => "set_param_duration",
+            PrizeEvent::SetParamSponsorInfoMaxLength { .. } => "set_param_sponsor_info_max_length",
+            PrizeEvent::SetParamNbMaxInstancesPerSponsor { .. } => "set_param_nb_max_instances_per_sponsor",
+            PrizeEvent::SetParamPotWinnerPercent { .. } => "set_param_pot_winner_percent",
+            PrizeEvent::SetParamManualClaim { .. } => "set_param_manual_claim",
+            PrizeEvent::AddAddrBlacklist { .. } => "add_addr_blacklist",
+            PrizeEvent::RmAddrBlacklist { .. } => "rm_addr_blacklist",
+        }
+    }
+
+    // Log status changes are always logged, they are reported in the setup category
+    pub fn category(&self) -> EventCategory {
+        match self {
+            PrizeEvent::CreateInstance { .. }
+            | PrizeEvent::Play { .. }
+            | PrizeEvent::Trigger { .. }
+            | PrizeEvent::JackpotWon { .. }
+            | PrizeEvent::AutoClaimPrize { .. }
+            | PrizeEvent::ManualClaimPrize { .. }
+            | PrizeEvent::CleanClaim { .. }
+            | PrizeEvent::DisableInstance { .. }
+            | PrizeEvent::SlashBond { .. }
+            | PrizeEvent::MigrateIndexes { .. }
+            | PrizeEvent::MigrateFeePolicy { .. } => EventCategory::Lifecycle,
+            PrizeEvent::PlayerDonation { .. }
+            | PrizeEvent::UsePromoCode { .. }
+            | PrizeEvent::AddPromoCodes { .. }
+            | PrizeEvent::AddDonation { .. }
+            | PrizeEvent::AwardLoyaltyPoints { .. }
+            | PrizeEvent::RedeemLoyaltyPoints { .. }
+            | PrizeEvent::SetReferrer { .. } => EventCategory::Player,
+            PrizeEvent::SendRewards { .. }
+            | PrizeEvent::ClaimFees { .. }
+            | PrizeEvent::ClaimLinkRewards { .. }
+            | PrizeEvent::ClaimDonations { .. }
+            | PrizeEvent::ClaimCharityDonations { .. }
+            | PrizeEvent::DepositBond { .. }
+            | PrizeEvent::WithdrawBond { .. } => EventCategory::Treasury,
+            PrizeEvent::FeePoolInfo { .. }
+            | PrizeEvent::JackpotPoolInfo { .. }
+            | PrizeEvent::PotInfo { .. }
+            | PrizeEvent::RewardPoolInfo { .. } => EventCategory::PoolInfo,
+            PrizeEvent::SetLogEnableStatus { .. }
+            | PrizeEvent::SetLogCategoryStatus { .. }
+            | PrizeEvent::SetPremium { .. }
+            | PrizeEvent::SetBondPolicy { .. }
+            | PrizeEvent::SetFeePolicy { .. }
+            | PrizeEvent::SetFeeBeneficiary { .. }
+            | PrizeEvent::SetCharity { .. }
+            | PrizeEvent::SetJackpotParams { .. }
+            | PrizeEvent::SetLoyaltyPolicy { .. }
+            | PrizeEvent::SetParamDuration { .. }
+            | PrizeEvent::SetParamSponsorInfoMaxLength { .. }
+            | PrizeEvent::SetParamNbMaxInstancesPerSponsor { .. }
+            | PrizeEvent::SetParamPotWinnerPercent { .. }
+            | PrizeEvent::SetParamManualClaim { .. }
+            | PrizeEvent::AddAddrBlacklist { .. }
+            | PrizeEvent::RmAddrBlacklist { .. } => EventCategory::Setup,
+        }
+    }
+}
\ No newline at end of file
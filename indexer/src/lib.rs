@@ -0,0 +1,43 @@
+////////////////////////////////////////////////////////////////////
+// Off-chain indexer : decodes the contract events from transaction results
+// and replays them into an in-memory model of the contract state
+////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////
+// Modules & uses
+////////////////////////////////////////////////////////////////////
+mod address;
+mod topic;
+mod event;
+mod log;
+mod model;
+
+pub use address::Address;
+pub use topic::{DecodeError, TopicReader};
+pub use event::PrizeEvent;
+pub use log::{decode_log_event, decode_transaction, parse_transactions, LogEvent, LoggedEvent, SmartContractResult, TransactionLogs, TransactionResult};
+pub use model::{Bond, Charity, FeePolicy, Instance, Player, Pools, State};
+pub use prize::{EventCategory, InstanceStatus, EVENT_SCHEMA_VERSION};
+
+////////////////////////////////////////////////////////////////////
+// Functions
+////////////////////////////////////////////////////////////////////
+
+// Returns : events of the contract logged by the transactions, in transactions order
+pub fn decode_transactions(transactions: &[TransactionResult], contract_address: &str) -> Result<Vec<LoggedEvent>, DecodeError> {
+    let mut events: Vec<LoggedEvent> = Vec::new();
+
+    for transaction in transactions.iter() {
+        events.extend(decode_transaction(transaction, contract_address)?);
+    }
+
+    return Ok(events);
+}
+
+// Returns : contract state rebuilt from the transactions
+pub fn index_transactions(transactions: &[TransactionResult], contract_address: &str) -> Result<State, DecodeError> {
+    let mut state = State::new();
+    state.replay(&decode_transactions(transactions, contract_address)?);
+
+    return Ok(state);
+}
\ No newline at end of file
@@ -0,0 +1,141 @@
+use crate::address::Address;
+use crate::event::PrizeEvent;
+use crate::topic::{DecodeError, TopicReader};
+use prize::EVENT_SCHEMA_VERSION;
+use serde::Deserialize;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Transaction with results, as returned by the API (/transactions/<hash>?withResults=true)
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionResult {
+    #[serde(default)]
+    pub tx_hash: String,
+    #[serde(default)]
+    pub timestamp: u64,
+    #[serde(default)]
+    pub logs: Option<TransactionLogs>,
+    #[serde(default)]
+    pub results: Vec<SmartContractResult>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct SmartContractResult {
+    #[serde(default)]
+    pub logs: Option<TransactionLogs>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct TransactionLogs {
+    #[serde(default)]
+    pub events: Vec<LogEvent>,
+}
+
+// Topics & data are base64 encoded, the first topic is the event identifier
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct LogEvent {
+    pub address: String,
+    #[serde(default)]
+    pub identifier: String,
+    #[serde(default)]
+    pub topics: Option<Vec<String>>,
+    #[serde(default)]
+    pub data: Option<String>,
+}
+
+// Decoded contract event, with its transaction context
+#[derive(Clone, PartialEq)]
+pub struct LoggedEvent {
+    pub tx_hash: String,
+    pub timestamp: u64,
+    pub event: PrizeEvent,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+
+// Returns : transactions of a JSON array
+pub fn parse_transactions(json: &str) -> Result<Vec<TransactionResult>, serde_json::Error> {
+    return serde_json::from_str(json);
+}
+
+// Returns : events of the contract logged by the transaction, in log order
+// Events of other contracts & built-in events are ignored
+pub fn decode_transaction(transaction: &TransactionResult, contract_address: &str) -> Result<Vec<LoggedEvent>, DecodeError> {
+    let contract = match Address::from_bech32(contract_address) {
+        Some(address) => address,
+        None => return Err(DecodeError::InvalidContractAddress(String::from(contract_address))),
+    };
+
+    let mut logs: Vec<&TransactionLogs> = Vec::new();
+    if let Some(transaction_logs) = &transaction.logs {
+        logs.push(transaction_logs);
+    }
+    for result in transaction.results.iter() {
+        if let Some(result_logs) = &result.logs {
+            logs.push(result_logs);
+        }
+    }
+
+    let mut events: Vec<LoggedEvent> = Vec::new();
+
+    for log_event in logs.iter().flat_map(|transaction_logs| transaction_logs.events.iter()) {
+        if Address::from_bech32(&log_event.address) != Some(contract) {
+            continue;
+        }
+
+        if let Some(event) = decode_log_event(log_event)? {
+            events.push(LoggedEvent {
+                tx_hash: transaction.tx_hash.clone(),
+                timestamp: transaction.timestamp,
+                event: event,
+            });
+        }
+    }
+
+    return Ok(events);
+}
+
+// Returns : None if the log event is not an event of the contract
+pub fn decode_log_event(log_event: &LogEvent) -> Result<Option<PrizeEvent>, DecodeError> {
+    let mut topics: Vec<Vec<u8>> = Vec::new();
+    for topic in log_event.topics.clone().unwrap_or_default().iter() {
+        topics.push(decode_base64(topic)?);
+    }
+
+    if topics.len() == 0 {
+        return Ok(None);
+    }
+    let identifier = String::from_utf8_lossy(&topics.remove(0)).into_owned();
+
+    let mut reader = TopicReader::new(&identifier, topics);
+    let decoded = PrizeEvent::decode(&identifier, &mut reader);
+    if matches!(decoded, Ok(None)) {
+        return Ok(None);
+    }
+
+    // Schema version is the event data, events logged before versioning have no data
+    let data = decode_base64(log_event.data.as_deref().unwrap_or(""))?;
+    let version: u8 = match data.as_slice() {
+        [version] => *version,
+        _ => 1,
+    };
+
+    // Topics order of other versions differ, only the identifier can be trusted
+    if version != EVENT_SCHEMA_VERSION {
+        return Err(DecodeError::UnsupportedVersion { event: identifier, version: version });
+    }
+
+    return decoded;
+}
+
+fn decode_base64(value: &str) -> Result<Vec<u8>, DecodeError> {
+    match base64::decode(value) {
+        Ok(bytes) => return Ok(bytes),
+        Err(_) => return Err(DecodeError::InvalidBase64(String::from(value))),
+    }
+}
\ No newline at end of file
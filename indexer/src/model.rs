@@ -0,0 +1,414 @@
+use crate::address::Address;
+use crate::event::PrizeEvent;
+use crate::log::LoggedEvent;
+use elrond_wasm::api::ManagedTypeApi;
+use elrond_wasm::types::{BigUint as ManagedBigUint, ManagedAddress, ManagedBuffer};
+use num_bigint::BigUint;
+use prize::{BondInfo, GetCharityStruct, InstanceStatus};
+use std::collections::{BTreeMap, BTreeSet};
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Off-chain copies of the contract types, see the From conversions below
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FeePolicy {
+    pub fee_amount_egld: BigUint,
+    pub sponsor_reward_percent: u8,
+    pub link_reward_percent: u8,
+    pub link2_reward_percent: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance {
+    pub iid: u32,
+    pub sponsor_address: Address,
+    pub pseudo: String,
+    pub token_identifier: String,
+    pub token_nonce: u64,
+    pub token_amount: BigUint,
+    pub creation_timestamp: u64,
+    pub deadline: u64,
+    pub fee_policy: FeePolicy,
+    pub charity_id: u32,
+    pub premium: bool,
+    pub disabled: bool,
+    pub claimed: bool,
+    pub winner_address: Option<Address>,
+    pub winning_ticket: usize,
+    // Player addresses, ticket <n> is players[n - 1]
+    pub players: Vec<Address>,
+    pub fees: BigUint,
+    pub donations: BigUint,
+    pub pot: BigUint,
+    pub reward_pool: BigUint,
+    pub rewards_sent: BigUint,
+    pub jackpot_won: BigUint,
+    pub nb_promo_codes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Player {
+    pub nb_plays: u32,
+    pub spent: BigUint,
+    pub donations: BigUint,
+    pub nb_wins: u32,
+    pub jackpots: BigUint,
+    pub loyalty_points: u64,
+    pub referrer: Option<Address>,
+    pub link_rewards_claimed: BigUint,
+    pub fees_claimed: BigUint,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Charity {
+    pub address: Address,
+    pub name: String,
+    pub enabled: bool,
+    pub balance: BigUint,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bond {
+    pub token_identifier: String,
+    pub amount: BigUint,
+}
+
+// Pools as last reported by the contract, and amounts which left the SC
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Pools {
+    pub fee_pool: BigUint,
+    pub jackpot_pool: BigUint,
+    pub charity_pool: BigUint,
+    pub fees_claimed: BigUint,
+    pub link_rewards_claimed: BigUint,
+    pub donations_claimed: BigUint,
+}
+
+// Contract state rebuilt from its events
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct State {
+    pub instances: BTreeMap<u32, Instance>,
+    // Claimed instances purged from the contract
+    pub archived: BTreeMap<u32, Instance>,
+    pub players: BTreeMap<Address, Player>,
+    pub charities: BTreeMap<u32, Charity>,
+    pub bonds: BTreeMap<Address, Bond>,
+    pub pools: Pools,
+    pub blacklist: BTreeSet<Address>,
+    pub fee_policy: FeePolicy,
+    pub nb_events: u64,
+    pub last_timestamp: u64,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+fn to_big_uint<M: ManagedTypeApi>(amount: &ManagedBigUint<M>) -> BigUint {
+    return BigUint::from_bytes_be(amount.to_bytes_be().as_slice());
+}
+
+fn to_address<M: ManagedTypeApi>(address: &ManagedAddress<M>) -> Address {
+    return Address::from_bytes(address.to_address().as_bytes()).unwrap();
+}
+
+fn to_string<M: ManagedTypeApi>(buffer: &ManagedBuffer<M>) -> String {
+    return String::from_utf8_lossy(buffer.to_boxed_bytes().as_slice()).into_owned();
+}
+
+// Conversions from the contract types, as returned by the views
+// Level-2 link reward percent is returned apart from the contract fee policy
+impl<M: ManagedTypeApi> From<(&prize::FeePolicy<M>, u8)> for FeePolicy {
+    fn from((fee_policy, link2_reward_percent): (&prize::FeePolicy<M>, u8)) -> Self {
+        return FeePolicy {
+            fee_amount_egld: to_big_uint(&fee_policy.fee_amount_egld),
+            sponsor_reward_percent: fee_policy.sponsor_reward_percent,
+            link_reward_percent: fee_policy.link_reward_percent,
+            link2_reward_percent: link2_reward_percent,
+        };
+    }
+}
+
+impl<M: ManagedTypeApi> From<&GetCharityStruct<M>> for Charity {
+    fn from(charity: &GetCharityStruct<M>) -> Self {
+        return Charity {
+            address: to_address(&charity.charity_info.address),
+            name: to_string(&charity.charity_info.name),
+            enabled: charity.charity_info.enabled,
+            balance: to_big_uint(&charity.balance),
+        };
+    }
+}
+
+impl<M: ManagedTypeApi> From<&BondInfo<M>> for Bond {
+    fn from(bond: &BondInfo<M>) -> Self {
+        return Bond {
+            token_identifier: to_string(bond.token_identifier.as_managed_buffer()),
+            amount: to_big_uint(&bond.amount),
+        };
+    }
+}
+
+impl Instance {
+
+    // Same rules as the contract getStatus view
+    pub fn status(&self, timestamp: u64) -> InstanceStatus {
+        if self.disabled == true {
+            return InstanceStatus::Disabled;
+        } else if self.claimed == true {
+            return InstanceStatus::Claimed;
+        } else if self.winner_address.is_some() {
+            return InstanceStatus::Triggered;
+        } else if timestamp > self.deadline {
+            return InstanceStatus::Ended;
+        } else {
+            return InstanceStatus::Running;
+        }
+    }
+}
+
+impl State {
+    pub fn new() -> Self {
+        return State::default();
+    }
+
+    pub fn replay(&mut self, events: &[LoggedEvent]) {
+        for event in events.iter() {
+            self.apply(event);
+        }
+    }
+
+    // Instance status at the timestamp of the last replayed event
+    pub fn get_instance_status(&self, iid: u32) -> InstanceStatus {
+        match self.instances.get(&iid) {
+            None => return InstanceStatus::NotExisting,
+            Some(instance) => return instance.status(self.last_timestamp),
+        }
+    }
+
+    // Events about unknown instances (indexing started after their creation) are ignored
+    pub fn apply(&mut self, logged_event: &LoggedEvent) {
+        self.nb_events += 1;
+        self.last_timestamp = logged_event.timestamp;
+
+        match &logged_event.event {
+            // Instance lifecycle
+            PrizeEvent::CreateInstance { iid, sponsor_address, token_identifier, token_nonce, token_amount, duration_in_s, pseudo, charity_id, premium, .. } => {
+                self.instances.insert(*iid, Instance {
+                    iid: *iid,
+                    sponsor_address: *sponsor_address,
+                    pseudo: pseudo.clone(),
+                    token_identifier: token_identifier.clone(),
+                    token_nonce: *token_nonce,
+                    token_amount: token_amount.clone(),
+                    creation_timestamp: logged_event.timestamp,
+                    deadline: logged_event.timestamp + duration_in_s,
+                    fee_policy: self.fee_policy.clone(),
+                    charity_id: *charity_id,
+                    premium: *premium,
+                    disabled: false,
+                    claimed: false,
+                    winner_address: None,
+                    winning_ticket: 0,
+                    players: Vec::new(),
+                    fees: BigUint::default(),
+                    donations: BigUint::default(),
+                    pot: BigUint::default(),
+                    reward_pool: BigUint::default(),
+                    rewards_sent: BigUint::default(),
+                    jackpot_won: BigUint::default(),
+                    nb_promo_codes: 0,
+                });
+            },
+            PrizeEvent::Play { iid, player_address, fees, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.players.push(*player_address);
+                    instance.fees += fees;
+                }
+
+                let player = self.players.entry(*player_address).or_default();
+                player.nb_plays += 1;
+                player.spent += fees;
+            },
+            PrizeEvent::Trigger { iid, winner_address, ticket_number, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.winner_address = Some(*winner_address);
+                    instance.winning_ticket = *ticket_number;
+
+                    // Prize given back to the sponsor if nobody played
+                    if *ticket_number != 0 {
+                        self.players.entry(*winner_address).or_default().nb_wins += 1;
+                    }
+                }
+            },
+            PrizeEvent::JackpotWon { iid, winner_address, jackpot, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.jackpot_won = jackpot.clone();
+                }
+                self.players.entry(*winner_address).or_default().jackpots += jackpot;
+            },
+            PrizeEvent::AutoClaimPrize { iid, .. } | PrizeEvent::ManualClaimPrize { iid, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.claimed = true;
+                }
+            },
+            PrizeEvent::CleanClaim { iid, .. } => {
+                if let Some(instance) = self.instances.remove(iid) {
+                    self.archived.insert(*iid, instance);
+                }
+            },
+
+            // Player & sponsor actions
+            // Instance donations are reported by add_donation
+            PrizeEvent::PlayerDonation { player_address, donation, .. } => {
+                self.players.entry(*player_address).or_default().donations += donation;
+            },
+            PrizeEvent::UsePromoCode { iid, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.nb_promo_codes = instance.nb_promo_codes.saturating_sub(1);
+                }
+            },
+            PrizeEvent::AddPromoCodes { iid, nb_promo_codes, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.nb_promo_codes += nb_promo_codes;
+                }
+            },
+            PrizeEvent::AddDonation { iid, charity_id, donation_amount, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.charity_id = *charity_id;
+                    instance.donations += donation_amount;
+                }
+
+                // Donations of instances without a registered charity go to the charity pool
+                match self.charities.get_mut(charity_id) {
+                    Some(charity) if *charity_id != 0 => charity.balance += donation_amount,
+                    _ => self.pools.charity_pool += donation_amount,
+                }
+            },
+            PrizeEvent::AwardLoyaltyPoints { address, points, .. } => {
+                self.players.entry(*address).or_default().loyalty_points += points;
+            },
+            PrizeEvent::RedeemLoyaltyPoints { address, points, .. } => {
+                let player = self.players.entry(*address).or_default();
+                player.loyalty_points = player.loyalty_points.saturating_sub(*points);
+            },
+            PrizeEvent::SetReferrer { address, referrer_address, .. } => {
+                self.players.entry(*address).or_default().referrer = Some(*referrer_address);
+            },
+
+            // Funds leaving the SC
+            PrizeEvent::SendRewards { iid, rewards, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.rewards_sent += rewards;
+                }
+            },
+            PrizeEvent::ClaimFees { beneficiary_address, fee_amount, .. } => {
+                self.players.entry(*beneficiary_address).or_default().fees_claimed += fee_amount;
+                self.pools.fees_claimed += fee_amount;
+            },
+            PrizeEvent::ClaimLinkRewards { link_address, reward_amount, .. } => {
+                self.players.entry(*link_address).or_default().link_rewards_claimed += reward_amount;
+                self.pools.link_rewards_claimed += reward_amount;
+            },
+            PrizeEvent::ClaimDonations { donations_amount, .. } => {
+                self.pools.charity_pool = BigUint::default();
+                self.pools.donations_claimed += donations_amount;
+            },
+            PrizeEvent::ClaimCharityDonations { charity_id, donations_amount, .. } => {
+                if let Some(charity) = self.charities.get_mut(charity_id) {
+                    charity.balance = BigUint::default();
+                }
+                self.pools.donations_claimed += donations_amount;
+            },
+            PrizeEvent::DepositBond { sponsor_address, token_identifier, bond_amount, .. } => {
+                self.bonds.insert(*sponsor_address, Bond { token_identifier: token_identifier.clone(), amount: bond_amount.clone() });
+            },
+            PrizeEvent::WithdrawBond { sponsor_address, .. } => {
+                self.bonds.remove(sponsor_address);
+            },
+
+            // Pools amounts
+            PrizeEvent::FeePoolInfo { fees, .. } => {
+                self.pools.fee_pool = fees.clone();
+            },
+            PrizeEvent::JackpotPoolInfo { jackpot_pool, .. } => {
+                self.pools.jackpot_pool = jackpot_pool.clone();
+            },
+            PrizeEvent::PotInfo { iid, pot, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.pot = pot.clone();
+                }
+            },
+            PrizeEvent::RewardPoolInfo { iid, reward_pool, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.reward_pool = reward_pool.clone();
+                }
+            },
+
+            // Setup
+            PrizeEvent::SetPremium { iid, premium_status, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.premium = *premium_status;
+                }
+            },
+            PrizeEvent::DisableInstance { iid, disable_status, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.disabled = *disable_status;
+                }
+            },
+            PrizeEvent::SlashBond { sponsor_address, slashed_amount, .. } => {
+                if let Some(bond) = self.bonds.get_mut(sponsor_address) {
+                    if bond.amount >= *slashed_amount {
+                        bond.amount -= slashed_amount;
+                    }
+                }
+            },
+            PrizeEvent::SetFeePolicy { fee_amount_egld, sponsor_reward_percent, link_reward_percent, link2_reward_percent, .. } => {
+                self.fee_policy = FeePolicy {
+                    fee_amount_egld: fee_amount_egld.clone(),
+                    sponsor_reward_percent: *sponsor_reward_percent,
+                    link_reward_percent: *link_reward_percent,
+                    link2_reward_percent: *link2_reward_percent,
+                };
+            },
+            PrizeEvent::MigrateFeePolicy { iid, fee_amount_egld, sponsor_reward_percent, link_reward_percent, link2_reward_percent, .. } => {
+                if let Some(instance) = self.instances.get_mut(iid) {
+                    instance.fee_policy = FeePolicy {
+                        fee_amount_egld: fee_amount_egld.clone(),
+                        sponsor_reward_percent: *sponsor_reward_percent,
+                        link_reward_percent: *link_reward_percent,
+                        link2_reward_percent: *link2_reward_percent,
+                    };
+                }
+            },
+            PrizeEvent::SetCharity { charity_id, address, name, enabled, .. } => {
+                let balance = match self.charities.get(charity_id) {
+                    Some(charity) => charity.balance.clone(),
+                    None => BigUint::default(),
+                };
+                self.charities.insert(*charity_id, Charity { address: *address, name: name.clone(), enabled: *enabled, balance: balance });
+            },
+            PrizeEvent::AddAddrBlacklist { address, .. } => {
+                self.blacklist.insert(*address);
+            },
+            PrizeEvent::RmAddrBlacklist { address, .. } => {
+                self.blacklist.remove(address);
+            },
+
+            // Log status & parameters are not modelled, query the contract views
+            PrizeEvent::SetLogEnableStatus { .. }
+            | PrizeEvent::SetLogCategoryStatus { .. }
+            | PrizeEvent::SetBondPolicy { .. }
+            | PrizeEvent::SetFeeBeneficiary { .. }
+            | PrizeEvent::SetJackpotParams { .. }
+            | PrizeEvent::SetLoyaltyPolicy { .. }
+            | PrizeEvent::SetParamDuration { .. }
+            | PrizeEvent::SetParamSponsorInfoMaxLength { .. }
+            | PrizeEvent::SetParamNbMaxInstancesPerSponsor { .. }
+            | PrizeEvent::SetParamPotWinnerPercent { .. }
+            | PrizeEvent::SetParamManualClaim { .. }
+            | PrizeEvent::MigrateIndexes { .. } => {},
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+use crate::address::Address;
+use elrond_wasm::elrond_codec::TopDecode;
+use num_bigint::BigUint;
+use std::fmt;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    InvalidBase64(String),
+    InvalidContractAddress(String),
+    UnsupportedVersion { event: String, version: u8 },
+    MissingTopic { event: String, position: usize },
+    InvalidTopic { event: String, position: usize, reason: String },
+    UnexpectedTopics { event: String, nb_topics: usize },
+}
+
+// Sequential reader of the top-encoded event topics
+pub struct TopicReader {
+    event: String,
+    topics: Vec<Vec<u8>>,
+    position: usize,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::InvalidBase64(value) => write!(f, "invalid base64 value '{}'", value),
+            DecodeError::InvalidContractAddress(address) => write!(f, "invalid contract address '{}'", address),
+            DecodeError::UnsupportedVersion { event, version } => write!(f, "event {} : unsupported schema version {}", event, version),
+            DecodeError::MissingTopic { event, position } => write!(f, "event {} : missing topic #{}", event, position),
+            DecodeError::InvalidTopic { event, position, reason } => write!(f, "event {} : invalid topic #{} ({})", event, position, reason),
+            DecodeError::UnexpectedTopics { event, nb_topics } => write!(f, "event {} : unexpected number of topics ({})", event, nb_topics),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl TopicReader {
+    // Topics exclude the event identifier
+    pub fn new(event: &str, topics: Vec<Vec<u8>>) -> Self {
+        return TopicReader {
+            event: String::from(event),
+            topics: topics,
+            position: 0,
+        };
+    }
+
+    fn next(&mut self) -> Result<&[u8], DecodeError> {
+        if self.position >= self.topics.len() {
+            return Err(DecodeError::MissingTopic { event: self.event.clone(), position: self.position });
+        }
+
+        self.position += 1;
+
+        return Ok(&self.topics[self.position - 1]);
+    }
+
+    fn invalid(&self, reason: &str) -> DecodeError {
+        return DecodeError::InvalidTopic { event: self.event.clone(), position: self.position - 1, reason: String::from(reason) };
+    }
+
+    // Integers are top-encoded as big endian bytes without leading zeros
+    fn read_unsigned(&mut self, max_length: usize) -> Result<u64, DecodeError> {
+        let bytes = self.next()?.to_vec();
+        if bytes.len() > max_length {
+            return Err(self.invalid("integer overflow"));
+        }
+
+        let mut value: u64 = 0;
+        for byte in bytes.iter() {
+            value = (value << 8) | (*byte as u64);
+        }
+
+        return Ok(value);
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        return Ok(self.read_unsigned(1)? as u8);
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        return Ok(self.read_unsigned(4)? as u32);
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        return self.read_unsigned(8);
+    }
+
+    // usize is 32 bits long in the contract
+    pub fn read_usize(&mut self) -> Result<usize, DecodeError> {
+        return Ok(self.read_unsigned(4)? as usize);
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, DecodeError> {
+        let bytes = self.next()?.to_vec();
+
+        match bytes.as_slice() {
+            [] => return Ok(false),
+            [1] => return Ok(true),
+            _ => return Err(self.invalid("invalid boolean")),
+        }
+    }
+
+    pub fn read_address(&mut self) -> Result<Address, DecodeError> {
+        let bytes = self.next()?.to_vec();
+
+        match Address::from_bytes(&bytes) {
+            Some(address) => return Ok(address),
+            None => return Err(self.invalid("invalid address length")),
+        }
+    }
+
+    pub fn read_big_uint(&mut self) -> Result<BigUint, DecodeError> {
+        return Ok(BigUint::from_bytes_be(self.next()?));
+    }
+
+    // Token identifiers, pseudos & names
+    pub fn read_string(&mut self) -> Result<String, DecodeError> {
+        return Ok(String::from_utf8_lossy(self.next()?).into_owned());
+    }
+
+    // Types defined by the contract are decoded with their own codec
+    pub fn read_top_decode<T: TopDecode>(&mut self) -> Result<T, DecodeError> {
+        let bytes = self.next()?.to_vec();
+
+        match T::top_decode(&bytes[..]) {
+            Ok(value) => return Ok(value),
+            Err(error) => return Err(self.invalid(&String::from_utf8_lossy(error.message_bytes()))),
+        }
+    }
+
+    // All topics must have been read
+    pub fn finish(&self) -> Result<(), DecodeError> {
+        if self.position != self.topics.len() {
+            return Err(DecodeError::UnexpectedTopics { event: self.event.clone(), nb_topics: self.topics.len() });
+        }
+
+        return Ok(());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,156 @@
+use elrond_wasm::types::{BigUint as ManagedBigUint, ManagedAddress, ManagedBuffer};
+use elrond_wasm_debug::DebugApi;
+use num_bigint::BigUint;
+use prize_indexer::*;
+
+const CONTRACT_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqenxvenxvenxvenxvenxvenxvenxvenxvenxqe5am7y";
+const SPONSOR_ADDRESS: &str = "erd1qyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqsl6e0p7";
+const PLAYER1_ADDRESS: &str = "erd1qgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqjv7g5k";
+const PLAYER2_ADDRESS: &str = "erd1qvpsxqcrqvpsxqcrqvpsxqcrqvpsxqcrqvpsxqcrqvpsxqcrqvpsh78jz5";
+const PLAYER3_ADDRESS: &str = "erd1qszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqxjfvxn";
+
+// Owner sets the fee policy, sponsor creates instance #1, player #1 plays, player #2 plays with a donation,
+// player #3 is blacklisted, instance #1 is triggered with auto-claim, then cleaned
+const LIFECYCLE_FIXTURE: &str = include_str!("fixtures/lifecycle.json");
+
+// Play event logged before the event schema was versioned
+const LEGACY_FIXTURE: &str = include_str!("fixtures/legacy.json");
+
+fn address(bech32_address: &str) -> Address {
+    return Address::from_bech32(bech32_address).unwrap();
+}
+
+fn egld(milli_egld: u64) -> BigUint {
+    return BigUint::from(milli_egld) * BigUint::from(1_000_000_000_000_000u64);
+}
+
+#[test]
+fn decode_lifecycle_events() {
+    let transactions = parse_transactions(LIFECYCLE_FIXTURE).unwrap();
+    let events = decode_transactions(&transactions, CONTRACT_ADDRESS).unwrap();
+
+    // writeLog & events of other contracts are ignored
+    assert_eq!(events.len(), 15);
+
+    let play_events: Vec<&LoggedEvent> = events.iter().filter(|logged_event| logged_event.event.identifier() == "play").collect();
+    assert_eq!(play_events.len(), 2);
+    assert_eq!(play_events[1].timestamp, 1650000200);
+    assert!(play_events[1].event == PrizeEvent::Play {
+        iid: 1,
+        player_address: address(PLAYER2_ADDRESS),
+        epoch: 500,
+        ticket_number: 2,
+        fees: egld(10),
+    });
+    assert!(play_events[1].event.category() == EventCategory::Lifecycle);
+
+    // Events logged in smart contract results are decoded
+    let last_events: Vec<&str> = events.iter().rev().take(4).map(|logged_event| logged_event.event.identifier()).collect();
+    assert_eq!(last_events, vec!["clean_claim", "trigger", "auto_claim_prize", "send_rewards"]);
+}
+
+#[test]
+fn replay_lifecycle_until_claim() {
+    let transactions = parse_transactions(LIFECYCLE_FIXTURE).unwrap();
+    let state = index_transactions(&transactions[..6], CONTRACT_ADDRESS).unwrap();
+
+    assert!(state.get_instance_status(1) == InstanceStatus::Claimed);
+
+    let instance = state.instances.get(&1).unwrap();
+    assert_eq!(instance.sponsor_address, address(SPONSOR_ADDRESS));
+    assert_eq!(instance.pseudo, "Elrond");
+    assert_eq!(instance.token_identifier, "EGLD");
+    assert_eq!(instance.token_amount, egld(1000));
+    assert_eq!(instance.deadline, 1650003600);
+    assert_eq!(instance.fee_policy, FeePolicy { fee_amount_egld: egld(10), sponsor_reward_percent: 10, link_reward_percent: 5, link2_reward_percent: 2 });
+    assert_eq!(instance.charity_id, 0);
+    assert_eq!(instance.premium, false);
+    assert_eq!(instance.players, vec![address(PLAYER1_ADDRESS), address(PLAYER2_ADDRESS)]);
+    assert_eq!(instance.winner_address, Some(address(PLAYER2_ADDRESS)));
+    assert_eq!(instance.winning_ticket, 2);
+    assert_eq!(instance.fees, egld(20));
+    assert_eq!(instance.donations, egld(5));
+    assert_eq!(instance.reward_pool, egld(2));
+    assert_eq!(instance.rewards_sent, egld(2));
+
+    let player2 = state.players.get(&address(PLAYER2_ADDRESS)).unwrap();
+    assert_eq!(player2.nb_plays, 1);
+    assert_eq!(player2.spent, egld(10));
+    assert_eq!(player2.donations, egld(5));
+    assert_eq!(player2.nb_wins, 1);
+    assert_eq!(state.players.get(&address(PLAYER1_ADDRESS)).unwrap().nb_wins, 0);
+
+    assert_eq!(state.pools.fee_pool, egld(14));
+    assert_eq!(state.pools.charity_pool, egld(5));
+    assert!(state.blacklist.contains(&address(PLAYER3_ADDRESS)));
+}
+
+#[test]
+fn convert_contract_types() {
+    let _ = DebugApi::dummy();
+
+    let fee_policy = prize::FeePolicy::<DebugApi> {
+        fee_amount_egld: ManagedBigUint::from(10_000_000_000_000_000u64),
+        sponsor_reward_percent: 10,
+        link_reward_percent: 5,
+    };
+    assert_eq!(FeePolicy::from((&fee_policy, 2u8)), FeePolicy { fee_amount_egld: egld(10), sponsor_reward_percent: 10, link_reward_percent: 5, link2_reward_percent: 2 });
+
+    let charity = prize::GetCharityStruct::<DebugApi> {
+        charity_id: 1,
+        charity_info: prize::CharityInfo {
+            address: ManagedAddress::from(&address(PLAYER3_ADDRESS).0),
+            name: ManagedBuffer::from(&b"Charity"[..]),
+            url: ManagedBuffer::new(),
+            enabled: true,
+        },
+        balance: ManagedBigUint::from(5_000_000_000_000_000u64),
+        lifetime_donations: ManagedBigUint::zero(),
+    };
+    assert_eq!(Charity::from(&charity), Charity { address: address(PLAYER3_ADDRESS), name: String::from("Charity"), enabled: true, balance: egld(5) });
+}
+
+#[test]
+fn replay_status_before_deadline() {
+    let transactions = parse_transactions(LIFECYCLE_FIXTURE).unwrap();
+    let mut state = index_transactions(&transactions[..5], CONTRACT_ADDRESS).unwrap();
+    assert!(state.get_instance_status(1) == InstanceStatus::Running);
+
+    // Instance ends once the deadline is over, even without event
+    state.last_timestamp = 1650003601;
+    assert!(state.get_instance_status(1) == InstanceStatus::Ended);
+}
+
+#[test]
+fn replay_clean_claim_archives_instance() {
+    let transactions = parse_transactions(LIFECYCLE_FIXTURE).unwrap();
+    let state = index_transactions(&transactions, CONTRACT_ADDRESS).unwrap();
+
+    assert!(state.get_instance_status(1) == InstanceStatus::NotExisting);
+    assert_eq!(state.instances.len(), 0);
+    assert_eq!(state.archived.get(&1).unwrap().winner_address, Some(address(PLAYER2_ADDRESS)));
+    assert_eq!(state.nb_events, 15);
+}
+
+#[test]
+fn reject_legacy_events() {
+    let transactions = parse_transactions(LEGACY_FIXTURE).unwrap();
+
+    match decode_transactions(&transactions, CONTRACT_ADDRESS) {
+        Err(DecodeError::UnsupportedVersion { event, version }) => {
+            assert_eq!(event, "play");
+            assert_eq!(version, 1);
+        },
+        _ => panic!("legacy event should be rejected"),
+    }
+}
+
+#[test]
+fn reject_invalid_contract_address() {
+    let transactions = parse_transactions(LIFECYCLE_FIXTURE).unwrap();
+
+    assert_eq!(
+        decode_transactions(&transactions, "erd1invalid").err(),
+        Some(DecodeError::InvalidContractAddress(String::from("erd1invalid")))
+    );
+}
\ No newline at end of file
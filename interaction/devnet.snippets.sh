@@ -57,9 +57,42 @@ disable() {
 
 # Param1 : fees amount in EGLD
 # Param2 : sponsor reward in percent
-# Param3 : link reward in percent
+# Param3 : link reward in percent (direct referrer)
+# Param4 : link reward in percent (referrer of the referrer)
 setFeePol() {
-    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setFeePol" --arguments $1 $2 $3 --send --proxy=${PROXY} --chain=${CHAIN}
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setFeePol" --arguments $1 $2 $3 $4 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Var params : optional instance IDs (all running instances if none is provided)
+migrateFeePol() {
+    if [ $# == 0 ]; then
+        erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=500000000 --function="migrateFeePol" --send --proxy=${PROXY} --chain=${CHAIN}
+    else
+        erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=500000000 --function="migrateFeePol" --arguments $* --send --proxy=${PROXY} --chain=${CHAIN}
+    fi
+}
+
+# Param1 : number of instances to migrate in this batch (call again until the returned next IID is 0)
+migrateIndexes() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=500000000 --function="migrateIndexes" --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Param1 : bond token ID
+# Param2 : bond amount (0 to disable the bond)
+setBondPol() {
+    TOKEN_ID="0x$(xxd -pu -c 256 <<< $1)"
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setBondPol" --arguments ${TOKEN_ID::-2} $2 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+getBondPol() {
+    erdpy --verbose contract query ${ADDRESS} --function="getBondPol" --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+# Param2 : slash percent
+# Param3 : slash to charity status
+slashBond() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="slashBond" --arguments $1 $2 $3 --send --proxy=${PROXY} --chain=${CHAIN}
 }
 
 getFeePol() {
@@ -77,8 +110,47 @@ getLinkRewardPool() {
     erdpy --verbose contract query ${ADDRESS} --function="getLinkRewardPool" --arguments "0x${HEX_ADDRESS}" --proxy=${PROXY} 
 }
 
+# Param1 : pem wallet
+# Param2 : referrer pem wallet
+setReferrer() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $2`    
+    HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$1 --gas-limit=50000000 --function="setReferrer" --arguments "0x${HEX_ADDRESS}" --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Param1 : address
+getAffiliateInfo() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+    erdpy --verbose contract query ${ADDRESS} --function="getAffiliateInfo" --arguments "0x${HEX_ADDRESS}" --proxy=${PROXY} 
+}
+
+# Param1 : beneficiary pem wallet
+# Param2 : weight (0 to remove the beneficiary)
+setFeeBeneficiary() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setFeeBeneficiary" --arguments "0x${HEX_ADDRESS}" $2 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+getFeeBeneficiaries() {
+    erdpy --verbose contract query ${ADDRESS} --function="getFeeBeneficiaries" --proxy=${PROXY} 
+}
+
+# Param1 : beneficiary pem wallet
+getFeeBalance() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+    erdpy --verbose contract query ${ADDRESS} --function="getFeeBalance" --arguments "0x${HEX_ADDRESS}" --proxy=${PROXY} 
+}
+
+# Param1 : beneficiary pem wallet
 claimFees() {
-    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="claimFees" --send --proxy=${PROXY} --chain=${CHAIN}
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$1 --gas-limit=50000000 --function="claimFees" --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+pushFees() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=100000000 --function="pushFees" --send --proxy=${PROXY} --chain=${CHAIN}
 }
 
 # Param #1 : pem wallet
@@ -94,6 +166,52 @@ claimDonations() {
     erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="claimDonations" --send --proxy=${PROXY} --chain=${CHAIN}
 }
 
+# Param1 : charity pem wallet
+# Param2 : charity name
+# Param3 : charity url
+addCharity() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+    NAME="0x$(xxd -pu -c 256 <<< "$2")"
+    URL="0x$(xxd -pu -c 256 <<< "$3")"
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="addCharity" --arguments "0x${HEX_ADDRESS}" ${NAME} ${URL} --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+getCharities() {
+    erdpy --verbose contract query ${ADDRESS} --function="getCharities" --proxy=${PROXY} 
+}
+
+# Param1 : charity ID
+# Param2 : charity pem wallet
+claimCharityDonations() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$2 --gas-limit=50000000 --function="claimCharityDonations" --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Param1 : percent of fees feeding the jackpot
+# Param2 : chance to win the jackpot at each trigger, in basis points (1/10000)
+setJackpotParams() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setJackpotParams" --arguments $1 $2 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+getJackpotParams() {
+    erdpy --verbose contract query ${ADDRESS} --function="getJackpotParams" --proxy=${PROXY} 
+}
+
+getJackpotPool() {
+    erdpy --verbose contract query ${ADDRESS} --function="getJackpotPool" --proxy=${PROXY} 
+}
+
+# Param1 : loyalty points per EGLD of fees
+# Param2 : loyalty points per win
+# Param3 : loyalty points cost of a free entry (0 to disable)
+setLoyaltyPol() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setLoyaltyPol" --arguments $1 $2 $3 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+getLoyaltyPol() {
+    erdpy --verbose contract query ${ADDRESS} --function="getLoyaltyPol" --proxy=${PROXY} 
+}
+
 # Param1 : manual claim enable status
 setParamManClaim() {
     erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setParamManClaim" --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN}
@@ -131,8 +249,25 @@ getParamSponsorInfoMaxLength() {
     erdpy --verbose contract query ${ADDRESS} --function="getParamSponsorInfoMaxLength" --proxy=${PROXY} 
 }
 
+# Param1 : percent of the fees given to the winner of instances funded by tickets
+setParamPotWinnerPercent() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setParamPotWinnerPercent" --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+getParamPotWinnerPercent() {
+    erdpy --verbose contract query ${ADDRESS} --function="getParamPotWinnerPercent" --proxy=${PROXY} 
+}
+
+# Param1 : cursor ('0' for the first page, then hex address returned as next cursor by the previous page)
+# Param2 : max number of addresses to return
 getAddrBlacklist() {
-    erdpy --verbose contract query ${ADDRESS} --function="getAddrBlacklist" --proxy=${PROXY} 
+    if [ $1 == "0" ]; then
+        CURSOR_HEX_ADDRESS=$ADDR_ZERO
+    else
+        CURSOR_HEX_ADDRESS=$1
+    fi
+
+    erdpy --verbose contract query ${ADDRESS} --function="getAddrBlacklist" --arguments ${CURSOR_HEX_ADDRESS} $2 --proxy=${PROXY} 
 }
 
 # Param1 : address to blacklist
@@ -158,6 +293,17 @@ getLogEnableStatus() {
     erdpy --verbose contract query ${ADDRESS} --function="getLogEnableStatus" --proxy=${PROXY} 
 }
 
+# Param #1 : event category (0 Lifecycle, 1 Player, 2 Treasury, 3 PoolInfo, 4 Setup)
+# Param #2 : enable status (0 or 1)
+setLogCategoryStatus() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER} --gas-limit=50000000 --function="setLogCategoryStatus" --arguments $1 $2 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Param #1 : event category (0 Lifecycle, 1 Player, 2 Treasury, 3 PoolInfo, 4 Setup)
+getLogCategoryStatus() {
+    erdpy --verbose contract query ${ADDRESS} --function="getLogCategoryStatus" --arguments $1 --proxy=${PROXY} 
+}
+
 ######################################################################
 # DApp endpoints : sponsor API
 ######################################################################
@@ -325,6 +471,23 @@ createEgld_21() {
 
 
 
+# Param #1 : duration in seconds
+# Param #2 : pem wallet
+createPot() {
+    PSEUDO="0x$(xxd -pu -c 256 <<< "Elrond")"
+    URL1="0x$(xxd -pu -c 256  <<< "https://elrond.com/")"
+    URL2="0x$(xxd -pu -c 256  <<< "")"
+    URL3="0x$(xxd -pu -c 256  <<< "")"
+    RESERVED="0x$(xxd -pu -c 256  <<< "")"
+    GRAPHIC="0x$(xxd -pu -c 256  <<< "cover")"
+    LOGO_LINK="0x$(xxd -pu -c 256  <<< "https://image.shutterstock.com/z/stock-vector-elrond-egld-token-coin-symbol-with-crypto-currency-themed-background-design-modern-blue-neon-color-1912925707.jpg")"
+    FREE_TEXT="0x$(xxd -pu -c 256  <<< "50/50 : the winner takes the pot !")"
+    PREMIUM="0"
+    CHARITY="0"
+
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$2 --gas-limit=500000000 --function="createPot" --arguments $1 ${PSEUDO} ${URL1} ${URL2} ${URL3} ${RESERVED} ${GRAPHIC} ${LOGO_LINK} ${FREE_TEXT} ${PREMIUM} ${CHARITY} --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
 # Param #1 : duration in seconds
 # Param #2 : pem wallet
 # Param #3 : Token ID
@@ -387,6 +550,37 @@ createNft() {
     erdpy --verbose tx new --receiver=${BECH32_PEM_WALLET} --recall-nonce --pem=$2 --gas-limit=50000000 --data=${TX_DATA} --send --proxy=${PROXY} --chain=${CHAIN}
 }
 
+# Param #1 : pem wallet
+# Param #2 : bond amount in EGLD
+depositBondEgld() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$1 --gas-limit=50000000 --function="depositBond" --value=$2 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Param #1 : pem wallet
+withdrawBond() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$1 --gas-limit=50000000 --function="withdrawBond" --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Param #1 : sponsor pem wallet
+getSponsorBond() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+    erdpy --verbose contract query ${ADDRESS} --function="getSponsorBond" --arguments "0x${HEX_ADDRESS}" --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+# Param2 : sponsor pem wallet
+# Param3 : discount : #1000000000000000 => 0.001 EGLD
+# Var params : promo codes (in clear, hashed before sending)
+addPromoCodes() {
+    PROMO_CODE_HASHES=""
+    for PROMO_CODE in "${@:4}"; do
+        PROMO_CODE_HASHES="${PROMO_CODE_HASHES} 0x$(echo -n ${PROMO_CODE} | openssl dgst -keccak-256 | awk '{print $NF}')"
+    done
+
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$2 --gas-limit=50000000 --function="addPromoCodes" --arguments $1 $3 ${PROMO_CODE_HASHES} --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
 # Param1 : Instance ID
 # Param2 : pem wallet
 trigger() {
@@ -399,7 +593,7 @@ trigger() {
 
 # Param1 : Instance ID
 # Param2 : pem wallet
-# Param3 : fees : #1000000000000000 => 0.001 EGLD
+# Param3 : fees : #1000000000000000 => 0.001 EGLD (may include a donation for charity instances)
 play() {
     erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$2 --gas-limit=10000000 --function="play" --value=$3 --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN}
 }
@@ -416,6 +610,21 @@ playLinked() {
     erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$2 --gas-limit=10000000 --function="play" --value=$3 --arguments $1 "0x${HEX_ADDRESS}" --send --proxy=${PROXY} --chain=${CHAIN}
 }
 
+# Param1 : Instance ID
+# Param2 : pem wallet
+# Param3 : fees after discount : #1000000000000000 => 0.001 EGLD
+# Param4 : promo code
+playWithCode() {
+    PROMO_CODE="0x$(echo -n $4 | xxd -pu -c 256)"
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$2 --gas-limit=10000000 --function="playWithCode" --value=$3 --arguments $1 ${PROMO_CODE} --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
+# Param1 : Instance ID
+# Param2 : pem wallet
+playWithPoints() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$2 --gas-limit=10000000 --function="playWithPoints" --arguments $1 --send --proxy=${PROXY} --chain=${CHAIN}
+}
+
 # Param1 : Instance ID
 # Param2 : pem wallet
 claimPrize() {
@@ -455,6 +664,8 @@ getInfo() {
 }
 
 # Param1 : player pem wallet or '0'
+# Param2 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param3 : max number of instances to return
 # Var params : Instance status filter (from 1 to 5 status can be provided)
 getAllInfo() {
     if [ $1 == "0" ]; then
@@ -471,6 +682,7 @@ getAllInfo() {
     erdpy --verbose contract query ${ADDRESS} --function="getAllInfo" --arguments $* --proxy=${PROXY} 
 }
 
+# Deprecated, use getAllInfo
 # Param1 : player pem wallet or '0'
 # Param2 : start iid
 # Param3 : max number of instances to return
@@ -490,6 +702,40 @@ getAllInfoFrag() {
     erdpy --verbose contract query ${ADDRESS} --function="getAllInfoFrag" --arguments $* --proxy=${PROXY} 
 }
 
+# Param1 : player pem wallet or '0'
+# Param2 : filter, hex encoded InstanceFilter structure (e.g. 0x0000000000000000000000000000000000000000000000000000000000 for no filter)
+# Param3 : sort (0: creation, 1: deadline soonest, 2: number of players)
+# Param4 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param5 : max number of instances to return (1 to 50)
+getInstances() {
+    if [ $1 == "0" ]; then
+        PLAYER_HEX_ADDRESS=$ADDR_ZERO
+    else
+        BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+        PLAYER_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+        PLAYER_HEX_ADDRESS="0x${PLAYER_HEX_ADDRESS}"
+    fi
+
+    erdpy --verbose contract query ${ADDRESS} --function="getInstances" --arguments $PLAYER_HEX_ADDRESS $2 $3 $4 $5 --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+getSummary() {
+    erdpy --verbose contract query ${ADDRESS} --function="getSummary" --arguments $1 --proxy=${PROXY} 
+}
+
+# Param1 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param2 : max number of instances to return
+# Var params : Instance status filter (from 1 to 5 status can be provided)
+getAllSummary() {
+    erdpy --verbose contract query ${ADDRESS} --function="getAllSummary" --arguments $* --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+getSponsorInfo() {
+    erdpy --verbose contract query ${ADDRESS} --function="getSponsorInfo" --arguments $1 --proxy=${PROXY} 
+}
+
 # Param1 : Instance ID
 getRemainingTime() {
     erdpy --verbose contract query ${ADDRESS} --function="getRemainingTime" --arguments $1 --proxy=${PROXY} 
@@ -500,25 +746,41 @@ hasStatus() {
     erdpy --verbose contract query ${ADDRESS} --function="hasStatus" --arguments $1 --proxy=${PROXY} 
 }
 
+# Param1 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param2 : max number of IDs to return
 # Var params : Instance status filter (from 1 to 5 status can be provided)
 getIDs() {
     erdpy --verbose contract query ${ADDRESS} --function="getIDs" --arguments $* --proxy=${PROXY} 
 }
 
 # Param1 : Sponsor pem wallet 
+# Param2 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param3 : max number of IDs to return
 getSponsorIDs() {
     BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
     SPONSOR_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
     
-    erdpy --verbose contract query ${ADDRESS} --function="getSponsorIDs" --arguments "0x${SPONSOR_HEX_ADDRESS}" --proxy=${PROXY} 
+    erdpy --verbose contract query ${ADDRESS} --function="getSponsorIDs" --arguments "0x${SPONSOR_HEX_ADDRESS}" $2 $3 --proxy=${PROXY} 
 }
 
 # Param1 : Player pem wallet
+# Param2 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param3 : max number of IDs to return
 getPlayerIDs() {
     BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
     PLAYER_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
 
-    erdpy --verbose contract query ${ADDRESS} --function="getPlayerIDs" --arguments "0x${PLAYER_HEX_ADDRESS}" --proxy=${PROXY} 
+    erdpy --verbose contract query ${ADDRESS} --function="getPlayerIDs" --arguments "0x${PLAYER_HEX_ADDRESS}" $2 $3 --proxy=${PROXY} 
+}
+
+# Param1 : Player pem wallet
+# Param2 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param3 : max number of instances to return
+getPlayerHistory() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    PLAYER_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+
+    erdpy --verbose contract query ${ADDRESS} --function="getPlayerHistory" --arguments "0x${PLAYER_HEX_ADDRESS}" $2 $3 --proxy=${PROXY} 
 }
 
 # Param1 : Instance ID
@@ -542,3 +804,72 @@ hasWon() {
 getTotalPlayers() {
     erdpy --verbose contract query ${ADDRESS} --function="getTotalPlayers" --proxy=${PROXY} 
 }
+
+# Param1 : player pem wallet
+getLoyaltyPoints() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    PLAYER_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+
+    erdpy --verbose contract query ${ADDRESS} --function="getLoyaltyPoints" --arguments "0x${PLAYER_HEX_ADDRESS}" --proxy=${PROXY} 
+}
+
+# Param1 : max number of ranks
+getLoyaltyRanking() {
+    erdpy --verbose contract query ${ADDRESS} --function="getLoyaltyRanking" --arguments $1 --proxy=${PROXY} 
+}
+
+# Param1 : leaderboard kind (0: sponsor instances, 1: sponsor prizes, 2: player plays, 3: player wins, 4: affiliate plays, 5: affiliate earnings, 6: loyalty points)
+# Param2 : max number of entries
+getLeaderboard() {
+    erdpy --verbose contract query ${ADDRESS} --function="getLeaderboard" --arguments $1 $2 --proxy=${PROXY} 
+}
+
+getStats() {
+    erdpy --verbose contract query ${ADDRESS} --function="getStats" --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+# Param2 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param3 : max number of tickets to return
+getTickets() {
+    erdpy --verbose contract query ${ADDRESS} --function="getTickets" --arguments $1 $2 $3 --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+# Param2 : Player pem wallet
+getTicketNumber() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $2`    
+    PLAYER_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+
+    erdpy --verbose contract query ${ADDRESS} --function="getTicketNumber" --arguments $1 "0x${PLAYER_HEX_ADDRESS}" --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+getWinningTicket() {
+    erdpy --verbose contract query ${ADDRESS} --function="getWinningTicket" --arguments $1 --proxy=${PROXY} 
+}
+
+# Param1 : Instance ID
+getArchive() {
+    erdpy --verbose contract query ${ADDRESS} --function="getArchive" --arguments $1 --proxy=${PROXY} 
+}
+
+# Param1 : Sponsor pem wallet
+# Param2 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param3 : max number of records to return
+getSponsorArchive() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    SPONSOR_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+
+    erdpy --verbose contract query ${ADDRESS} --function="getSponsorArchive" --arguments "0x${SPONSOR_HEX_ADDRESS}" $2 $3 --proxy=${PROXY} 
+}
+
+# Param1 : Winner pem wallet
+# Param2 : cursor (0 for the first page, then next cursor returned by the previous page)
+# Param3 : max number of records to return
+getWinnerArchive() {
+    BECH32_PEM_WALLET=`grep -o -m 1 "erd[0-9a-z]*" $1`    
+    WINNER_HEX_ADDRESS=`${SCRIPT_PATH}/${BECH32_UTIL} $BECH32_PEM_WALLET`
+
+    erdpy --verbose contract query ${ADDRESS} --function="getWinnerArchive" --arguments "0x${WINNER_HEX_ADDRESS}" $2 $3 --proxy=${PROXY} 
+}
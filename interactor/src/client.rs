@@ -0,0 +1,439 @@
+use crate::codec::{Api, ReturnData};
+use crate::error::ClientError;
+use crate::profile::{Profile, GAS_PRICE, TX_VERSION};
+use crate::proxy::Proxy;
+use crate::transaction::{ContractCall, Transaction};
+use crate::wallet::Wallet;
+use num_bigint::BigUint;
+use prize::{
+    AffiliateInfo, ArchiveInfo, EventCategory, GetCharityStruct, GetInfoStruct, GetPlayerHistoryStruct, GetPlayerTotalsStruct, GetStatsStruct, GetSummaryStruct,
+    InstanceFilter, InstanceSort, InstanceStatus, LeaderboardEntry, LeaderboardKind, SponsorInfo,
+};
+use prize_indexer::Address;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Page of results and cursor of the next page (0 when there is no more result)
+pub type Page<T> = (u32, Vec<T>);
+
+pub struct PrizeClient {
+    pub profile: Profile,
+    pub contract_address: Address,
+    proxy: Proxy,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl PrizeClient {
+    pub fn new(profile: Profile) -> Result<Self, ClientError> {
+        let contract_address = profile.get_contract_address()?;
+        let proxy = Proxy::new(&profile.proxy);
+
+        return Ok(PrizeClient {
+            profile: profile,
+            contract_address: contract_address,
+            proxy: proxy,
+        });
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Transactions
+    /////////////////////////////////////////////////////////////////////
+
+    // Returns : transaction signed offline
+    pub fn sign(&self, wallet: &Wallet, nonce: u64, call: &ContractCall) -> Transaction {
+        let mut transaction = call.to_transaction(&self.contract_address, &wallet.address(), nonce, &self.profile.chain_id, GAS_PRICE, TX_VERSION);
+        transaction.sign(wallet);
+
+        return transaction;
+    }
+
+    // Returns : hash of the transaction, signed with the current nonce of the wallet
+    pub fn send(&self, wallet: &Wallet, call: &ContractCall) -> Result<String, ClientError> {
+        let nonce = self.proxy.get_nonce(&wallet.address())?;
+        return self.proxy.send(&self.sign(wallet, nonce, call));
+    }
+
+    pub fn send_signed(&self, transaction: &Transaction) -> Result<String, ClientError> {
+        return self.proxy.send(transaction);
+    }
+
+    fn query(&self, view: ContractCall) -> Result<ReturnData, ClientError> {
+        let results = self.proxy.query(&self.contract_address, &view.function, &view.args)?;
+        return Ok(ReturnData::new(&view.function, results));
+    }
+
+    // Views returning a single value
+    fn query_value<T: elrond_wasm::elrond_codec::TopDecode>(&self, view: ContractCall) -> Result<T, ClientError> {
+        let mut results = self.query(view)?;
+        let value: T = results.read()?;
+        results.finish()?;
+
+        return Ok(value);
+    }
+
+    fn query_big_uint(&self, view: ContractCall) -> Result<BigUint, ClientError> {
+        let mut results = self.query(view)?;
+        let value = results.read_big_uint()?;
+        results.finish()?;
+
+        return Ok(value);
+    }
+
+    // Views returning a page : next cursor, then one result per item
+    fn query_page<T: elrond_wasm::elrond_codec::TopDecode>(&self, view: ContractCall) -> Result<Page<T>, ClientError> {
+        let mut results = self.query(view)?;
+        let next_cursor: u32 = results.read()?;
+
+        return Ok((next_cursor, results.read_remaining()?));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries : instances
+    /////////////////////////////////////////////////////////////////////
+    pub fn get_status(&self, iid: u32) -> Result<InstanceStatus, ClientError> {
+        return self.query_value(ContractCall::view("getStatus").arg(&iid));
+    }
+
+    pub fn has_status(&self, instance_status: InstanceStatus) -> Result<bool, ClientError> {
+        return self.query_value(ContractCall::view("hasStatus").arg(&instance_status));
+    }
+
+    pub fn get_nb(&self, status_filter: &[InstanceStatus]) -> Result<u32, ClientError> {
+        return self.query_value(ContractCall::view("getNb").var_args(status_filter));
+    }
+
+    pub fn get_remaining_time(&self, iid: u32) -> Result<u64, ClientError> {
+        return self.query_value(ContractCall::view("getRemainingTime").arg(&iid));
+    }
+
+    pub fn get_ids(&self, cursor: u32, limit: u32, status_filter: &[InstanceStatus]) -> Result<Page<u32>, ClientError> {
+        return self.query_page(ContractCall::view("getIDs").arg(&cursor).arg(&limit).var_args(status_filter));
+    }
+
+    pub fn has_won(&self, iid: u32, player_address: &Address) -> Result<bool, ClientError> {
+        return self.query_value(ContractCall::view("hasWon").arg(&iid).arg(player_address));
+    }
+
+    pub fn get_info(&self, iid: u32, player_address: &Address) -> Result<GetInfoStruct<Api>, ClientError> {
+        return self.query_value(ContractCall::view("getInfo").arg(&iid).arg(player_address));
+    }
+
+    pub fn get_all_info(&self, player_address: &Address, cursor: u32, limit: u32, status_filter: &[InstanceStatus]) -> Result<Page<GetInfoStruct<Api>>, ClientError> {
+        return self.query_page(ContractCall::view("getAllInfo").arg(player_address).arg(&cursor).arg(&limit).var_args(status_filter));
+    }
+
+    pub fn get_instances(&self, player_address: &Address, filter: &InstanceFilter<Api>, sort: InstanceSort, cursor: u32, limit: u32) -> Result<Page<GetInfoStruct<Api>>, ClientError> {
+        return self.query_page(ContractCall::view("getInstances").arg(player_address).arg(filter).arg(&sort).arg(&cursor).arg(&limit));
+    }
+
+    pub fn get_summary(&self, iid: u32) -> Result<GetSummaryStruct<Api>, ClientError> {
+        return self.query_value(ContractCall::view("getSummary").arg(&iid));
+    }
+
+    pub fn get_all_summary(&self, cursor: u32, limit: u32, status_filter: &[InstanceStatus]) -> Result<Page<GetSummaryStruct<Api>>, ClientError> {
+        return self.query_page(ContractCall::view("getAllSummary").arg(&cursor).arg(&limit).var_args(status_filter));
+    }
+
+    pub fn get_sponsor_info(&self, iid: u32) -> Result<SponsorInfo<Api>, ClientError> {
+        return self.query_value(ContractCall::view("getSponsorInfo").arg(&iid));
+    }
+
+    pub fn get_sponsor_ids(&self, sponsor_address: &Address, cursor: u32, limit: u32) -> Result<Page<u32>, ClientError> {
+        return self.query_page(ContractCall::view("getSponsorIDs").arg(sponsor_address).arg(&cursor).arg(&limit));
+    }
+
+    pub fn get_nb_sponsor_running(&self, sponsor_address: &Address) -> Result<u32, ClientError> {
+        return self.query_value(ContractCall::view("getNbSponsorRunning").arg(sponsor_address));
+    }
+
+    pub fn get_nb_promo_codes(&self, iid: u32) -> Result<usize, ClientError> {
+        return self.query_value(ContractCall::view("getNbPromoCodes").arg(&iid));
+    }
+
+    pub fn get_promo_code_discount(&self, iid: u32, promo_code_hash: &[u8]) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getPromoCodeDiscount").arg(&iid).arg(promo_code_hash));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries : players
+    /////////////////////////////////////////////////////////////////////
+    pub fn get_total_players(&self) -> Result<usize, ClientError> {
+        return self.query_value(ContractCall::view("getTotalPlayers"));
+    }
+
+    pub fn get_player_ids(&self, player_address: &Address, cursor: u32, limit: u32) -> Result<Page<u32>, ClientError> {
+        return self.query_page(ContractCall::view("getPlayerIDs").arg(player_address).arg(&cursor).arg(&limit));
+    }
+
+    pub fn get_player_donations(&self, player_address: &Address) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getPlayerDonations").arg(player_address));
+    }
+
+    pub fn has_donated(&self, iid: u32, player_address: &Address) -> Result<bool, ClientError> {
+        return self.query_value(ContractCall::view("hasDonated").arg(&iid).arg(player_address));
+    }
+
+    pub fn has_played(&self, iid: u32, player_address: &Address) -> Result<bool, ClientError> {
+        return self.query_value(ContractCall::view("hasPlayed").arg(&iid).arg(player_address));
+    }
+
+    // Returns : next cursor, (ticket number, player address) of the page
+    pub fn get_tickets(&self, iid: u32, cursor: usize, limit: usize) -> Result<(usize, Vec<(usize, Address)>), ClientError> {
+        let mut results = self.query(ContractCall::view("getTickets").arg(&iid).arg(&cursor).arg(&limit))?;
+        let next_cursor: usize = results.read()?;
+
+        let mut tickets: Vec<(usize, Address)> = Vec::new();
+        while results.has_remaining() {
+            let ticket_number: usize = results.read()?;
+            tickets.push((ticket_number, results.read_address()?));
+        }
+
+        return Ok((next_cursor, tickets));
+    }
+
+    pub fn get_ticket_number(&self, iid: u32, player_address: &Address) -> Result<usize, ClientError> {
+        return self.query_value(ContractCall::view("getTicketNumber").arg(&iid).arg(player_address));
+    }
+
+    // Returns : winning ticket number & winner address
+    pub fn get_winning_ticket(&self, iid: u32) -> Result<(usize, Address), ClientError> {
+        let mut results = self.query(ContractCall::view("getWinningTicket").arg(&iid))?;
+        let ticket_number: usize = results.read()?;
+        let winner_address = results.read_address()?;
+        results.finish()?;
+
+        return Ok((ticket_number, winner_address));
+    }
+
+    // Returns : next cursor, player totals & history of the page
+    pub fn get_player_history(&self, player_address: &Address, cursor: u32, limit: u32) -> Result<(u32, GetPlayerTotalsStruct<Api>, Vec<GetPlayerHistoryStruct<Api>>), ClientError> {
+        let mut results = self.query(ContractCall::view("getPlayerHistory").arg(player_address).arg(&cursor).arg(&limit))?;
+        let next_cursor: u32 = results.read()?;
+        let totals: GetPlayerTotalsStruct<Api> = results.read()?;
+
+        return Ok((next_cursor, totals, results.read_remaining()?));
+    }
+
+    pub fn get_referrer(&self, address: &Address) -> Result<Address, ClientError> {
+        let mut results = self.query(ContractCall::view("getReferrer").arg(address))?;
+        let referrer_address = results.read_address()?;
+        results.finish()?;
+
+        return Ok(referrer_address);
+    }
+
+    pub fn get_affiliate_info(&self, address: &Address) -> Result<AffiliateInfo<Api>, ClientError> {
+        return self.query_value(ContractCall::view("getAffiliateInfo").arg(address));
+    }
+
+    // Returns : current & lifetime points
+    pub fn get_loyalty_points(&self, address: &Address) -> Result<(u64, u64), ClientError> {
+        let mut results = self.query(ContractCall::view("getLoyaltyPoints").arg(address))?;
+        let points: u64 = results.read()?;
+        let lifetime_points: u64 = results.read()?;
+        results.finish()?;
+
+        return Ok((points, lifetime_points));
+    }
+
+    pub fn get_loyalty_ranking(&self, max_nb_ranks: usize) -> Result<Vec<LeaderboardEntry<Api>>, ClientError> {
+        return self.query(ContractCall::view("getLoyaltyRanking").arg(&max_nb_ranks))?.read_remaining();
+    }
+
+    pub fn get_leaderboard(&self, kind: LeaderboardKind, max_nb_entries: usize) -> Result<Vec<LeaderboardEntry<Api>>, ClientError> {
+        return self.query(ContractCall::view("getLeaderboard").arg(&kind).arg(&max_nb_entries))?.read_remaining();
+    }
+
+    pub fn get_leaderboard_score(&self, kind: LeaderboardKind, address: &Address) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getLeaderboardScore").arg(&kind).arg(address));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries : archive
+    /////////////////////////////////////////////////////////////////////
+    pub fn get_archive(&self, iid: u32) -> Result<ArchiveInfo<Api>, ClientError> {
+        return self.query_value(ContractCall::view("getArchive").arg(&iid));
+    }
+
+    pub fn get_sponsor_archive(&self, sponsor_address: &Address, cursor: u32, limit: u32) -> Result<Page<ArchiveInfo<Api>>, ClientError> {
+        return self.query_page(ContractCall::view("getSponsorArchive").arg(sponsor_address).arg(&cursor).arg(&limit));
+    }
+
+    pub fn get_winner_archive(&self, winner_address: &Address, cursor: u32, limit: u32) -> Result<Page<ArchiveInfo<Api>>, ClientError> {
+        return self.query_page(ContractCall::view("getWinnerArchive").arg(winner_address).arg(&cursor).arg(&limit));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries : fees, pools & charities
+    /////////////////////////////////////////////////////////////////////
+
+    // Returns : fee amount, sponsor, link & link2 reward percents
+    pub fn get_fee_policy(&self) -> Result<(BigUint, u8, u8, u8), ClientError> {
+        let mut results = self.query(ContractCall::view("getFeePol"))?;
+        let fee_amount_egld = results.read_big_uint()?;
+        let sponsor_reward_percent: u8 = results.read()?;
+        let link_reward_percent: u8 = results.read()?;
+        let link2_reward_percent: u8 = results.read()?;
+        results.finish()?;
+
+        return Ok((fee_amount_egld, sponsor_reward_percent, link_reward_percent, link2_reward_percent));
+    }
+
+    pub fn get_fee_pool(&self) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getFeePool"));
+    }
+
+    // Returns : (address, weight) of the beneficiaries
+    pub fn get_fee_beneficiaries(&self) -> Result<Vec<(Address, u32)>, ClientError> {
+        let mut results = self.query(ContractCall::view("getFeeBeneficiaries"))?;
+
+        let mut beneficiaries: Vec<(Address, u32)> = Vec::new();
+        while results.has_remaining() {
+            let address = results.read_address()?;
+            beneficiaries.push((address, results.read()?));
+        }
+
+        return Ok(beneficiaries);
+    }
+
+    pub fn get_fee_balance(&self, address: &Address) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getFeeBalance").arg(address));
+    }
+
+    pub fn get_link_reward_pool(&self, link_address: &Address) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getLinkRewardPool").arg(link_address));
+    }
+
+    // Returns : fee percent & chance
+    pub fn get_jackpot_params(&self) -> Result<(u8, u32), ClientError> {
+        let mut results = self.query(ContractCall::view("getJackpotParams"))?;
+        let fee_percent: u8 = results.read()?;
+        let chance: u32 = results.read()?;
+        results.finish()?;
+
+        return Ok((fee_percent, chance));
+    }
+
+    pub fn get_jackpot_pool(&self) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getJackpotPool"));
+    }
+
+    pub fn get_jackpot_won(&self, iid: u32) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getJackpotWon").arg(&iid));
+    }
+
+    // Returns : token identifier & amount
+    pub fn get_bond_policy(&self) -> Result<(String, BigUint), ClientError> {
+        let mut results = self.query(ContractCall::view("getBondPol"))?;
+        let token_identifier = results.read_string()?;
+        let amount = results.read_big_uint()?;
+        results.finish()?;
+
+        return Ok((token_identifier, amount));
+    }
+
+    // Returns : token identifier & amount
+    pub fn get_sponsor_bond(&self, sponsor_address: &Address) -> Result<(String, BigUint), ClientError> {
+        let mut results = self.query(ContractCall::view("getSponsorBond").arg(sponsor_address))?;
+        let token_identifier = results.read_string()?;
+        let amount = results.read_big_uint()?;
+        results.finish()?;
+
+        return Ok((token_identifier, amount));
+    }
+
+    pub fn get_charity_pool(&self) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getCharityPool"));
+    }
+
+    pub fn get_charity(&self, charity_id: u32) -> Result<GetCharityStruct<Api>, ClientError> {
+        return self.query_value(ContractCall::view("getCharity").arg(&charity_id));
+    }
+
+    pub fn get_charities(&self) -> Result<Vec<GetCharityStruct<Api>>, ClientError> {
+        return self.query(ContractCall::view("getCharities"))?.read_remaining();
+    }
+
+    pub fn get_instance_donations(&self, iid: u32) -> Result<BigUint, ClientError> {
+        return self.query_big_uint(ContractCall::view("getInstanceDonations").arg(&iid));
+    }
+
+    // Returns : statistics & prizes distributed (token identifier, amount)
+    pub fn get_stats(&self) -> Result<(GetStatsStruct<Api>, Vec<(String, BigUint)>), ClientError> {
+        let mut results = self.query(ContractCall::view("getStats"))?;
+        let stats: GetStatsStruct<Api> = results.read()?;
+
+        let mut prizes: Vec<(String, BigUint)> = Vec::new();
+        while results.has_remaining() {
+            let token_identifier = results.read_string()?;
+            prizes.push((token_identifier, results.read_big_uint()?));
+        }
+
+        return Ok((stats, prizes));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries : parameters & security
+    /////////////////////////////////////////////////////////////////////
+    pub fn get_param_manual_claim(&self) -> Result<bool, ClientError> {
+        return self.query_value(ContractCall::view("getParamManClaim"));
+    }
+
+    pub fn get_param_nb_max_instances_per_sponsor(&self) -> Result<u32, ClientError> {
+        return self.query_value(ContractCall::view("getParamNbMaxInstancesPerSponsor"));
+    }
+
+    // Returns : min & max durations in seconds
+    pub fn get_param_duration(&self) -> Result<(u64, u64), ClientError> {
+        let mut results = self.query(ContractCall::view("getParamDuration"))?;
+        let duration_min: u64 = results.read()?;
+        let duration_max: u64 = results.read()?;
+        results.finish()?;
+
+        return Ok((duration_min, duration_max));
+    }
+
+    pub fn get_param_sponsor_info_max_length(&self) -> Result<u32, ClientError> {
+        return self.query_value(ContractCall::view("getParamSponsorInfoMaxLength"));
+    }
+
+    pub fn get_param_pot_winner_percent(&self) -> Result<u8, ClientError> {
+        return self.query_value(ContractCall::view("getParamPotWinnerPercent"));
+    }
+
+    // Returns : points per EGLD, points per win & free entry cost
+    pub fn get_loyalty_policy(&self) -> Result<(u64, u64, u64), ClientError> {
+        let mut results = self.query(ContractCall::view("getLoyaltyPol"))?;
+        let points_per_egld: u64 = results.read()?;
+        let points_per_win: u64 = results.read()?;
+        let free_entry_cost: u64 = results.read()?;
+        results.finish()?;
+
+        return Ok((points_per_egld, points_per_win, free_entry_cost));
+    }
+
+    pub fn get_log_enable_status(&self) -> Result<bool, ClientError> {
+        return self.query_value(ContractCall::view("getLogEnableStatus"));
+    }
+
+    pub fn get_log_category_status(&self, category: EventCategory) -> Result<bool, ClientError> {
+        return self.query_value(ContractCall::view("getLogCategoryStatus").arg(&category));
+    }
+
+    // Cursor is a blacklisted address, zero address for the first page
+    pub fn get_addr_blacklist(&self, cursor: &Address, limit: u32) -> Result<(Address, Vec<Address>), ClientError> {
+        let mut results = self.query(ContractCall::view("getAddrBlacklist").arg(cursor).arg(&limit))?;
+        let next_cursor: Address = results.read_address()?;
+
+        let mut addresses: Vec<Address> = Vec::new();
+        while results.has_remaining() {
+            addresses.push(results.read_address()?);
+        }
+
+        return Ok((next_cursor, addresses));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,168 @@
+use crate::error::ClientError;
+use elrond_wasm::elrond_codec::{top_encode_to_vec_u8_or_panic, TopDecode};
+use elrond_wasm_debug::DebugApi;
+use num_bigint::BigUint;
+use prize::{EventCategory, InstanceFilter, InstanceSort, InstanceStatus, LeaderboardKind};
+use prize_indexer::Address;
+use std::cell::Cell;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Managed types of the contract structs are backed by the debug API off-chain
+pub type Api = DebugApi;
+
+// Contract argument, top-encoded as expected by the endpoint ABI
+pub trait ContractArg {
+    fn to_arg_bytes(&self) -> Vec<u8>;
+}
+
+// Sequential reader of the top-encoded values returned by a view
+pub struct ReturnData {
+    function: String,
+    items: Vec<Vec<u8>>,
+    position: usize,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+thread_local! {
+    static DEBUG_API_INITIALIZED: Cell<bool> = Cell::new(false);
+}
+
+// Managed types can only be built once a debug API context exists for the thread
+pub fn init_debug_api() {
+    DEBUG_API_INITIALIZED.with(|initialized| {
+        if initialized.get() == false {
+            let _ = DebugApi::dummy();
+            initialized.set(true);
+        }
+    });
+}
+
+macro_rules! impl_top_encode_arg {
+    ($($arg_type:ty),*) => {
+        $(
+            impl ContractArg for $arg_type {
+                fn to_arg_bytes(&self) -> Vec<u8> {
+                    init_debug_api();
+                    return top_encode_to_vec_u8_or_panic(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_top_encode_arg!(u8, u32, u64, usize, bool, InstanceStatus, EventCategory, LeaderboardKind, InstanceSort, InstanceFilter<Api>);
+
+impl ContractArg for Address {
+    fn to_arg_bytes(&self) -> Vec<u8> {
+        return self.0.to_vec();
+    }
+}
+
+// Big integers are top-encoded without leading zeros, zero is empty
+impl ContractArg for BigUint {
+    fn to_arg_bytes(&self) -> Vec<u8> {
+        if *self == BigUint::default() {
+            return Vec::new();
+        }
+        return self.to_bytes_be();
+    }
+}
+
+// Token identifiers, pseudos, urls & promo codes
+impl ContractArg for str {
+    fn to_arg_bytes(&self) -> Vec<u8> {
+        return self.as_bytes().to_vec();
+    }
+}
+
+impl ContractArg for [u8] {
+    fn to_arg_bytes(&self) -> Vec<u8> {
+        return self.to_vec();
+    }
+}
+
+impl ReturnData {
+    pub fn new(function: &str, items: Vec<Vec<u8>>) -> Self {
+        init_debug_api();
+
+        return ReturnData {
+            function: String::from(function),
+            items: items,
+            position: 0,
+        };
+    }
+
+    fn error(&self, reason: &str) -> ClientError {
+        return ClientError::Decode { function: self.function.clone(), reason: String::from(reason) };
+    }
+
+    fn next(&mut self) -> Result<Vec<u8>, ClientError> {
+        if self.position >= self.items.len() {
+            return Err(self.error(&format!("missing result #{}", self.position)));
+        }
+
+        self.position += 1;
+
+        return Ok(self.items[self.position - 1].clone());
+    }
+
+    pub fn has_remaining(&self) -> bool {
+        return self.position < self.items.len();
+    }
+
+    // Primitive types & contract types
+    pub fn read<T: TopDecode>(&mut self) -> Result<T, ClientError> {
+        let bytes = self.next()?;
+
+        match T::top_decode(&bytes[..]) {
+            Ok(value) => return Ok(value),
+            Err(error) => return Err(self.error(&String::from_utf8_lossy(error.message_bytes()))),
+        }
+    }
+
+    pub fn read_big_uint(&mut self) -> Result<BigUint, ClientError> {
+        return Ok(BigUint::from_bytes_be(&self.next()?));
+    }
+
+    pub fn read_address(&mut self) -> Result<Address, ClientError> {
+        let bytes = self.next()?;
+
+        match Address::from_bytes(&bytes) {
+            Some(address) => return Ok(address),
+            None => return Err(self.error("invalid address length")),
+        }
+    }
+
+    pub fn read_string(&mut self) -> Result<String, ClientError> {
+        return Ok(String::from_utf8_lossy(&self.next()?).into_owned());
+    }
+
+    // OptionalValue results are not returned when empty
+    pub fn read_optional<T: TopDecode>(&mut self) -> Result<Option<T>, ClientError> {
+        if self.has_remaining() == false {
+            return Ok(None);
+        }
+        return Ok(Some(self.read()?));
+    }
+
+    // Multi-value results : one result per item until the end of the results
+    pub fn read_remaining<T: TopDecode>(&mut self) -> Result<Vec<T>, ClientError> {
+        let mut values: Vec<T> = Vec::new();
+        while self.has_remaining() {
+            values.push(self.read()?);
+        }
+        return Ok(values);
+    }
+
+    pub fn finish(&self) -> Result<(), ClientError> {
+        if self.has_remaining() {
+            return Err(self.error(&format!("unexpected number of results ({})", self.items.len())));
+        }
+        return Ok(());
+    }
+}
\ No newline at end of file
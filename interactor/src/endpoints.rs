@@ -0,0 +1,241 @@
+use crate::transaction::{ContractCall, Payment};
+use num_bigint::BigUint;
+use prize::EventCategory;
+use prize_indexer::Address;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+
+// Same gas limits as the interaction snippets
+pub const GAS_LIMIT_PLAY: u64 = 10_000_000;
+pub const GAS_LIMIT_DEFAULT: u64 = 50_000_000;
+pub const GAS_LIMIT_PUSH_FEES: u64 = 100_000_000;
+pub const GAS_LIMIT_INSTANCE: u64 = 500_000_000;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Sponsor information & options of a new instance
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InstanceDetails {
+    pub pseudo: String,
+    pub url1: String,
+    pub url2: String,
+    pub url3: String,
+    pub reserved: String,
+    pub graphic: String,
+    pub logo_link: String,
+    pub free_text: String,
+    pub premium: bool,
+    pub charity_id: u32,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl InstanceDetails {
+    fn add_args(&self, call: ContractCall) -> ContractCall {
+        return call
+            .arg(self.pseudo.as_str())
+            .arg(self.url1.as_str())
+            .arg(self.url2.as_str())
+            .arg(self.url3.as_str())
+            .arg(self.reserved.as_str())
+            .arg(self.graphic.as_str())
+            .arg(self.logo_link.as_str())
+            .arg(self.free_text.as_str())
+            .arg(&self.premium)
+            .arg(&self.charity_id);
+    }
+}
+
+/////////////////////////////////////////////////////////////////////
+// Administrator API
+/////////////////////////////////////////////////////////////////////
+pub fn clean_claimed(iids: &[u32]) -> ContractCall {
+    return ContractCall::new("cleanClaimed", GAS_LIMIT_INSTANCE).var_args(iids);
+}
+
+pub fn migrate_fee_policy(iids: &[u32]) -> ContractCall {
+    return ContractCall::new("migrateFeePol", GAS_LIMIT_INSTANCE).var_args(iids);
+}
+
+// Run until the returned next IID is 0
+pub fn migrate_indexes(nb_instances: u32) -> ContractCall {
+    return ContractCall::new("migrateIndexes", GAS_LIMIT_INSTANCE).arg(&nb_instances);
+}
+
+pub fn set_premium(iid: u32, premium_status: bool) -> ContractCall {
+    return ContractCall::new("setPremium", GAS_LIMIT_DEFAULT).arg(&iid).arg(&premium_status);
+}
+
+pub fn disable(iid: u32, disable_status: bool) -> ContractCall {
+    return ContractCall::new("disable", GAS_LIMIT_DEFAULT).arg(&iid).arg(&disable_status);
+}
+
+pub fn add_addr_blacklist(address: &Address) -> ContractCall {
+    return ContractCall::new("addAddrBlacklist", GAS_LIMIT_DEFAULT).arg(address);
+}
+
+pub fn rm_addr_blacklist(address: &Address) -> ContractCall {
+    return ContractCall::new("rmAddrBlacklist", GAS_LIMIT_DEFAULT).arg(address);
+}
+
+pub fn set_fee_policy(fee_amount_egld: &BigUint, sponsor_reward_percent: u8, link_reward_percent: u8, link2_reward_percent: u8) -> ContractCall {
+    return ContractCall::new("setFeePol", GAS_LIMIT_DEFAULT)
+        .arg(fee_amount_egld)
+        .arg(&sponsor_reward_percent)
+        .arg(&link_reward_percent)
+        .arg(&link2_reward_percent);
+}
+
+pub fn set_fee_beneficiary(address: &Address, weight: u32) -> ContractCall {
+    return ContractCall::new("setFeeBeneficiary", GAS_LIMIT_DEFAULT).arg(address).arg(&weight);
+}
+
+pub fn push_fees() -> ContractCall {
+    return ContractCall::new("pushFees", GAS_LIMIT_PUSH_FEES);
+}
+
+pub fn set_jackpot_params(fee_percent: u8, chance: u32) -> ContractCall {
+    return ContractCall::new("setJackpotParams", GAS_LIMIT_DEFAULT).arg(&fee_percent).arg(&chance);
+}
+
+pub fn set_bond_policy(token_identifier: &str, amount: &BigUint) -> ContractCall {
+    return ContractCall::new("setBondPol", GAS_LIMIT_DEFAULT).arg(token_identifier).arg(amount);
+}
+
+pub fn slash_bond(iid: u32, slash_percent: u8, to_charity: bool) -> ContractCall {
+    return ContractCall::new("slashBond", GAS_LIMIT_DEFAULT).arg(&iid).arg(&slash_percent).arg(&to_charity);
+}
+
+pub fn add_charity(address: &Address, name: &str, url: &str) -> ContractCall {
+    return ContractCall::new("addCharity", GAS_LIMIT_DEFAULT).arg(address).arg(name).arg(url);
+}
+
+pub fn update_charity(charity_id: u32, address: &Address, name: &str, url: &str, enabled: bool) -> ContractCall {
+    return ContractCall::new("updateCharity", GAS_LIMIT_DEFAULT).arg(&charity_id).arg(address).arg(name).arg(url).arg(&enabled);
+}
+
+pub fn claim_donations() -> ContractCall {
+    return ContractCall::new("claimDonations", GAS_LIMIT_DEFAULT);
+}
+
+pub fn set_loyalty_policy(points_per_egld: u64, points_per_win: u64, free_entry_cost: u64) -> ContractCall {
+    return ContractCall::new("setLoyaltyPol", GAS_LIMIT_DEFAULT).arg(&points_per_egld).arg(&points_per_win).arg(&free_entry_cost);
+}
+
+pub fn set_param_manual_claim(manual_claim: bool) -> ContractCall {
+    return ContractCall::new("setParamManClaim", GAS_LIMIT_DEFAULT).arg(&manual_claim);
+}
+
+pub fn set_param_nb_max_instances_per_sponsor(nb_instances_max: u32) -> ContractCall {
+    return ContractCall::new("setParamNbMaxInstancesPerSponsor", GAS_LIMIT_DEFAULT).arg(&nb_instances_max);
+}
+
+pub fn set_param_duration(duration_min: u64, duration_max: u64) -> ContractCall {
+    return ContractCall::new("setParamDuration", GAS_LIMIT_DEFAULT).arg(&duration_min).arg(&duration_max);
+}
+
+pub fn set_param_sponsor_info_max_length(length_max: u32) -> ContractCall {
+    return ContractCall::new("setParamSponsorInfoMaxLength", GAS_LIMIT_DEFAULT).arg(&length_max);
+}
+
+pub fn set_param_pot_winner_percent(winner_percent: u8) -> ContractCall {
+    return ContractCall::new("setParamPotWinnerPercent", GAS_LIMIT_DEFAULT).arg(&winner_percent);
+}
+
+pub fn set_log_enable_status(enable: bool) -> ContractCall {
+    return ContractCall::new("setLogEnableStatus", GAS_LIMIT_DEFAULT).arg(&enable);
+}
+
+pub fn set_log_category_status(category: EventCategory, enable: bool) -> ContractCall {
+    return ContractCall::new("setLogCategoryStatus", GAS_LIMIT_DEFAULT).arg(&category).arg(&enable);
+}
+
+/////////////////////////////////////////////////////////////////////
+// DApp endpoints : sponsor API
+/////////////////////////////////////////////////////////////////////
+
+// Prize sent with the call : EGLD, ESDT, SFT or NFT
+pub fn create(prize: Payment, duration_in_s: u64, details: &InstanceDetails) -> ContractCall {
+    let call = ContractCall::new("create", GAS_LIMIT_INSTANCE).with_payment(prize).arg(&duration_in_s);
+    return details.add_args(call);
+}
+
+// Prize funded by the tickets
+pub fn create_pot(duration_in_s: u64, details: &InstanceDetails) -> ContractCall {
+    let call = ContractCall::new("createPot", GAS_LIMIT_INSTANCE).arg(&duration_in_s);
+    return details.add_args(call);
+}
+
+pub fn trigger(iid: u32) -> ContractCall {
+    return ContractCall::new("prize", GAS_LIMIT_INSTANCE).arg(&iid);
+}
+
+pub fn add_promo_codes(iid: u32, discount: &BigUint, promo_code_hashes: &[Vec<u8>]) -> ContractCall {
+    let mut call = ContractCall::new("addPromoCodes", GAS_LIMIT_DEFAULT).arg(&iid).arg(discount);
+    for promo_code_hash in promo_code_hashes.iter() {
+        call = call.arg(promo_code_hash.as_slice());
+    }
+    return call;
+}
+
+// Bond sent with the call
+pub fn deposit_bond(bond: Payment) -> ContractCall {
+    return ContractCall::new("depositBond", GAS_LIMIT_DEFAULT).with_payment(bond);
+}
+
+pub fn withdraw_bond() -> ContractCall {
+    return ContractCall::new("withdrawBond", GAS_LIMIT_DEFAULT);
+}
+
+/////////////////////////////////////////////////////////////////////
+// DApp endpoints : player API
+/////////////////////////////////////////////////////////////////////
+pub fn play(payment: &BigUint, iid: u32, link_address: Option<&Address>) -> ContractCall {
+    return ContractCall::new("play", GAS_LIMIT_PLAY)
+        .with_payment(Payment::Egld(payment.clone()))
+        .arg(&iid)
+        .optional_arg(link_address);
+}
+
+pub fn play_with_code(payment: &BigUint, iid: u32, promo_code: &str, link_address: Option<&Address>) -> ContractCall {
+    return ContractCall::new("playWithCode", GAS_LIMIT_PLAY)
+        .with_payment(Payment::Egld(payment.clone()))
+        .arg(&iid)
+        .arg(promo_code)
+        .optional_arg(link_address);
+}
+
+pub fn play_with_points(payment: &BigUint, iid: u32, link_address: Option<&Address>) -> ContractCall {
+    return ContractCall::new("playWithPoints", GAS_LIMIT_PLAY)
+        .with_payment(Payment::Egld(payment.clone()))
+        .arg(&iid)
+        .optional_arg(link_address);
+}
+
+pub fn claim_prize(iid: u32) -> ContractCall {
+    return ContractCall::new("claimPrize", GAS_LIMIT_DEFAULT).arg(&iid);
+}
+
+pub fn set_referrer(referrer_address: &Address) -> ContractCall {
+    return ContractCall::new("setReferrer", GAS_LIMIT_DEFAULT).arg(referrer_address);
+}
+
+/////////////////////////////////////////////////////////////////////
+// DApp endpoints : rewards API
+/////////////////////////////////////////////////////////////////////
+pub fn claim_fees() -> ContractCall {
+    return ContractCall::new("claimFees", GAS_LIMIT_DEFAULT);
+}
+
+pub fn claim_link_rewards() -> ContractCall {
+    return ContractCall::new("claimLinkRewards", GAS_LIMIT_DEFAULT);
+}
+
+pub fn claim_charity_donations(charity_id: u32) -> ContractCall {
+    return ContractCall::new("claimCharityDonations", GAS_LIMIT_DEFAULT).arg(&charity_id);
+}
\ No newline at end of file
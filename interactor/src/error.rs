@@ -0,0 +1,37 @@
+use std::fmt;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientError {
+    Io(String),
+    Json(String),
+    Http(String),
+    InvalidPem(String),
+    InvalidAddress(String),
+    MissingContractAddress(String),
+    // Return code & message of a failed query
+    Contract { code: String, message: String },
+    Decode { function: String, reason: String },
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Io(reason) => write!(f, "I/O error : {}", reason),
+            ClientError::Json(reason) => write!(f, "JSON error : {}", reason),
+            ClientError::Http(reason) => write!(f, "HTTP error : {}", reason),
+            ClientError::InvalidPem(reason) => write!(f, "invalid PEM file : {}", reason),
+            ClientError::InvalidAddress(address) => write!(f, "invalid address '{}'", address),
+            ClientError::MissingContractAddress(profile) => write!(f, "no contract address for profile {}", profile),
+            ClientError::Contract { code, message } => write!(f, "contract error ({}) : {}", code, message),
+            ClientError::Decode { function, reason } => write!(f, "cannot decode {} results : {}", function, reason),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
\ No newline at end of file
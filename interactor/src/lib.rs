@@ -0,0 +1,25 @@
+////////////////////////////////////////////////////////////////////
+// Typed client : contract endpoints & views, transactions signed offline
+// from PEM files, devnet / testnet / mainnet profiles
+////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////
+// Modules & uses
+////////////////////////////////////////////////////////////////////
+mod error;
+mod profile;
+mod wallet;
+mod codec;
+mod transaction;
+mod proxy;
+mod client;
+pub mod endpoints;
+
+pub use error::ClientError;
+pub use profile::{Profile, GAS_PRICE, TX_VERSION};
+pub use wallet::Wallet;
+pub use codec::{init_debug_api, Api, ContractArg, ReturnData};
+pub use transaction::{ContractCall, Payment, Transaction};
+pub use proxy::Proxy;
+pub use client::{Page, PrizeClient};
+pub use prize_indexer::Address;
\ No newline at end of file
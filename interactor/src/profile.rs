@@ -0,0 +1,87 @@
+use crate::error::ClientError;
+use prize_indexer::Address;
+use std::fs;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+pub const GAS_PRICE: u64 = 1_000_000_000;
+pub const TX_VERSION: u32 = 1;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Network environment, same values as the interaction snippets
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    pub proxy: String,
+    pub chain_id: String,
+    pub contract_address: Option<Address>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl Profile {
+    pub fn devnet() -> Self {
+        return Profile::new("devnet", "https://devnet-api.elrond.com", "D");
+    }
+
+    pub fn testnet() -> Self {
+        return Profile::new("testnet", "https://testnet-api.elrond.com", "T");
+    }
+
+    pub fn mainnet() -> Self {
+        return Profile::new("mainnet", "https://api.elrond.com", "1");
+    }
+
+    fn new(name: &str, proxy: &str, chain_id: &str) -> Self {
+        return Profile {
+            name: String::from(name),
+            proxy: String::from(proxy),
+            chain_id: String::from(chain_id),
+            contract_address: None,
+        };
+    }
+
+    // Returns : None if the profile name is not devnet, testnet or mainnet
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "devnet" => return Some(Profile::devnet()),
+            "testnet" => return Some(Profile::testnet()),
+            "mainnet" => return Some(Profile::mainnet()),
+            _ => return None,
+        }
+    }
+
+    pub fn with_contract_address(mut self, contract_address: Address) -> Self {
+        self.contract_address = Some(contract_address);
+        return self;
+    }
+
+    // Contract address stored by the deploy snippet (erdpy data store --key=address-<profile>)
+    pub fn with_data_storage(self, data_storage_path: &str) -> Result<Self, ClientError> {
+        let content = fs::read_to_string(data_storage_path).map_err(|error| ClientError::Io(error.to_string()))?;
+        let data_storage: serde_json::Value = serde_json::from_str(&content).map_err(|error| ClientError::Json(error.to_string()))?;
+
+        let key = format!("address-{}", self.name);
+        let bech32_address = match data_storage["*"][&key].as_str() {
+            Some(bech32_address) => bech32_address,
+            None => return Err(ClientError::MissingContractAddress(self.name.clone())),
+        };
+
+        match Address::from_bech32(bech32_address) {
+            Some(contract_address) => return Ok(self.with_contract_address(contract_address)),
+            None => return Err(ClientError::InvalidAddress(String::from(bech32_address))),
+        }
+    }
+
+    pub fn get_contract_address(&self) -> Result<Address, ClientError> {
+        match self.contract_address {
+            Some(contract_address) => return Ok(contract_address),
+            None => return Err(ClientError::MissingContractAddress(self.name.clone())),
+        }
+    }
+}
\ No newline at end of file
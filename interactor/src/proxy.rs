@@ -0,0 +1,103 @@
+use crate::error::ClientError;
+use crate::transaction::Transaction;
+use prize_indexer::Address;
+use serde::Deserialize;
+use serde_json::json;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Elrond API client (accounts, transactions & VM queries)
+pub struct Proxy {
+    url: String,
+    http: reqwest::blocking::Client,
+}
+
+#[derive(Deserialize)]
+struct AccountResponse {
+    #[serde(default)]
+    nonce: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SendResponse {
+    tx_hash: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryResponse {
+    #[serde(default)]
+    return_data: Option<Vec<String>>,
+    #[serde(default)]
+    return_code: String,
+    // Failed queries may be reported as HTTP errors, with a message only
+    #[serde(default, alias = "message")]
+    return_message: String,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+fn http_error(error: reqwest::Error) -> ClientError {
+    return ClientError::Http(error.to_string());
+}
+
+impl Proxy {
+    pub fn new(url: &str) -> Self {
+        return Proxy {
+            url: String::from(url.trim_end_matches('/')),
+            http: reqwest::blocking::Client::new(),
+        };
+    }
+
+    pub fn get_nonce(&self, address: &Address) -> Result<u64, ClientError> {
+        let response: AccountResponse = self.http
+            .get(format!("{}/accounts/{}", self.url, address.to_bech32()))
+            .send().map_err(http_error)?
+            .error_for_status().map_err(http_error)?
+            .json().map_err(http_error)?;
+
+        return Ok(response.nonce);
+    }
+
+    // Returns : transaction hash
+    pub fn send(&self, transaction: &Transaction) -> Result<String, ClientError> {
+        let response: SendResponse = self.http
+            .post(format!("{}/transactions", self.url))
+            .json(transaction)
+            .send().map_err(http_error)?
+            .error_for_status().map_err(http_error)?
+            .json().map_err(http_error)?;
+
+        return Ok(response.tx_hash);
+    }
+
+    // Returns : results of the view, top-encoded
+    pub fn query(&self, contract_address: &Address, function: &str, args: &[Vec<u8>]) -> Result<Vec<Vec<u8>>, ClientError> {
+        let hex_args: Vec<String> = args.iter().map(|arg| hex::encode(arg)).collect();
+
+        let response: QueryResponse = self.http
+            .post(format!("{}/query", self.url))
+            .json(&json!({
+                "scAddress": contract_address.to_bech32(),
+                "funcName": function,
+                "args": hex_args,
+            }))
+            .send().map_err(http_error)?
+            .json().map_err(http_error)?;
+
+        if response.return_code != "ok" {
+            return Err(ClientError::Contract { code: response.return_code, message: response.return_message });
+        }
+
+        let mut results: Vec<Vec<u8>> = Vec::new();
+        for item in response.return_data.unwrap_or_default().iter() {
+            results.push(base64::decode(item).map_err(|error| ClientError::Decode { function: String::from(function), reason: error.to_string() })?);
+        }
+
+        return Ok(results);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,159 @@
+use crate::codec::ContractArg;
+use crate::wallet::Wallet;
+use num_bigint::BigUint;
+use prize_indexer::Address;
+use serde::{Deserialize, Serialize};
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payment {
+    None,
+    Egld(BigUint),
+    Esdt { token_identifier: String, amount: BigUint },
+    // NFT & SFT
+    Nft { token_identifier: String, nonce: u64, amount: BigUint },
+}
+
+// Endpoint call, independent from the network & the caller
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractCall {
+    pub function: String,
+    pub args: Vec<Vec<u8>>,
+    pub payment: Payment,
+    pub gas_limit: u64,
+}
+
+// Fields order is the signing order
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub nonce: u64,
+    pub value: String,
+    pub receiver: String,
+    pub sender: String,
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    // base64 encoded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    #[serde(rename = "chainID")]
+    pub chain_id: String,
+    pub version: u32,
+    // hex encoded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl ContractCall {
+    pub fn new(function: &str, gas_limit: u64) -> Self {
+        return ContractCall {
+            function: String::from(function),
+            args: Vec::new(),
+            payment: Payment::None,
+            gas_limit: gas_limit,
+        };
+    }
+
+    // Views are queried, without gas
+    pub fn view(function: &str) -> Self {
+        return ContractCall::new(function, 0);
+    }
+
+    pub fn arg<T: ContractArg + ?Sized>(mut self, value: &T) -> Self {
+        self.args.push(value.to_arg_bytes());
+        return self;
+    }
+
+    // Optional arguments (OptionalValue) are omitted when empty
+    pub fn optional_arg<T: ContractArg + ?Sized>(self, value: Option<&T>) -> Self {
+        match value {
+            Some(value) => return self.arg(value),
+            None => return self,
+        }
+    }
+
+    // Variable arguments (MultiValueManagedVec)
+    pub fn var_args<T: ContractArg>(mut self, values: &[T]) -> Self {
+        for value in values.iter() {
+            self.args.push(value.to_arg_bytes());
+        }
+        return self;
+    }
+
+    pub fn with_payment(mut self, payment: Payment) -> Self {
+        self.payment = payment;
+        return self;
+    }
+
+    // Returns : receiver, EGLD value & data of the transaction
+    // Tokens are sent with the built-in transfer functions, which call the endpoint afterwards
+    pub fn encode(&self, contract_address: &Address, sender: &Address) -> (Address, BigUint, String) {
+        let mut data: Vec<String> = Vec::new();
+        let mut receiver: Address = *contract_address;
+        let mut value: BigUint = BigUint::default();
+
+        match &self.payment {
+            Payment::None => {
+                data.push(self.function.clone());
+            },
+            Payment::Egld(amount) => {
+                data.push(self.function.clone());
+                value = amount.clone();
+            },
+            Payment::Esdt { token_identifier, amount } => {
+                data.push(String::from("ESDTTransfer"));
+                data.push(hex::encode(token_identifier.to_arg_bytes()));
+                data.push(hex::encode(amount.to_arg_bytes()));
+                data.push(hex::encode(self.function.as_bytes()));
+            },
+            Payment::Nft { token_identifier, nonce, amount } => {
+                data.push(String::from("ESDTNFTTransfer"));
+                data.push(hex::encode(token_identifier.to_arg_bytes()));
+                data.push(hex::encode(nonce.to_arg_bytes()));
+                data.push(hex::encode(amount.to_arg_bytes()));
+                data.push(hex::encode(contract_address.to_arg_bytes()));
+                data.push(hex::encode(self.function.as_bytes()));
+                receiver = *sender;
+            },
+        }
+
+        for arg in self.args.iter() {
+            data.push(hex::encode(arg));
+        }
+
+        return (receiver, value, data.join("@"));
+    }
+
+    // Returns : unsigned transaction
+    pub fn to_transaction(&self, contract_address: &Address, sender: &Address, nonce: u64, chain_id: &str, gas_price: u64, version: u32) -> Transaction {
+        let (receiver, value, data) = self.encode(contract_address, sender);
+
+        return Transaction {
+            nonce: nonce,
+            value: value.to_string(),
+            receiver: receiver.to_bech32(),
+            sender: sender.to_bech32(),
+            gas_price: gas_price,
+            gas_limit: self.gas_limit,
+            data: Some(base64::encode(data.as_bytes())),
+            chain_id: String::from(chain_id),
+            version: version,
+            signature: None,
+        };
+    }
+}
+
+impl Transaction {
+    // Signature of the JSON serialization of the transaction without signature
+    pub fn sign(&mut self, wallet: &Wallet) {
+        self.signature = None;
+
+        let message = serde_json::to_vec(&self).unwrap();
+        self.signature = Some(hex::encode(wallet.sign(&message)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use crate::error::ClientError;
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use prize_indexer::Address;
+use std::fs;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Wallet loaded from a PEM file, as generated by erdpy
+pub struct Wallet {
+    keypair: Keypair,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl Wallet {
+    pub fn from_pem_file(path: &str) -> Result<Self, ClientError> {
+        let pem = fs::read_to_string(path).map_err(|error| ClientError::Io(error.to_string()))?;
+        return Wallet::from_pem(&pem);
+    }
+
+    // PEM body : base64 of the hex encoded secret key (32 bytes) followed by the public key (32 bytes)
+    pub fn from_pem(pem: &str) -> Result<Self, ClientError> {
+        let body: String = pem.lines().filter(|line| line.starts_with("-----") == false).map(|line| line.trim()).collect();
+
+        let hex_keys = base64::decode(&body).map_err(|error| ClientError::InvalidPem(error.to_string()))?;
+        let keys = hex::decode(&hex_keys).map_err(|error| ClientError::InvalidPem(error.to_string()))?;
+        if keys.len() != 64 {
+            return Err(ClientError::InvalidPem(format!("unexpected key length {}", keys.len())));
+        }
+
+        let secret = SecretKey::from_bytes(&keys[..32]).map_err(|error| ClientError::InvalidPem(error.to_string()))?;
+        let public = PublicKey::from(&secret);
+        if public.as_bytes()[..] != keys[32..] {
+            return Err(ClientError::InvalidPem(String::from("public key does not match the secret key")));
+        }
+
+        return Ok(Wallet { keypair: Keypair { secret: secret, public: public } });
+    }
+
+    pub fn address(&self) -> Address {
+        return Address(self.keypair.public.to_bytes());
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        return self.keypair.sign(message).to_bytes();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use serde_json::Value;
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+
+// Returns : amount with thousands separators, as written in the scenarios (e.g. 5,000,000,000)
+pub fn amount(value: u128) -> String {
+    let digits = value.to_string();
+    let mut formatted = String::new();
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+
+    return formatted;
+}
+
+// Returns : JSON document indented with tabs, like the hand-written scenarios
+pub fn to_pretty_string(value: &Value) -> String {
+    return canonical(value, "", "\t");
+}
+
+fn scalar(value: &Value) -> String {
+    return serde_json::to_string(value).unwrap();
+}
+
+fn key(name: &str) -> String {
+    return serde_json::to_string(name).unwrap();
+}
+
+// Returns : value indented with <unit>, empty objects on one line & empty arrays on two lines
+fn canonical(value: &Value, indent: &str, unit: &str) -> String {
+    let child_indent = format!("{}{}", indent, unit);
+
+    match value {
+        Value::Object(object) => {
+            if object.is_empty() {
+                return String::from("{}");
+            }
+            let members: Vec<String> = object.iter().map(|(name, member)| format!("\n{}{}: {}", child_indent, key(name), canonical(member, &child_indent, unit))).collect();
+            return format!("{{{}\n{}}}", members.join(","), indent);
+        },
+        Value::Array(array) => {
+            let items: Vec<String> = array.iter().map(|item| format!("\n{}{}", child_indent, canonical(item, &child_indent, unit))).collect();
+            return format!("[{}\n{}]", items.join(","), indent);
+        },
+        _ => return scalar(value),
+    }
+}
@@ -0,0 +1,22 @@
+////////////////////////////////////////////////////////////////////
+// Scenario generator : builds the mandos scenarios & their steps files
+// from a declarative description of actors, prizes & lifecycle steps
+////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////
+// Modules & uses
+////////////////////////////////////////////////////////////////////
+mod json;
+mod world;
+mod step;
+mod setup;
+mod scenario;
+mod suite;
+
+pub use json::{amount, to_pretty_string};
+pub use world::{Account, Actor, Holding, PrizeDefinition, PrizeKind, TokenMetadata, World, CONTRACT, EGLD, GAS_LIMIT, NO_FEES_MESSAGE, OWNER_ONLY_MESSAGE};
+pub use step::{Call, Check, GeneratedStep, Moment, Query, Step, StepsFile};
+pub use setup::setup_files;
+pub use scenario::{Scenario, Suite, STEPS_DIRECTORY};
+pub use suite::prize_suite;
+pub use prize::InstanceStatus;
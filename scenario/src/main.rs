@@ -0,0 +1,20 @@
+use prize_scenario::prize_suite;
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+// Usage : prize-scenario [<mandos directory>], defaults to the mandos directory of the contract
+fn main() {
+    let mandos_directory = match env::args().nth(1) {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../mandos"),
+    };
+
+    match prize_suite().write(&mandos_directory) {
+        Ok(nb_files) => println!("{} files written to {}", nb_files, mandos_directory.display()),
+        Err(error) => {
+            eprintln!("Cannot write to {} : {}", mandos_directory.display(), error);
+            process::exit(1);
+        },
+    }
+}
@@ -0,0 +1,212 @@
+use crate::json::to_pretty_string;
+use crate::setup::setup_files;
+use crate::step::{Call, Check, GeneratedStep, Moment, Query, Step, StepsFile};
+use crate::world::{Actor, PrizeKind, World, NO_FEES_MESSAGE, OWNER_ONLY_MESSAGE};
+use prize::InstanceStatus;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+pub const STEPS_DIRECTORY: &str = "steps";
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Scenario file, written as <file_name>.scen.json
+#[derive(Clone, PartialEq)]
+pub struct Scenario {
+    pub file_name: &'static str,
+    pub name: &'static str,
+    pub comment: &'static str,
+    pub steps: Vec<Step>,
+}
+
+// Scenarios sharing the same initial setup
+#[derive(Clone, PartialEq)]
+pub struct Suite {
+    pub world: World,
+    pub scenarios: Vec<Scenario>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl Scenario {
+    // Starts with the initial setup
+    pub fn new(file_name: &'static str, name: &'static str, comment: &'static str) -> Self {
+        return Scenario { file_name: file_name, name: name, comment: comment, steps: vec![Step::InitialSetup] };
+    }
+
+    pub fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        return self;
+    }
+
+    pub fn play(self, player: u8, prize: PrizeKind) -> Self {
+        return self.step(Step::Play { player: player, prize: prize });
+    }
+
+    // Players play in the given order
+    pub fn plays(mut self, players: &[u8], prize: PrizeKind) -> Self {
+        for player in players.iter() {
+            self = self.play(*player, prize);
+        }
+        return self;
+    }
+
+    pub fn forward(self, prize: PrizeKind, moment: Moment) -> Self {
+        return self.step(Step::Forward { prize: prize, moment: moment });
+    }
+
+    pub fn trigger(self, prize: PrizeKind) -> Self {
+        return self.step(Step::Trigger(prize));
+    }
+
+    pub fn claim(self, actor: Actor, prize: PrizeKind) -> Self {
+        return self.step(Step::Claim { actor: actor, prize: prize });
+    }
+
+    pub fn clean_claimed(self) -> Self {
+        return self.step(Step::CleanClaimed);
+    }
+
+    // Owner only endpoint called by another account, written as admin_<file_name>_bad_caller.steps.json
+    pub fn bad_caller(self, caller: Actor, file_name: &'static str, function: &'static str, arguments: &[&'static str]) -> Self {
+        return self.step(Step::BadCaller { caller: caller, file_name: file_name, role: "admin", function: function, arguments: arguments.to_vec(), message: OWNER_ONLY_MESSAGE });
+    }
+
+    // Fees claimed by an account without fee balance
+    pub fn bad_fee_claimer(self, caller: Actor) -> Self {
+        return self.step(Step::BadCaller { caller: caller, file_name: "claimFees", role: "beneficiary", function: "claimFees", arguments: Vec::new(), message: NO_FEES_MESSAGE });
+    }
+
+    pub fn expect_status(self, iid: u32, status: InstanceStatus) -> Self {
+        return self.step(Step::ExpectStatus { iid: iid, label: status, status: status, query_status: "0" });
+    }
+
+    // Steps file named after the label, expecting another status
+    pub fn expect_status_labelled(self, iid: u32, label: InstanceStatus, status: InstanceStatus) -> Self {
+        return self.step(Step::ExpectStatus { iid: iid, label: label, status: status, query_status: "0" });
+    }
+
+    // Same as expect_status, the view call expecting the given status (e.g. "" for success)
+    pub fn expect_status_with_query_status(self, iid: u32, status: InstanceStatus, query_status: &'static str) -> Self {
+        return self.step(Step::ExpectStatus { iid: iid, label: status, status: status, query_status: query_status });
+    }
+
+    pub fn expect_nb(self, nb_instances: u32) -> Self {
+        return self.step(Step::ExpectNb(nb_instances));
+    }
+
+    pub fn expect_ids(self, status: InstanceStatus, iids: &[u32]) -> Self {
+        return self.step(Step::ExpectIds { status: status, iids: iids.to_vec() });
+    }
+
+    pub fn expect_winner(self, player: u8, prize: PrizeKind) -> Self {
+        return self.step(Step::ExpectHasWon { player: player, prize: prize, result: true });
+    }
+
+    pub fn random_seed(self, seed: &'static str) -> Self {
+        return self.step(Step::RandomSeed(seed));
+    }
+
+    pub fn check_state(self, comment: &'static str, checks: Vec<Check>) -> Self {
+        return self.step(Step::CheckState { comment: comment, checks: checks });
+    }
+
+    pub fn transaction(self, path: &'static str, name: &'static str, call: Call) -> Self {
+        return self.step(Step::Transaction { path: path, name: name, call: call });
+    }
+
+    pub fn query(self, path: &'static str, name: &'static str, query: Query) -> Self {
+        return self.step(Step::Query { path: path, name: name, query: query });
+    }
+
+    // Returns : scenario document & steps files it refers to
+    pub fn generate(&self, world: &World) -> (Value, Vec<StepsFile>) {
+        let mut steps: Vec<Value> = Vec::new();
+        let mut steps_files: Vec<StepsFile> = Vec::new();
+
+        for step in self.steps.iter() {
+            match step.generate(world) {
+                GeneratedStep::External(steps_file) => {
+                    steps.push(json!({
+                        "step": "externalSteps",
+                        "path": format!("{}/{}", STEPS_DIRECTORY, steps_file.path)
+                    }));
+                    steps_files.push(steps_file);
+                },
+                GeneratedStep::Inline(value) => steps.push(value),
+            }
+        }
+
+        let scenario = json!({
+            "name": self.name,
+            "comment": self.comment,
+            "steps": steps
+        });
+
+        return (scenario, steps_files);
+    }
+}
+
+fn add_steps_file(documents: &mut BTreeMap<String, Value>, steps_file: &StepsFile) {
+    let path = format!("{}/{}", STEPS_DIRECTORY, steps_file.path);
+    let document = steps_file.to_json();
+
+    if let Some(previous_document) = documents.insert(path.clone(), document.clone()) {
+        assert!(previous_document == document, "Conflicting contents generated for {}", path);
+    }
+}
+
+impl Suite {
+    // Returns : generated documents, by path relative to the mandos directory
+    // Panics if two steps generate different contents for the same file
+    pub fn documents(&self) -> BTreeMap<String, Value> {
+        let mut documents: BTreeMap<String, Value> = BTreeMap::new();
+
+        for steps_file in setup_files(&self.world).iter() {
+            add_steps_file(&mut documents, steps_file);
+        }
+
+        for scenario in self.scenarios.iter() {
+            let (document, steps_files) = scenario.generate(&self.world);
+            for steps_file in steps_files.iter() {
+                add_steps_file(&mut documents, steps_file);
+            }
+            documents.insert(format!("{}.scen.json", scenario.file_name), document);
+        }
+
+        return documents;
+    }
+
+    // Returns : content of the generated files, by path relative to the mandos directory
+    pub fn files(&self) -> BTreeMap<String, String> {
+        let mut files: BTreeMap<String, String> = BTreeMap::new();
+
+        for (path, document) in self.documents().iter() {
+            files.insert(path.clone(), to_pretty_string(document));
+        }
+
+        return files;
+    }
+
+    // Returns : number of files written
+    pub fn write(&self, mandos_directory: &Path) -> io::Result<usize> {
+        let files = self.files();
+
+        for (path, content) in files.iter() {
+            let file_path = mandos_directory.join(path);
+            fs::create_dir_all(file_path.parent().unwrap())?;
+            fs::write(&file_path, content)?;
+        }
+
+        return Ok(files.len());
+    }
+}
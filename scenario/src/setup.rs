@@ -0,0 +1,137 @@
+use crate::json::amount;
+use crate::step::{Call, StepsFile};
+use crate::world::{Actor, PrizeKind, World, CONTRACT};
+use serde_json::{json, Map, Value};
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+
+// Returns : steps files of the initial setup, the last one includes the others
+pub fn setup_files(world: &World) -> Vec<StepsFile> {
+    return vec![init_accounts(world), deploy_contract(), create_prizes(world), set_manual_claim(), initial_setup()];
+}
+
+fn external_steps(path: &str) -> Value {
+    return json!({
+        "step": "externalSteps",
+        "path": path
+    });
+}
+
+pub fn initial_setup() -> StepsFile {
+    let mut steps: Vec<Value> = ["init_accounts.steps.json", "deploy_contract.steps.json", "create_prizes.steps.json", "admin_actions/admin_setParamManClaim_true.steps.json"]
+        .iter()
+        .map(|path| external_steps(path))
+        .collect();
+    steps.push(json!({
+        "step": "setState",
+        "currentBlockInfo": {
+            "blockNonce": "1"
+        }
+    }));
+
+    return StepsFile { path: String::from("initial_setup.steps.json"), name: String::from("Initial setup"), comment: None, steps: steps };
+}
+
+fn init_accounts(world: &World) -> StepsFile {
+    let mut accounts = Map::new();
+    for account in world.accounts.iter() {
+        accounts.insert(
+            account.actor.address(),
+            json!({
+                "nonce": "0",
+                "balance": amount(account.balance),
+                "esdt": account.holdings_to_json(),
+                "storage": {}
+            }),
+        );
+    }
+
+    let step = json!({
+        "step": "setState",
+        "accounts": accounts,
+        "newAddresses": [
+            {
+                "creatorAddress": Actor::Owner.address(),
+                "creatorNonce": "0",
+                "newAddress": CONTRACT
+            }
+        ]
+    });
+
+    return StepsFile { path: String::from("init_accounts.steps.json"), name: String::from("init accounts"), comment: None, steps: vec![step] };
+}
+
+fn deploy_contract() -> StepsFile {
+    let step = json!({
+        "step": "scDeploy",
+        "txId": "deploy-sc-prize",
+        "tx": {
+            "from": Actor::Owner.address(),
+            "value": "0",
+            "contractCode": "file:../../output/prize.wasm",
+            "arguments": [],
+            "gasLimit": amount(100_000_000),
+            "gasPrice": "0"
+        },
+        "expect": {
+            "out": [],
+            "status": "",
+            "gas": "*",
+            "refund": "*"
+        }
+    });
+
+    return StepsFile { path: String::from("deploy_contract.steps.json"), name: String::from("deploy prize sc"), comment: None, steps: vec![step] };
+}
+
+// One prize per kind, created at timestamp 0 by its sponsor
+fn create_prizes(world: &World) -> StepsFile {
+    let mut steps: Vec<Value> = Vec::new();
+
+    for prize in world.prizes.iter() {
+        let sponsor = Actor::Sponsor(prize.kind);
+        let website = format!("https://website-{}.com", sponsor.name());
+
+        let mut call = Call::new(&format!("create-prize-{}", prize.kind.name()), &format!("create a prize with {} prize", prize.kind.label()), sponsor, "create");
+        if prize.kind == PrizeKind::Egld {
+            call = call.egld_value(prize.amount);
+        } else {
+            call = call.esdt_value(prize.token_identifier, prize.nonce, prize.amount);
+        }
+
+        let call = call
+            .arg(&format!("u64:{}", prize.duration_in_s))
+            .arg(&format!("str:pseudo-{}", sponsor.name()))
+            .arg(&format!("str:{}", website))
+            .arg("str:")
+            .arg("str:")
+            .arg("str:")
+            .arg("str:")
+            .arg(&format!("str:{}/logo.svg", website))
+            .arg(&format!("str:Welcome to my {} prize. Good luck !", prize.kind.label()))
+            .arg("false")
+            .arg("false")
+            .out(&prize.kind.iid().to_string());
+        steps.push(call.to_json());
+    }
+
+    return StepsFile {
+        path: String::from("create_prizes.steps.json"),
+        name: String::from("Create prizes"),
+        comment: Some(String::from("Create 1 prize of each type (ESDT, NFT, EGLD, ...)")),
+        steps: steps,
+    };
+}
+
+fn set_manual_claim() -> StepsFile {
+    let call = Call::new("setParamManClaim_true", "", Actor::Owner, "setParamManClaim").arg("1").with_message("");
+
+    return StepsFile {
+        path: String::from("admin_actions/admin_setParamManClaim_true.steps.json"),
+        name: String::from("Setup manual prize claim for winner"),
+        comment: None,
+        steps: vec![call.to_json()],
+    };
+}
@@ -0,0 +1,513 @@
+use crate::json::amount;
+use crate::world::{Actor, Holding, PrizeKind, World, CONTRACT, GAS_LIMIT};
+use prize::InstanceStatus;
+use serde_json::{json, Map, Value};
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Position in the playing period of a prize created at timestamp 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Moment {
+    Mid,
+    BeforeEnd,
+    End,
+}
+
+// Expected account state, other accounts & fields are not checked
+#[derive(Debug, Clone, PartialEq)]
+pub enum Check {
+    Balance(Actor, u128),
+    Esdt(Actor, &'static str, u128),
+    // Same as Esdt, balance written as given (e.g. without thousands separators)
+    EsdtWritten(Actor, &'static str, &'static str),
+    // Nonce & balance of the instances held, empty when all the instances have been sent
+    Nft(Actor, &'static str, Vec<(u64, u128)>),
+    // Same as Nft, creator & royalties of the instances are not checked
+    NftAnyMetadata(Actor, &'static str, Vec<(u64, u128)>),
+}
+
+#[derive(Clone, PartialEq)]
+pub enum Step {
+    // Accounts, deployment, prizes creation & manual claim enabled
+    InitialSetup,
+    Play { player: u8, prize: PrizeKind },
+    Forward { prize: PrizeKind, moment: Moment },
+    Trigger(PrizeKind),
+    Claim { actor: Actor, prize: PrizeKind },
+    CleanClaimed,
+    // Restricted endpoint called by an account without the required role (e.g. "admin"), failing with the message
+    // Written as admin_<file_name>_bad_caller.steps.json
+    BadCaller { caller: Actor, file_name: &'static str, role: &'static str, function: &'static str, arguments: Vec<&'static str>, message: &'static str },
+    // Label names the steps file, it differs from the expected status for some hand-written scenarios
+    // Query status is the expected status of the view call, "0" or "" in the hand-written scenarios
+    ExpectStatus { iid: u32, label: InstanceStatus, status: InstanceStatus, query_status: &'static str },
+    ExpectNb(u32),
+    // getIDs filtered with a single status
+    ExpectIds { status: InstanceStatus, iids: Vec<u32> },
+    ExpectHasWon { player: u8, prize: PrizeKind, result: bool },
+    RandomSeed(&'static str),
+    CheckState { comment: &'static str, checks: Vec<Check> },
+    // Any endpoint call, path relative to the steps directory
+    Transaction { path: &'static str, name: &'static str, call: Call },
+    // Any view call, path relative to the steps directory
+    Query { path: &'static str, name: &'static str, query: Query },
+}
+
+// Steps file shared by the scenarios, path relative to the steps directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepsFile {
+    pub path: String,
+    pub name: String,
+    pub comment: Option<String>,
+    pub steps: Vec<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeneratedStep {
+    External(StepsFile),
+    Inline(Value),
+}
+
+// Transaction sent to the contract
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    tx_id: String,
+    comment: String,
+    from: Actor,
+    egld_value: u128,
+    esdt_value: Option<Value>,
+    function: &'static str,
+    arguments: Vec<String>,
+    out: Vec<String>,
+    status: &'static str,
+    message: Option<&'static str>,
+}
+
+// View call without transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    tx_id: String,
+    comment: String,
+    function: &'static str,
+    arguments: Vec<String>,
+    out: Vec<String>,
+    status: &'static str,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl Moment {
+    pub fn timestamp(&self, duration_in_s: u64) -> u64 {
+        match self {
+            Moment::Mid => return duration_in_s / 2,
+            Moment::BeforeEnd => return duration_in_s - 1,
+            Moment::End => return duration_in_s + 1,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Moment::Mid => return "mid",
+            Moment::BeforeEnd => return "before_end",
+            Moment::End => return "end",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Moment::Mid => return "middle of",
+            Moment::BeforeEnd => return "before end of",
+            Moment::End => return "end of",
+        }
+    }
+}
+
+impl Check {
+    fn actor(&self) -> Actor {
+        match self {
+            Check::Balance(actor, _) => return *actor,
+            Check::Esdt(actor, _, _) => return *actor,
+            Check::EsdtWritten(actor, _, _) => return *actor,
+            Check::Nft(actor, _, _) => return *actor,
+            Check::NftAnyMetadata(actor, _, _) => return *actor,
+        }
+    }
+
+    // Returns : None for balance checks & balances written as given
+    fn to_holding(&self, world: &World) -> Option<Holding> {
+        match self {
+            Check::Balance(_, _) | Check::EsdtWritten(_, _, _) => return None,
+            Check::Esdt(_, token_identifier, token_amount) => return Some(Holding::Fungible { token_identifier: *token_identifier, amount: *token_amount }),
+            Check::Nft(_, token_identifier, instances) | Check::NftAnyMetadata(_, token_identifier, instances) => {
+                return Some(Holding::NonFungible {
+                    token_identifier: *token_identifier,
+                    metadata: world.token_metadata(token_identifier).unwrap().clone(),
+                    instances: instances.clone(),
+                });
+            },
+        }
+    }
+}
+
+impl Call {
+    pub fn new(tx_id: &str, comment: &str, from: Actor, function: &'static str) -> Self {
+        return Call {
+            tx_id: String::from(tx_id),
+            comment: String::from(comment),
+            from: from,
+            egld_value: 0,
+            esdt_value: None,
+            function: function,
+            arguments: Vec::new(),
+            out: Vec::new(),
+            status: "0",
+            message: None,
+        };
+    }
+
+    pub fn arg(mut self, argument: &str) -> Self {
+        self.arguments.push(String::from(argument));
+        return self;
+    }
+
+    pub fn egld_value(mut self, value: u128) -> Self {
+        self.egld_value = value;
+        return self;
+    }
+
+    pub fn esdt_value(mut self, token_identifier: &str, nonce: u64, value: u128) -> Self {
+        let mut transfer = Map::new();
+        transfer.insert(String::from("tokenIdentifier"), json!(format!("str:{}", token_identifier)));
+        if nonce != 0 {
+            transfer.insert(String::from("nonce"), json!(nonce.to_string()));
+        }
+        transfer.insert(String::from("value"), json!(amount(value)));
+
+        self.esdt_value = Some(json!([transfer]));
+        return self;
+    }
+
+    pub fn out(mut self, value: &str) -> Self {
+        self.out.push(String::from(value));
+        return self;
+    }
+
+    pub fn fails_with(mut self, message: &'static str) -> Self {
+        self.status = "4";
+        self.message = Some(message);
+        return self;
+    }
+
+    // Message expected from a successful call
+    pub fn with_message(mut self, message: &'static str) -> Self {
+        self.message = Some(message);
+        return self;
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut tx = Map::new();
+        tx.insert(String::from("from"), json!(self.from.address()));
+        tx.insert(String::from("to"), json!(CONTRACT));
+        tx.insert(String::from("egldValue"), json!(amount(self.egld_value)));
+        if let Some(esdt_value) = &self.esdt_value {
+            tx.insert(String::from("esdtValue"), esdt_value.clone());
+        }
+        tx.insert(String::from("function"), json!(self.function));
+        tx.insert(String::from("arguments"), json!(self.arguments));
+        tx.insert(String::from("gasLimit"), json!(amount(GAS_LIMIT as u128)));
+        tx.insert(String::from("gasPrice"), json!("0"));
+
+        let mut expect = Map::new();
+        expect.insert(String::from("out"), json!(self.out));
+        expect.insert(String::from("status"), json!(self.status));
+        if let Some(message) = self.message {
+            expect.insert(String::from("message"), json!(message));
+        }
+        expect.insert(String::from("gas"), json!("*"));
+        expect.insert(String::from("refund"), json!("*"));
+
+        return json!({
+            "step": "scCall",
+            "txId": self.tx_id,
+            "comment": self.comment,
+            "tx": tx,
+            "expect": expect
+        });
+    }
+}
+
+impl Query {
+    pub fn new(tx_id: &str, comment: &str, function: &'static str) -> Self {
+        return Query { tx_id: String::from(tx_id), comment: String::from(comment), function: function, arguments: Vec::new(), out: Vec::new(), status: "0" };
+    }
+
+    pub fn arg(mut self, argument: &str) -> Self {
+        self.arguments.push(String::from(argument));
+        return self;
+    }
+
+    pub fn out(mut self, value: &str) -> Self {
+        self.out.push(String::from(value));
+        return self;
+    }
+
+    pub fn status(mut self, status: &'static str) -> Self {
+        self.status = status;
+        return self;
+    }
+
+    pub fn to_json(&self) -> Value {
+        return json!({
+            "step": "scQuery",
+            "txId": self.tx_id,
+            "comment": self.comment,
+            "tx": {
+                "to": CONTRACT,
+                "function": self.function,
+                "arguments": self.arguments
+            },
+            "expect": {
+                "out": self.out,
+                "status": self.status
+            }
+        });
+    }
+}
+
+fn status_name(status: InstanceStatus) -> &'static str {
+    match status {
+        InstanceStatus::NotExisting => return "not_existing",
+        InstanceStatus::Running => return "running",
+        InstanceStatus::Ended => return "ended",
+        InstanceStatus::Triggered => return "triggered",
+        InstanceStatus::Claimed => return "claimed",
+        InstanceStatus::Disabled => return "disabled",
+    }
+}
+
+// Returns : steps directory of the actions of an actor
+fn actions_directory(actor: Actor) -> &'static str {
+    match actor {
+        Actor::Owner => return "admin_actions",
+        Actor::Sponsor(_) => return "sponsor_actions",
+        Actor::Player(_) => return "player_actions",
+    }
+}
+
+impl StepsFile {
+    fn new(path: String, name: String, steps: Vec<Value>) -> Self {
+        return StepsFile { path: path, name: name, comment: None, steps: steps };
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut file = Map::new();
+        file.insert(String::from("name"), json!(self.name));
+        if let Some(comment) = &self.comment {
+            file.insert(String::from("comment"), json!(comment));
+        }
+        file.insert(String::from("steps"), json!(self.steps));
+        return Value::Object(file);
+    }
+}
+
+impl Step {
+    pub fn generate(&self, world: &World) -> GeneratedStep {
+        match self {
+            Step::InitialSetup => return GeneratedStep::External(crate::setup::initial_setup()),
+            Step::Play { player, prize } => {
+                let actor = Actor::Player(*player);
+                let call = Call::new(&format!("{}_play_{}_prize", actor.name(), prize.name()), &format!("{} plays to {} prize", actor.title(), prize.label()), actor, "play")
+                    .arg(&prize.iid().to_string())
+                    .out("*");
+                return GeneratedStep::External(StepsFile::new(
+                    format!("player_actions/{}_plays_to_{}_prize.steps.json", actor.name(), prize.name()),
+                    format!("{} plays {} prize", actor.name(), prize.name()),
+                    vec![call.to_json()],
+                ));
+            },
+            Step::Forward { prize, moment } => {
+                let timestamp = moment.timestamp(world.prize(*prize).duration_in_s);
+                let step = json!({
+                    "step": "setState",
+                    "comment": format!("Forward in time : {} the {} prize playing period", moment.description(), prize.label()),
+                    "currentBlockInfo": {
+                        "blockTimestamp": timestamp.to_string()
+                    }
+                });
+                return GeneratedStep::External(StepsFile::new(
+                    format!("time_management/{}_prize_forward_{}_period.steps.json", prize.name(), moment.name()),
+                    format!("{} prize: forward {} period", prize.label(), moment.name().replace('_', " ")),
+                    vec![step],
+                ));
+            },
+            Step::Trigger(prize) => {
+                let definition = world.prize(*prize);
+                let call = Call::new("trigger", definition.trigger_comment, Actor::Owner, "prize").arg(&prize.iid().to_string());
+                return GeneratedStep::External(StepsFile::new(
+                    format!("admin_actions/admin_trigger_{}_prize.steps.json", prize.name()),
+                    String::from(definition.trigger_name),
+                    vec![call.to_json()],
+                ));
+            },
+            Step::Claim { actor, prize } => {
+                let call = Call::new(&format!("claim-{}-prize", prize.name()), &format!("{} claims {} prize", actor.title(), prize.label()), *actor, "claimPrize").arg(&prize.iid().to_string());
+                let name = match actor {
+                    Actor::Sponsor(kind) if kind == prize => String::from(world.prize(*prize).sponsor_claim_name),
+                    _ => format!("{} claims {} prize", actor.label(), prize.name()),
+                };
+                return GeneratedStep::External(StepsFile::new(
+                    format!("{}/{}_claims_{}_prize.steps.json", actions_directory(*actor), actor.name(), prize.name()),
+                    name,
+                    vec![call.to_json()],
+                ));
+            },
+            Step::CleanClaimed => {
+                let call = Call::new("cleanClaimed", "admin calls cleanClaimed endpoint", Actor::Owner, "cleanClaimed");
+                return GeneratedStep::External(StepsFile::new(String::from("admin_actions/admin_cleanClaimed.steps.json"), String::from("cleanClaimed endpoint"), vec![call.to_json()]));
+            },
+            Step::BadCaller { caller, file_name, role, function, arguments, message } => {
+                let mut call = Call::new(function, &format!("non {} tries to call {} endpoint", role, function), *caller, *function).fails_with(message);
+                for argument in arguments.iter() {
+                    call = call.arg(argument);
+                }
+                return GeneratedStep::External(StepsFile::new(
+                    format!("admin_actions/admin_{}_bad_caller.steps.json", file_name),
+                    format!("{} endpoint bad caller", function),
+                    vec![call.to_json()],
+                ));
+            },
+            Step::ExpectStatus { iid, label, status, query_status } => {
+                let description = status_name(*label).replace('_', " ");
+                let step = Query::new(&format!("get_status_{}_{}", iid, status_name(*label)), &format!("expected status on prize #{} : {}", iid, description), "getStatus")
+                    .arg(&iid.to_string())
+                    .out(&(*status as u8).to_string())
+                    .status(query_status)
+                    .to_json();
+                return GeneratedStep::External(StepsFile::new(
+                    format!("query_getStatus/query_getStatus_[{}]_[{}].steps.json", iid, status_name(*label)),
+                    format!("getStatus query on prize #{} ; result expected : {}", iid, description),
+                    vec![step],
+                ));
+            },
+            Step::ExpectNb(nb_instances) => {
+                let step = Query::new(&format!("get_nb_{}", nb_instances), &format!("expected status : {}", nb_instances), "getNb").out(&nb_instances.to_string()).to_json();
+                return GeneratedStep::External(StepsFile::new(
+                    format!("query_getNb/query_getNb_[{}].steps.json", nb_instances),
+                    format!("getNb query ; result expected : {}", nb_instances),
+                    vec![step],
+                ));
+            },
+            Step::ExpectIds { status, iids } => {
+                let code = *status as u8;
+                let iids: Vec<String> = iids.iter().map(|iid| iid.to_string()).collect();
+
+                let (file_suffix, tx_id_suffix, description) = match iids.len() {
+                    0 => (String::from("none"), String::from("none"), String::from("none")),
+                    _ => (iids.join("-"), iids.join("_"), iids.join(", ")),
+                };
+                // First page holds all the instances : next cursor is 0
+                let mut query = Query::new(&format!("get_IDs_{}_{}", status_name(*status), tx_id_suffix), &format!("expected prizes {} : {}", status_name(*status).replace('_', " "), description), "getIDs")
+                    .arg("0")
+                    .arg("100")
+                    .arg(&code.to_string())
+                    .out("0");
+                for iid in iids.iter() {
+                    query = query.out(iid);
+                }
+                let step = query.to_json();
+                return GeneratedStep::External(StepsFile::new(
+                    format!("query_getIDs/query_getIDs_[{}]_[{}].steps.json", code, file_suffix),
+                    format!("getIDs query with <{}> filter ; result expected : {}", status_name(*status).replace('_', " "), description),
+                    vec![step],
+                ));
+            },
+            Step::ExpectHasWon { player, prize, result } => {
+                let actor = Actor::Player(*player);
+                let step = Query::new(&format!("has_won_player_{}_{}_{}", player, prize.iid(), result), &format!("expected result : {}", result), "hasWon")
+                    .arg(&prize.iid().to_string())
+                    .arg(&actor.address())
+                    .out(if *result == true { "1" } else { "" })
+                    .to_json();
+                return GeneratedStep::External(StepsFile::new(
+                    format!("query_hasWon/{}_hasWon_{}_prize_{}.steps.json", actor.name(), prize.name(), result),
+                    format!("hasWon query {} on {} prize; result expected : {}", actor.name(), prize.name(), result),
+                    vec![step],
+                ));
+            },
+            Step::RandomSeed(seed) => {
+                return GeneratedStep::Inline(json!({
+                    "step": "setState",
+                    "currentBlockInfo": {
+                        "blockRandomSeed": seed
+                    }
+                }));
+            },
+            Step::CheckState { comment, checks } => {
+                let mut accounts = Map::new();
+
+                for account in world.accounts.iter() {
+                    let account_checks: Vec<&Check> = checks.iter().filter(|check| check.actor() == account.actor).collect();
+
+                    let mut balance = json!("*");
+                    let mut esdt = Map::new();
+                    for check in account_checks.iter() {
+                        match check.to_holding(world) {
+                            None => match check {
+                                Check::Balance(_, expected_balance) => balance = json!(amount(*expected_balance)),
+                                Check::EsdtWritten(_, token_identifier, written_balance) => {
+                                    esdt.insert(format!("str:{}", token_identifier), json!(written_balance));
+                                },
+                                _ => {},
+                            },
+                            Some(holding) => {
+                                let mut holding_json = holding.to_json();
+                                if let Check::NftAnyMetadata(_, _, _) = check {
+                                    for instance in holding_json["instances"].as_array_mut().unwrap().iter_mut() {
+                                        instance["creator"] = json!("*");
+                                        instance["royalties"] = json!("*");
+                                    }
+                                }
+                                esdt.insert(format!("str:{}", holding.token_identifier()), holding_json);
+                            },
+                        }
+                    }
+
+                    let mut entry = Map::new();
+                    entry.insert(String::from("nonce"), json!("*"));
+                    entry.insert(String::from("balance"), balance);
+                    if esdt.is_empty() == false {
+                        entry.insert(String::from("esdt"), Value::Object(esdt));
+                    } else if account.holdings.is_empty() == false {
+                        entry.insert(String::from("esdt"), json!("*"));
+                    }
+                    accounts.insert(account.actor.address(), Value::Object(entry));
+                }
+
+                accounts.insert(
+                    String::from(CONTRACT),
+                    json!({
+                        "nonce": "*",
+                        "balance": "*",
+                        "esdt": "*",
+                        "code": "file:../output/prize.wasm"
+                    }),
+                );
+
+                return GeneratedStep::Inline(json!({
+                    "step": "checkState",
+                    "comment": comment,
+                    "accounts": accounts
+                }));
+            },
+            Step::Transaction { path, name, call } => {
+                return GeneratedStep::External(StepsFile::new(String::from(*path), String::from(*name), vec![call.to_json()]));
+            },
+            Step::Query { path, name, query } => {
+                return GeneratedStep::External(StepsFile::new(String::from(*path), String::from(*name), vec![query.to_json()]));
+            },
+        }
+    }
+}
@@ -0,0 +1,662 @@
+use crate::json::amount;
+use crate::scenario::{Scenario, Suite};
+use crate::step::{Call, Check, Moment, Query, Step};
+use crate::world::{Actor, PrizeKind, World, EGLD};
+use prize::InstanceStatus;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+
+// First instance created after the initial setup
+const NEW_IID: &str = "5";
+// Fees of the instances created after the fee policy update
+const FEES: u128 = EGLD / 10;
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+
+// Returns : description of the scenarios of the mandos directory
+pub fn prize_suite() -> Suite {
+    return Suite {
+        world: World::new(),
+        scenarios: vec![
+            admin_endpoints_bad_caller(),
+            bond(),
+            charity(),
+            clean_claimed_2_out_of_4(),
+            clean_claimed_one_by_one(),
+            ended(),
+            jackpot(),
+            loyalty(),
+            pot(),
+            pot_slash(),
+            prize_egld(),
+            prize_esdt(),
+            prize_life_cycle(),
+            prize_nft(),
+            prize_sft(),
+            promo(),
+            randomness(),
+            referral(),
+        ],
+    };
+}
+
+fn admin_endpoints_bad_caller() -> Scenario {
+    let sponsor = Actor::Sponsor(PrizeKind::Egld);
+
+    return Scenario::new("err_admin_endpoints_bad_caller", "admin endpoints bad caller", "Try to call admin endpoint with non admin address")
+        .bad_caller(Actor::Player(2), "cleanClaimed", "cleanClaimed", &[])
+        .bad_caller(Actor::Player(3), "setFeePol", "setFeePol", &["1000000000000000", "u8:20"])
+        .bad_fee_claimer(sponsor)
+        .bad_caller(sponsor, "pushFees", "pushFees", &[])
+        .bad_caller(sponsor, "claimDonations", "claimDonations", &[])
+        .bad_caller(sponsor, "setPremium", "setPremium", &["1", "1"])
+        .bad_caller(sponsor, "disable", "disable", &["1", "1"])
+        .bad_caller(sponsor, "set_param_manual_claim", "setParamManClaim", &["1"])
+        .bad_caller(sponsor, "set_param_nb_max_instances_per_sponsor", "setParamNbMaxInstancesPerSponsor", &["20"])
+        .bad_caller(sponsor, "set_param_duration", "setParamDuration", &["60", "120"])
+        .bad_caller(sponsor, "set_param_sponsor_info_max_length", "setParamSponsorInfoMaxLength", &["10"])
+        .bad_caller(sponsor, "add_addr_blacklist", "addAddrBlacklist", &["address:player1"])
+        .bad_caller(sponsor, "rm_addr_blacklist", "rmAddrBlacklist", &["address:player1"])
+        .bad_caller(sponsor, "set_log_enable_status", "setLogEnableStatus", &["1"]);
+}
+
+fn clean_claimed_2_out_of_4() -> Scenario {
+    return Scenario::new("nom_cleanClaimed_2_out_of_4", "cleanClaimed 2 out of 4", "Call cleanClaimed with the following context : 2 out of 4 prizes claimed")
+        .expect_nb(4)
+        .forward(PrizeKind::Nft, Moment::End)
+        .trigger(PrizeKind::Egld)
+        .trigger(PrizeKind::Esdt)
+        .trigger(PrizeKind::Nft)
+        .claim(Actor::Sponsor(PrizeKind::Egld), PrizeKind::Egld)
+        .claim(Actor::Sponsor(PrizeKind::Nft), PrizeKind::Nft)
+        .clean_claimed()
+        .expect_status(1, InstanceStatus::NotExisting)
+        .expect_status_labelled(2, InstanceStatus::Ended, InstanceStatus::Triggered)
+        .expect_status(3, InstanceStatus::NotExisting)
+        .expect_status(4, InstanceStatus::Running)
+        .expect_nb(2);
+}
+
+fn clean_claimed_one_by_one() -> Scenario {
+    let mut scenario = Scenario::new("nom_cleanClaimed_one_by_one", "cleanClaimed one by one", "Call cleanClaimed with the following contexts : 0, 1, 2, 3, 4 prizes claimed")
+        .clean_claimed()
+        .expect_nb(4);
+
+    for (index, kind) in PrizeKind::all().iter().enumerate() {
+        scenario = scenario
+            .forward(*kind, Moment::End)
+            .trigger(*kind)
+            .claim(Actor::Sponsor(*kind), *kind)
+            .clean_claimed()
+            .expect_status(kind.iid(), InstanceStatus::NotExisting)
+            .expect_nb(3 - index as u32);
+    }
+
+    return scenario;
+}
+
+fn ended() -> Scenario {
+    let mut scenario = Scenario::new("nom_ended", "trigger", "Call trigger with the following contexts : 0, 1, 2, 3, 4 prizes ended").expect_ids(InstanceStatus::Ended, &[]);
+    let mut ended_iids: Vec<u32> = Vec::new();
+
+    for kind in PrizeKind::all().iter() {
+        ended_iids.push(kind.iid());
+        scenario = scenario.forward(*kind, Moment::End).expect_ids(InstanceStatus::Ended, &ended_iids);
+    }
+
+    return scenario;
+}
+
+// Player #1 plays alone, wins & claims the prize
+fn single_player(file_name: &'static str, name: &'static str, comment: &'static str, kind: PrizeKind) -> Scenario {
+    return Scenario::new(file_name, name, comment)
+        .forward(kind, Moment::Mid)
+        .play(1, kind)
+        .forward(kind, Moment::End)
+        .trigger(kind)
+        .claim(Actor::Player(1), kind);
+}
+
+fn prize_egld() -> Scenario {
+    return single_player("nom_prize_egld", "Prize EGLD", "Create a prize with EGLD prize, make player1 winning it", PrizeKind::Egld).check_state(
+        "check balances following prize claim",
+        vec![Check::Balance(Actor::Sponsor(PrizeKind::Egld), 99 * EGLD), Check::Balance(Actor::Player(1), 101 * EGLD)],
+    );
+}
+
+fn prize_esdt() -> Scenario {
+    let sponsor = Actor::Sponsor(PrizeKind::Esdt);
+
+    return single_player("nom_prize_esdt", "Prize ESDT", "Create a prize with ESDT prize, make player1 winning it", PrizeKind::Esdt).check_state(
+        "check balances following prize claim",
+        vec![
+            Check::Esdt(sponsor, "MEX-abcdef", 4_999_999_000),
+            Check::Esdt(sponsor, "RIDE-abcdef", 5_000_000_000),
+            Check::Balance(Actor::Player(1), 100 * EGLD),
+            Check::EsdtWritten(Actor::Player(1), "MEX-abcdef", "1000"),
+        ],
+    );
+}
+
+fn prize_nft() -> Scenario {
+    return single_player("nom_prize_nft", "Prize NFT", "Create a prize with NFT prize, make player1 winning it", PrizeKind::Nft).check_state(
+        "check balances following prize claim",
+        vec![
+            Check::Nft(Actor::Sponsor(PrizeKind::Nft), "NFT-123456", Vec::new()),
+            Check::Balance(Actor::Player(1), 100 * EGLD),
+            Check::NftAnyMetadata(Actor::Player(1), "NFT-123456", vec![(1, 1)]),
+        ],
+    );
+}
+
+fn prize_sft() -> Scenario {
+    return single_player("nom_prize_sft", "Prize SFT", "Create a prize with SFT prize, make player1 winning it", PrizeKind::Sft).check_state(
+        "check balances following prize claim",
+        vec![
+            Check::Nft(Actor::Sponsor(PrizeKind::Sft), "SFT-123456", vec![(1, 10), (2, 5)]),
+            Check::Balance(Actor::Player(1), 100 * EGLD),
+            Check::Nft(Actor::Player(1), "SFT-123456", vec![(2, 5)]),
+        ],
+    );
+}
+
+fn prize_life_cycle() -> Scenario {
+    return Scenario::new("nom_prize_life_cycle", "prize life cycle", "Check the different states of a prize : not existing, running, ended, triggered, claimed")
+        .expect_status_with_query_status(5, InstanceStatus::NotExisting, "")
+        .expect_status_with_query_status(1, InstanceStatus::Running, "")
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .expect_status_with_query_status(1, InstanceStatus::Running, "")
+        .play(1, PrizeKind::Egld)
+        .forward(PrizeKind::Egld, Moment::BeforeEnd)
+        .expect_status_with_query_status(1, InstanceStatus::Running, "")
+        .forward(PrizeKind::Egld, Moment::End)
+        .expect_status_with_query_status(1, InstanceStatus::Ended, "")
+        .trigger(PrizeKind::Egld)
+        .expect_status_with_query_status(1, InstanceStatus::Triggered, "")
+        .claim(Actor::Player(1), PrizeKind::Egld)
+        .expect_status_with_query_status(1, InstanceStatus::Claimed, "");
+}
+
+// 5 players play each prize, the block random seed decides the winner
+fn randomness() -> Scenario {
+    let draws: [(PrizeKind, &'static str, u8); 4] = [
+        (PrizeKind::Egld, "0x0000000000000010000120000000000B000000000000000000000000000000000000000000000000000000000000000", 5),
+        (PrizeKind::Esdt, "0x000000000000000000000100000000010000000000000000000000000000000000000000000000000000000000000000", 3),
+        (PrizeKind::Nft, "0x000000800000004020032030070003000000000000000000000000000000000000000000000000000000000000000000", 1),
+        (PrizeKind::Sft, "0x000000000000000020000306000000000000000000000000000000000000000000000000000000000000000000000000", 2),
+    ];
+
+    let mut scenario = Scenario::new("nom_randomness", "Randomness", "Make the same players playing several times and check the winner evolves");
+    for (kind, seed, winner) in draws.iter() {
+        scenario = scenario
+            .forward(*kind, Moment::Mid)
+            .plays(&[1, 2, 3, 4, 5], *kind)
+            .forward(*kind, Moment::End)
+            .random_seed(*seed)
+            .trigger(*kind)
+            .expect_winner(*winner, *kind);
+    }
+
+    return scenario;
+}
+
+// Instance created by the EGLD sponsor after the initial setup, lasting as long as the EGLD prize
+fn create_call(tx_id: &str, comment: &str, function: &'static str, charity_id: &str) -> Call {
+    let sponsor = Actor::Sponsor(PrizeKind::Egld);
+    let website = format!("https://website-{}.com", sponsor.name());
+
+    return Call::new(tx_id, comment, sponsor, function)
+        .arg("u64:60")
+        .arg(&format!("str:pseudo-{}", sponsor.name()))
+        .arg(&format!("str:{}", website))
+        .arg("str:")
+        .arg("str:")
+        .arg("str:")
+        .arg("str:")
+        .arg(&format!("str:{}/logo.svg", website))
+        .arg("str:Welcome to my new prize. Good luck !")
+        .arg("false")
+        .arg(charity_id);
+}
+
+// Fee policy without rewards, applied to the instances created afterwards
+fn set_fees_without_rewards() -> Step {
+    return Step::Transaction {
+        path: "admin_actions/admin_setFeePol_fees_only.steps.json",
+        name: "setFeePol endpoint without rewards",
+        call: Call::new("setFeePol", "admin sets fees of 0.1 EGLD without rewards", Actor::Owner, "setFeePol").arg(&amount(FEES)).arg("u8:0").arg("u8:0").arg("u8:0"),
+    };
+}
+
+fn create_egld_prize() -> Step {
+    return Step::Transaction {
+        path: "sponsor_actions/sponsorEGLD_creates_prize_5.steps.json",
+        name: "sponsor EGLD creates prize #5",
+        call: create_call("create-prize-5", "SponsorEGLD creates prize #5 with 1 EGLD prize", "create", "false").egld_value(EGLD).out(NEW_IID),
+    };
+}
+
+fn create_pot_prize() -> Step {
+    return Step::Transaction {
+        path: "sponsor_actions/sponsorEGLD_creates_pot_prize.steps.json",
+        name: "sponsor EGLD creates pot prize",
+        call: create_call("create-pot-prize", "SponsorEGLD creates prize #5 funded by the fees", "createPot", "false").out(NEW_IID),
+    };
+}
+
+fn play_paying_fees() -> Step {
+    return Step::Transaction {
+        path: "player_actions/player1_plays_to_prize_5_paying_fees.steps.json",
+        name: "player1 plays prize #5 paying fees",
+        call: Call::new("player1_play_prize_5", "Player1 pays 0.1 EGLD fees to play prize #5", Actor::Player(1), "play").egld_value(FEES).arg(NEW_IID).out("1"),
+    };
+}
+
+fn trigger_new_prize() -> Step {
+    return Step::Transaction {
+        path: "admin_actions/admin_trigger_prize_5.steps.json",
+        name: "trigger prize #5",
+        call: Call::new("trigger", "admin triggers prize #5", Actor::Owner, "prize").arg(NEW_IID),
+    };
+}
+
+fn claim_new_prize() -> Step {
+    return Step::Transaction {
+        path: "player_actions/player1_claims_prize_5.steps.json",
+        name: "player1 claims prize #5",
+        call: Call::new("claim-prize-5", "Player1 claims prize #5", Actor::Player(1), "claimPrize").arg(NEW_IID),
+    };
+}
+
+// Sponsor bond locked by a new sponsor until its prize is claimed
+fn bond() -> Scenario {
+    let sponsor = Actor::Player(1);
+
+    return Scenario::new("nom_bond", "Sponsor bond", "Require a bond of 1 EGLD to create prizes, player1 creates a pot prize & withdraws its bond once the prize is claimed")
+        .transaction(
+            "admin_actions/admin_setBondPol_1_egld.steps.json",
+            "setBondPol endpoint",
+            Call::new("setBondPol", "admin requires a bond of 1 EGLD", Actor::Owner, "setBondPol").arg("str:EGLD").arg(&amount(EGLD)),
+        )
+        .transaction(
+            "player_actions/player1_creates_pot_prize_without_bond.steps.json",
+            "player1 creates pot prize without bond",
+            Call::new("create-pot-prize-no-bond", "Player1 creates a pot prize without bond", sponsor, "createPot")
+                .arg("u64:60")
+                .arg("str:pseudo-player1")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("false")
+                .arg("false")
+                .fails_with("str:Sponsor bond required"),
+        )
+        .transaction(
+            "player_actions/player1_deposits_bond.steps.json",
+            "player1 deposits bond",
+            Call::new("deposit-bond", "Player1 deposits a bond of 1 EGLD", sponsor, "depositBond").egld_value(EGLD),
+        )
+        .query(
+            "query_getSponsorBond/query_getSponsorBond_[player1]_[1_EGLD].steps.json",
+            "getSponsorBond query on player1 ; result expected : 1 EGLD",
+            Query::new("get_sponsor_bond_player1_1_egld", "expected bond : 1 EGLD", "getSponsorBond").arg(&sponsor.address()).out("str:EGLD").out(&amount(EGLD)),
+        )
+        .transaction(
+            "player_actions/player1_creates_pot_prize.steps.json",
+            "player1 creates pot prize",
+            Call::new("create-pot-prize", "Player1 creates a pot prize", sponsor, "createPot")
+                .arg("u64:60")
+                .arg("str:pseudo-player1")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("str:")
+                .arg("false")
+                .arg("false")
+                .out(NEW_IID),
+        )
+        .transaction(
+            "player_actions/player1_withdraws_bond_prize_running.steps.json",
+            "player1 withdraws bond while prize running",
+            Call::new("withdraw-bond-running", "Player1 withdraws its bond while its prize is running", sponsor, "withdrawBond").fails_with("str:Sponsor has running instances"),
+        )
+        .forward(PrizeKind::Egld, Moment::End)
+        .step(trigger_new_prize())
+        .transaction(
+            "player_actions/player1_withdraws_bond_prize_unclaimed.steps.json",
+            "player1 withdraws bond while prize unclaimed",
+            Call::new("withdraw-bond-unclaimed", "Player1 withdraws its bond while its prize is unclaimed", sponsor, "withdrawBond").fails_with("str:Sponsor has unclaimed or disabled instances"),
+        )
+        .step(claim_new_prize())
+        .transaction(
+            "player_actions/player1_withdraws_bond.steps.json",
+            "player1 withdraws bond",
+            Call::new("withdraw-bond", "Player1 withdraws its bond", sponsor, "withdrawBond"),
+        )
+        .query(
+            "query_getSponsorBond/query_getSponsorBond_[player1]_[0].steps.json",
+            "getSponsorBond query on player1 ; result expected : 0",
+            Query::new("get_sponsor_bond_player1_0", "expected bond : 0", "getSponsorBond").arg(&sponsor.address()).out("str:EGLD").out("0"),
+        )
+        .check_state("check balances following bond withdrawal", vec![Check::Balance(sponsor, 100 * EGLD)]);
+}
+
+// Player1 donates to the charity of the prize, the charity claims the donation
+fn charity() -> Scenario {
+    let charity = Actor::Player(5);
+
+    return Scenario::new("nom_charity", "Charity", "Register player5 as charity, player1 donates 0.5 EGLD playing a prize created for the charity")
+        .transaction(
+            "admin_actions/admin_addCharity.steps.json",
+            "addCharity endpoint",
+            Call::new("add-charity", "admin registers player5 as charity #1", Actor::Owner, "addCharity").arg(&charity.address()).arg("str:Charity").arg("str:https://charity.org").out("1"),
+        )
+        .transaction(
+            "sponsor_actions/sponsorEGLD_creates_prize_unknown_charity.steps.json",
+            "sponsor EGLD creates prize for unknown charity",
+            create_call("create-prize-unknown-charity", "SponsorEGLD creates a prize for charity #2", "create", "2").egld_value(EGLD).fails_with("str:Charity not available"),
+        )
+        .transaction(
+            "sponsor_actions/sponsorEGLD_creates_charity_prize.steps.json",
+            "sponsor EGLD creates charity prize",
+            create_call("create-charity-prize", "SponsorEGLD creates prize #5 for charity #1", "create", "1").egld_value(EGLD).out(NEW_IID),
+        )
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .transaction(
+            "player_actions/player1_plays_to_prize_5_with_donation.steps.json",
+            "player1 plays prize #5 with donation",
+            Call::new("player1_play_prize_5_donation", "Player1 donates 0.5 EGLD playing prize #5", Actor::Player(1), "play").egld_value(EGLD / 2).arg(NEW_IID).out("1"),
+        )
+        .query(
+            "query_getInstanceDonations/query_getInstanceDonations_[5]_[0.5_EGLD].steps.json",
+            "getInstanceDonations query on prize #5 ; result expected : 0.5 EGLD",
+            Query::new("get_instance_donations_5", "expected donations : 0.5 EGLD", "getInstanceDonations").arg(NEW_IID).out(&amount(EGLD / 2)),
+        )
+        .transaction(
+            "player_actions/player2_claims_charity_donations.steps.json",
+            "player2 claims charity donations",
+            Call::new("claim-charity-donations-bad-caller", "Player2 claims the donations of charity #1", Actor::Player(2), "claimCharityDonations").arg("1").fails_with("str:Donations can only be claimed by the charity"),
+        )
+        .transaction(
+            "player_actions/player5_claims_charity_donations.steps.json",
+            "player5 claims charity donations",
+            Call::new("claim-charity-donations", "Player5 claims the donations of charity #1", charity, "claimCharityDonations").arg("1"),
+        )
+        .transaction(
+            "player_actions/player5_claims_charity_donations_again.steps.json",
+            "player5 claims charity donations again",
+            Call::new("claim-charity-donations-again", "Player5 claims the donations of charity #1 again", charity, "claimCharityDonations").arg("1").fails_with("str:No donation to claim"),
+        )
+        .check_state(
+            "check balances following donations claim",
+            vec![Check::Balance(Actor::Sponsor(PrizeKind::Egld), 98 * EGLD), Check::Balance(Actor::Player(1), 99 * EGLD + EGLD / 2), Check::Balance(charity, 100 * EGLD + EGLD / 2)],
+        );
+}
+
+// Jackpot fed by half of the fees, always won
+fn jackpot() -> Scenario {
+    let jackpot_amount = amount(FEES / 2);
+
+    return Scenario::new("nom_jackpot", "Jackpot", "Feed the jackpot with 50% of the fees & make player1 winning it with its prize")
+        .step(set_fees_without_rewards())
+        .transaction(
+            "admin_actions/admin_setJackpotParams.steps.json",
+            "setJackpotParams endpoint",
+            Call::new("setJackpotParams", "admin sets jackpot to 50% of fees, won at each trigger", Actor::Owner, "setJackpotParams").arg("u8:50").arg("u32:10000"),
+        )
+        .query(
+            "query_getJackpotParams/query_getJackpotParams_[50]_[10000].steps.json",
+            "getJackpotParams query ; result expected : 50, 10000",
+            Query::new("get_jackpot_params", "expected jackpot params : 50, 10000", "getJackpotParams").out("50").out("10000"),
+        )
+        .step(create_egld_prize())
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .step(play_paying_fees())
+        .query(
+            "query_getJackpotPool/query_getJackpotPool_[0.05_EGLD].steps.json",
+            "getJackpotPool query ; result expected : 0.05 EGLD",
+            Query::new("get_jackpot_pool_fed", "expected jackpot : 0.05 EGLD", "getJackpotPool").out(&jackpot_amount),
+        )
+        .forward(PrizeKind::Egld, Moment::End)
+        .step(trigger_new_prize())
+        .query(
+            "query_getJackpotWon/query_getJackpotWon_[5]_[0.05_EGLD].steps.json",
+            "getJackpotWon query on prize #5 ; result expected : 0.05 EGLD",
+            Query::new("get_jackpot_won_5", "expected jackpot won : 0.05 EGLD", "getJackpotWon").arg(NEW_IID).out(&jackpot_amount),
+        )
+        .query(
+            "query_getJackpotPool/query_getJackpotPool_[0].steps.json",
+            "getJackpotPool query ; result expected : 0",
+            Query::new("get_jackpot_pool_empty", "expected jackpot : 0", "getJackpotPool").out("0"),
+        )
+        .step(claim_new_prize())
+        .check_state(
+            "check balances following jackpot & prize claim",
+            vec![Check::Balance(Actor::Sponsor(PrizeKind::Egld), 98 * EGLD), Check::Balance(Actor::Player(1), 101 * EGLD - FEES / 2)],
+        );
+}
+
+// Player1 earns points playing prize #5, spends them to play prize #6 & earns points winning prize #5
+fn loyalty() -> Scenario {
+    let player = Actor::Player(1);
+
+    return Scenario::new("nom_loyalty", "Loyalty points", "Award 1000 points per EGLD of fees & 500 points per win, a free entry costs 100 points")
+        .step(set_fees_without_rewards())
+        .transaction(
+            "admin_actions/admin_setLoyaltyPol.steps.json",
+            "setLoyaltyPol endpoint",
+            Call::new("setLoyaltyPol", "admin sets loyalty policy", Actor::Owner, "setLoyaltyPol").arg("1000").arg("500").arg("100"),
+        )
+        .query(
+            "query_getLoyaltyPol/query_getLoyaltyPol_[1000]_[500]_[100].steps.json",
+            "getLoyaltyPol query ; result expected : 1000, 500, 100",
+            Query::new("get_loyalty_pol", "expected loyalty policy : 1000, 500, 100", "getLoyaltyPol").out("1000").out("500").out("100"),
+        )
+        .step(create_egld_prize())
+        .transaction(
+            "sponsor_actions/sponsorEGLD_creates_prize_6.steps.json",
+            "sponsor EGLD creates prize #6",
+            create_call("create-prize-6", "SponsorEGLD creates prize #6 with 1 EGLD prize", "create", "false").egld_value(EGLD).out("6"),
+        )
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .step(play_paying_fees())
+        .query(
+            "query_getLoyaltyPoints/query_getLoyaltyPoints_[player1]_[100]_[100].steps.json",
+            "getLoyaltyPoints query on player1 ; result expected : 100, 100",
+            Query::new("get_loyalty_points_player1_100_100", "expected points : 100, lifetime 100", "getLoyaltyPoints").arg(&player.address()).out("100").out("100"),
+        )
+        .transaction(
+            "player_actions/player1_plays_to_prize_6_with_points.steps.json",
+            "player1 plays prize #6 with points",
+            Call::new("player1_play_prize_6_points", "Player1 spends 100 points to play prize #6", player, "playWithPoints").arg("6").out("1"),
+        )
+        .transaction(
+            "player_actions/player2_plays_to_prize_6_with_points.steps.json",
+            "player2 plays prize #6 with points",
+            Call::new("player2_play_prize_6_points", "Player2 has no point to play prize #6", Actor::Player(2), "playWithPoints").arg("6").fails_with("str:Not enough loyalty points"),
+        )
+        .forward(PrizeKind::Egld, Moment::End)
+        .step(trigger_new_prize())
+        .query(
+            "query_getLoyaltyPoints/query_getLoyaltyPoints_[player1]_[500]_[600].steps.json",
+            "getLoyaltyPoints query on player1 ; result expected : 500, 600",
+            Query::new("get_loyalty_points_player1_500_600", "expected points : 500, lifetime 600", "getLoyaltyPoints").arg(&player.address()).out("500").out("600"),
+        )
+        .query(
+            "query_getLeaderboardScore/query_getLeaderboardScore_[loyalty_points]_[player1]_[600].steps.json",
+            "getLeaderboardScore query on loyalty points of player1 ; result expected : 600",
+            Query::new("get_leaderboard_score_loyalty_player1", "expected score : 600", "getLeaderboardScore").arg("6").arg(&player.address()).out("600"),
+        );
+}
+
+// Prize funded by the winner share of the fees
+fn pot() -> Scenario {
+    let fee_pool = amount(FEES / 2);
+
+    return Scenario::new("nom_pot", "Pot prize", "Create a prize funded by 50% of the fees, make player1 winning it")
+        .step(set_fees_without_rewards())
+        .step(create_pot_prize())
+        .query(
+            "query_getParamPotWinnerPercent/query_getParamPotWinnerPercent_[50].steps.json",
+            "getParamPotWinnerPercent query ; result expected : 50",
+            Query::new("get_param_pot_winner_percent", "expected winner percent : 50", "getParamPotWinnerPercent").out("50"),
+        )
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .step(play_paying_fees())
+        .query(
+            "query_getFeePool/query_getFeePool_[0.05_EGLD].steps.json",
+            "getFeePool query ; result expected : 0.05 EGLD",
+            Query::new("get_fee_pool_pot", "expected fee pool : 0.05 EGLD", "getFeePool").out(&fee_pool),
+        )
+        .forward(PrizeKind::Egld, Moment::End)
+        .step(trigger_new_prize())
+        .step(claim_new_prize())
+        .check_state(
+            "check balances following pot claim",
+            vec![Check::Balance(Actor::Sponsor(PrizeKind::Egld), 99 * EGLD), Check::Balance(Actor::Player(1), 100 * EGLD - FEES / 2)],
+        );
+}
+
+// Pot already paid to its winner is not paid again by slashing the disabled prize
+fn pot_slash() -> Scenario {
+    return Scenario::new("nom_pot_slash", "Pot prize slash", "Make player1 winning & claiming a pot prize, then disable the prize & try to slash it")
+        .step(set_fees_without_rewards())
+        .step(create_pot_prize())
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .step(play_paying_fees())
+        .forward(PrizeKind::Egld, Moment::End)
+        .step(trigger_new_prize())
+        .step(claim_new_prize())
+        .transaction(
+            "admin_actions/admin_disable_prize_5.steps.json",
+            "disable prize #5",
+            Call::new("disable-prize-5", "admin disables prize #5", Actor::Owner, "disable").arg(NEW_IID).arg("true"),
+        )
+        .transaction(
+            "admin_actions/admin_slashBond_claimed_prize_5.steps.json",
+            "slashBond endpoint on claimed prize #5",
+            Call::new("slash-bond-claimed-prize-5", "admin slashes the claimed prize #5", Actor::Owner, "slashBond").arg(NEW_IID).arg("u8:100").arg("false").fails_with("str:Instance already has a winner"),
+        )
+        .query(
+            "query_getFeePool/query_getFeePool_[0.05_EGLD].steps.json",
+            "getFeePool query ; result expected : 0.05 EGLD",
+            Query::new("get_fee_pool_pot", "expected fee pool : 0.05 EGLD", "getFeePool").out(&amount(FEES / 2)),
+        )
+        .check_state(
+            "check balances following slash of the claimed pot",
+            vec![Check::Balance(Actor::Sponsor(PrizeKind::Egld), 99 * EGLD), Check::Balance(Actor::Player(1), 100 * EGLD - FEES / 2)],
+        );
+}
+
+// Promo code giving a free entry, used once
+fn promo() -> Scenario {
+    let sponsor = Actor::Sponsor(PrizeKind::Egld);
+
+    return Scenario::new("nom_promo", "Promo codes", "Register a promo code giving a free entry to prize #5, player1 plays with it")
+        .step(set_fees_without_rewards())
+        .step(create_egld_prize())
+        .transaction(
+            "sponsor_actions/sponsorEGLD_adds_promo_code.steps.json",
+            "sponsor EGLD adds promo code",
+            Call::new("add-promo-codes", "SponsorEGLD adds the WELCOME promo code to prize #5", sponsor, "addPromoCodes").arg(NEW_IID).arg(&amount(FEES)).arg("keccak256:str:WELCOME"),
+        )
+        .transaction(
+            "player_actions/player2_adds_promo_code.steps.json",
+            "player2 adds promo code",
+            Call::new("add-promo-codes-bad-caller", "Player2 adds a promo code to prize #5", Actor::Player(2), "addPromoCodes").arg(NEW_IID).arg(&amount(FEES)).arg("keccak256:str:WELCOME").fails_with("str:Bad caller"),
+        )
+        .query(
+            "query_getNbPromoCodes/query_getNbPromoCodes_[5]_[1].steps.json",
+            "getNbPromoCodes query on prize #5 ; result expected : 1",
+            Query::new("get_nb_promo_codes_5_1", "expected promo codes : 1", "getNbPromoCodes").arg(NEW_IID).out("1"),
+        )
+        .query(
+            "query_getPromoCodeDiscount/query_getPromoCodeDiscount_[5]_[WELCOME].steps.json",
+            "getPromoCodeDiscount query on prize #5 ; result expected : 0.1 EGLD",
+            Query::new("get_promo_code_discount_5", "expected discount : 0.1 EGLD", "getPromoCodeDiscount").arg(NEW_IID).arg("keccak256:str:WELCOME").out(&amount(FEES)),
+        )
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .transaction(
+            "player_actions/player1_plays_to_prize_5_with_code.steps.json",
+            "player1 plays prize #5 with code",
+            Call::new("player1_play_prize_5_code", "Player1 plays prize #5 for free with the WELCOME promo code", Actor::Player(1), "playWithCode").arg(NEW_IID).arg("str:WELCOME").out("1"),
+        )
+        .transaction(
+            "player_actions/player2_plays_to_prize_5_with_code.steps.json",
+            "player2 plays prize #5 with code",
+            Call::new("player2_play_prize_5_code", "Player2 plays prize #5 with the used WELCOME promo code", Actor::Player(2), "playWithCode").arg(NEW_IID).arg("str:WELCOME").fails_with("str:Invalid promo code"),
+        )
+        .query(
+            "query_getNbPromoCodes/query_getNbPromoCodes_[5]_[0].steps.json",
+            "getNbPromoCodes query on prize #5 ; result expected : 0",
+            Query::new("get_nb_promo_codes_5_0", "expected promo codes : 0", "getNbPromoCodes").arg(NEW_IID).out("0"),
+        )
+        .check_state("check balances following free entry", vec![Check::Balance(Actor::Player(1), 100 * EGLD)]);
+}
+
+// Player3 is referred by player2, itself referred by player1 : both earn link rewards when player3 plays
+fn referral() -> Scenario {
+    return Scenario::new("nom_referral", "Referral", "Give 10% of the fees to the referrer & 5% to the referrer of the referrer")
+        .transaction(
+            "admin_actions/admin_setFeePol_link_rewards.steps.json",
+            "setFeePol endpoint with link rewards",
+            Call::new("setFeePol", "admin sets fees of 0.1 EGLD with link rewards", Actor::Owner, "setFeePol").arg(&amount(FEES)).arg("u8:0").arg("u8:10").arg("u8:5"),
+        )
+        .step(create_egld_prize())
+        .transaction(
+            "player_actions/player2_sets_referrer_player1.steps.json",
+            "player2 sets referrer player1",
+            Call::new("set-referrer-player2", "Player2 is referred by player1", Actor::Player(2), "setReferrer").arg(&Actor::Player(1).address()),
+        )
+        .transaction(
+            "player_actions/player3_sets_referrer_player2.steps.json",
+            "player3 sets referrer player2",
+            Call::new("set-referrer-player3", "Player3 is referred by player2", Actor::Player(3), "setReferrer").arg(&Actor::Player(2).address()),
+        )
+        .query(
+            "query_getReferrer/query_getReferrer_[player3]_[player2].steps.json",
+            "getReferrer query on player3 ; result expected : player2",
+            Query::new("get_referrer_player3", "expected referrer : player2", "getReferrer").arg(&Actor::Player(3).address()).out(&Actor::Player(2).address()),
+        )
+        .transaction(
+            "player_actions/player1_sets_referrer_player3.steps.json",
+            "player1 sets referrer player3",
+            Call::new("set-referrer-player1", "Player1 cannot be referred by its own referral", Actor::Player(1), "setReferrer").arg(&Actor::Player(3).address()).fails_with("str:Referral cycle or too long referral chain"),
+        )
+        .forward(PrizeKind::Egld, Moment::Mid)
+        .transaction(
+            "player_actions/player3_plays_to_prize_5_paying_fees.steps.json",
+            "player3 plays prize #5 paying fees",
+            Call::new("player3_play_prize_5", "Player3 pays 0.1 EGLD fees to play prize #5", Actor::Player(3), "play").egld_value(FEES).arg(NEW_IID).out("1"),
+        )
+        .query(
+            "query_getLinkRewardPool/query_getLinkRewardPool_[player2]_[0.01_EGLD].steps.json",
+            "getLinkRewardPool query on player2 ; result expected : 0.01 EGLD",
+            Query::new("get_link_reward_pool_player2", "expected link rewards : 0.01 EGLD", "getLinkRewardPool").arg(&Actor::Player(2).address()).out(&amount(FEES / 10)),
+        )
+        .query(
+            "query_getLinkRewardPool/query_getLinkRewardPool_[player1]_[0.005_EGLD].steps.json",
+            "getLinkRewardPool query on player1 ; result expected : 0.005 EGLD",
+            Query::new("get_link_reward_pool_player1", "expected link rewards : 0.005 EGLD", "getLinkRewardPool").arg(&Actor::Player(1).address()).out(&amount(FEES / 20)),
+        )
+        .transaction(
+            "player_actions/player2_claims_link_rewards.steps.json",
+            "player2 claims link rewards",
+            Call::new("claim-link-rewards", "Player2 claims its link rewards", Actor::Player(2), "claimLinkRewards"),
+        )
+        .check_state(
+            "check balances following link rewards claim",
+            vec![Check::Balance(Actor::Player(2), 100 * EGLD + FEES / 10), Check::Balance(Actor::Player(3), 100 * EGLD - FEES)],
+        );
+}
@@ -0,0 +1,289 @@
+use crate::json::amount;
+use serde_json::{json, Map, Value};
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+pub const EGLD: u128 = 1_000_000_000_000_000_000;
+pub const GAS_LIMIT: u64 = 5_000_000_000;
+pub const CONTRACT: &str = "sc:prize";
+pub const OWNER_ONLY_MESSAGE: &str = "str:Endpoint can only be called by owner";
+pub const NO_FEES_MESSAGE: &str = "str:No fees to claim";
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// One prize of each kind is created by the initial setup, in this order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrizeKind {
+    Egld,
+    Esdt,
+    Nft,
+    Sft,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Actor {
+    Owner,
+    // Sponsor of the prize of the given kind
+    Sponsor(PrizeKind),
+    // 1 to 5
+    Player(u8),
+}
+
+// Properties shared by all the instances of a NFT / SFT collection
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenMetadata {
+    pub creator: &'static str,
+    pub royalties: u32,
+    pub uri: &'static str,
+    pub attributes: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Holding {
+    Fungible { token_identifier: &'static str, amount: u128 },
+    // Nonce & balance of each instance
+    NonFungible { token_identifier: &'static str, metadata: TokenMetadata, instances: Vec<(u64, u128)> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Account {
+    pub actor: Actor,
+    pub balance: u128,
+    pub holdings: Vec<Holding>,
+}
+
+// Prize escrowed by the sponsor at creation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrizeDefinition {
+    pub kind: PrizeKind,
+    pub duration_in_s: u64,
+    pub token_identifier: &'static str,
+    pub nonce: u64,
+    pub amount: u128,
+    // Names & comment of the trigger & sponsor claim steps files, as written in the scenarios
+    pub trigger_name: &'static str,
+    pub trigger_comment: &'static str,
+    pub sponsor_claim_name: &'static str,
+}
+
+// Accounts & prizes of the initial setup, shared by all the scenarios
+#[derive(Debug, Clone, PartialEq)]
+pub struct World {
+    pub accounts: Vec<Account>,
+    pub prizes: Vec<PrizeDefinition>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+impl PrizeKind {
+    pub fn all() -> [PrizeKind; 4] {
+        return [PrizeKind::Egld, PrizeKind::Esdt, PrizeKind::Nft, PrizeKind::Sft];
+    }
+
+    // Used in file names & step names
+    pub fn name(&self) -> &'static str {
+        match self {
+            PrizeKind::Egld => return "egld",
+            PrizeKind::Esdt => return "esdt",
+            PrizeKind::Nft => return "nft",
+            PrizeKind::Sft => return "sft",
+        }
+    }
+
+    // Used in comments & sponsors names
+    pub fn label(&self) -> &'static str {
+        match self {
+            PrizeKind::Egld => return "EGLD",
+            PrizeKind::Esdt => return "ESDT",
+            PrizeKind::Nft => return "NFT",
+            PrizeKind::Sft => return "SFT",
+        }
+    }
+
+    // Instance ID, following the creation order of the initial setup
+    pub fn iid(&self) -> u32 {
+        return PrizeKind::all().iter().position(|kind| kind == self).unwrap() as u32 + 1;
+    }
+}
+
+impl Actor {
+    // Returns : name of the account, e.g. player1 or sponsorEGLD
+    pub fn name(&self) -> String {
+        match self {
+            Actor::Owner => return String::from("owner"),
+            Actor::Sponsor(kind) => return format!("sponsor{}", kind.label()),
+            Actor::Player(number) => return format!("player{}", number),
+        }
+    }
+
+    // Returns : name used in step names, e.g. player1 or sponsor EGLD
+    pub fn label(&self) -> String {
+        match self {
+            Actor::Sponsor(kind) => return format!("sponsor {}", kind.label()),
+            _ => return self.name(),
+        }
+    }
+
+    // Returns : name starting with an uppercase letter, for comments
+    pub fn title(&self) -> String {
+        let name = self.name();
+        return format!("{}{}", name[..1].to_uppercase(), &name[1..]);
+    }
+
+    pub fn address(&self) -> String {
+        return format!("address:{}", self.name());
+    }
+}
+
+impl Holding {
+    pub fn token_identifier(&self) -> &'static str {
+        match self {
+            Holding::Fungible { token_identifier, .. } => return *token_identifier,
+            Holding::NonFungible { token_identifier, .. } => return *token_identifier,
+        }
+    }
+
+    // Returns : mandos value of the holding
+    pub fn to_json(&self) -> Value {
+        match self {
+            Holding::Fungible { amount: token_amount, .. } => return json!(amount(*token_amount)),
+            Holding::NonFungible { metadata, instances, .. } => {
+                let instances: Vec<Value> = instances
+                    .iter()
+                    .map(|(nonce, balance)| {
+                        json!({
+                            "nonce": nonce.to_string(),
+                            "balance": amount(*balance),
+                            "creator": metadata.creator,
+                            "royalties": metadata.royalties.to_string(),
+                            "uri": [metadata.uri],
+                            "attributes": metadata.attributes
+                        })
+                    })
+                    .collect();
+                return json!({ "instances": instances });
+            },
+        }
+    }
+}
+
+impl Account {
+    pub fn holdings_to_json(&self) -> Value {
+        let mut esdt = Map::new();
+        for holding in self.holdings.iter() {
+            esdt.insert(format!("str:{}", holding.token_identifier()), holding.to_json());
+        }
+        return Value::Object(esdt);
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        return World::new();
+    }
+}
+
+impl World {
+    // Owner, one sponsor per prize kind & 5 players, 100 EGLD each
+    pub fn new() -> Self {
+        let nft_metadata = TokenMetadata { creator: "sc:nft-minter", royalties: 5000, uri: "str:www.mycoolnft.com/nft1.jpg", attributes: "u64:100,000" };
+        let sft_metadata = TokenMetadata { creator: "sc:sft-minter", royalties: 0, uri: "", attributes: "u64:100,000" };
+
+        let mut accounts: Vec<Account> = vec![
+            Account { actor: Actor::Owner, balance: 100 * EGLD, holdings: Vec::new() },
+            Account { actor: Actor::Sponsor(PrizeKind::Egld), balance: 100 * EGLD, holdings: Vec::new() },
+            Account {
+                actor: Actor::Sponsor(PrizeKind::Esdt),
+                balance: 100 * EGLD,
+                holdings: vec![
+                    Holding::Fungible { token_identifier: "MEX-abcdef", amount: 5_000_000_000 },
+                    Holding::Fungible { token_identifier: "RIDE-abcdef", amount: 5_000_000_000 },
+                ],
+            },
+            Account {
+                actor: Actor::Sponsor(PrizeKind::Nft),
+                balance: 100 * EGLD,
+                holdings: vec![Holding::NonFungible { token_identifier: "NFT-123456", metadata: nft_metadata, instances: vec![(1, 1)] }],
+            },
+            Account {
+                actor: Actor::Sponsor(PrizeKind::Sft),
+                balance: 100 * EGLD,
+                holdings: vec![Holding::NonFungible { token_identifier: "SFT-123456", metadata: sft_metadata, instances: vec![(1, 10), (2, 10)] }],
+            },
+        ];
+        for number in 1..=5 {
+            accounts.push(Account { actor: Actor::Player(number), balance: 100 * EGLD, holdings: Vec::new() });
+        }
+
+        let prizes: Vec<PrizeDefinition> = vec![
+            PrizeDefinition {
+                kind: PrizeKind::Egld,
+                duration_in_s: 60,
+                token_identifier: "EGLD",
+                nonce: 0,
+                amount: EGLD,
+                trigger_name: "trigger egld instance ",
+                trigger_comment: "admin triggers egld instance",
+                sponsor_claim_name: "sponsor EGLD claims egld prize",
+            },
+            PrizeDefinition {
+                kind: PrizeKind::Esdt,
+                duration_in_s: 180,
+                token_identifier: "MEX-abcdef",
+                nonce: 0,
+                amount: 1_000,
+                trigger_name: "trigger esdt instance ",
+                trigger_comment: "admin triggers esdt instance",
+                sponsor_claim_name: "sponsor ESDT claims egld prize",
+            },
+            PrizeDefinition {
+                kind: PrizeKind::Nft,
+                duration_in_s: 3600,
+                token_identifier: "NFT-123456",
+                nonce: 1,
+                amount: 1,
+                trigger_name: "trigger nft instance ",
+                trigger_comment: "admin triggers nft instance",
+                sponsor_claim_name: "sponsor NFT claims nft prize",
+            },
+            PrizeDefinition {
+                kind: PrizeKind::Sft,
+                duration_in_s: 604800,
+                token_identifier: "SFT-123456",
+                nonce: 2,
+                amount: 5,
+                trigger_name: "trigger nft instance ",
+                trigger_comment: "admin triggers nft instance",
+                sponsor_claim_name: "sponsor SFT claims sft prize",
+            },
+        ];
+
+        return World { accounts: accounts, prizes: prizes };
+    }
+
+    pub fn account(&self, actor: Actor) -> &Account {
+        return self.accounts.iter().find(|account| account.actor == actor).unwrap();
+    }
+
+    pub fn prize(&self, kind: PrizeKind) -> &PrizeDefinition {
+        return self.prizes.iter().find(|prize| prize.kind == kind).unwrap();
+    }
+
+    // Returns : NFT / SFT properties of a collection held at setup
+    pub fn token_metadata(&self, token_identifier: &str) -> Option<&TokenMetadata> {
+        for account in self.accounts.iter() {
+            for holding in account.holdings.iter() {
+                if let Holding::NonFungible { token_identifier: identifier, metadata, .. } = holding {
+                    if *identifier == token_identifier {
+                        return Some(metadata);
+                    }
+                }
+            }
+        }
+        return None;
+    }
+}
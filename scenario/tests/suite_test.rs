@@ -0,0 +1,121 @@
+use prize_scenario::*;
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn mandos_directory() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../mandos");
+}
+
+// Returns : JSON files of a directory, by path relative to the mandos directory
+fn json_files(directory: &Path, relative_directory: &str, files: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap().to_string();
+        let relative_path = if relative_directory.is_empty() { name.clone() } else { format!("{}/{}", relative_directory, name) };
+
+        if path.is_dir() {
+            json_files(&path, &relative_path, files);
+        } else if name.ends_with(".json") {
+            files.insert(relative_path);
+        }
+    }
+}
+
+#[test]
+fn mandos_directory_is_generated() {
+    let generated_files = prize_suite().files();
+
+    // Regenerate with : cargo run -p prize-scenario
+    for (path, content) in generated_files.iter() {
+        let written_content = fs::read_to_string(mandos_directory().join(path)).unwrap_or_default();
+        assert!(&written_content == content, "{} is outdated", path);
+    }
+
+    let mut written_files: BTreeSet<String> = BTreeSet::new();
+    json_files(&mandos_directory(), "", &mut written_files);
+    for path in written_files.iter() {
+        assert!(generated_files.contains_key(path), "{} is not generated", path);
+    }
+}
+
+#[test]
+fn keep_hand_written_values() {
+    let documents = prize_suite().documents();
+
+    assert!(documents.contains_key("steps/admin_actions/admin_set_param_duration_bad_caller.steps.json"));
+    assert_eq!(documents["steps/query_getStatus/query_getStatus_[2]_[ended].steps.json"]["steps"][0]["expect"]["out"], json!(["3"]));
+    assert_eq!(documents["steps/admin_actions/admin_trigger_egld_prize.steps.json"]["name"], json!("trigger egld instance "));
+    assert_eq!(documents["steps/deploy_contract.steps.json"]["steps"][0]["expect"]["status"], json!(""));
+    assert_eq!(documents["steps/admin_actions/admin_trigger_sft_prize.steps.json"]["name"], json!("trigger nft instance "));
+    assert_eq!(documents["steps/admin_actions/admin_trigger_sft_prize.steps.json"]["steps"][0]["comment"], json!("admin triggers nft instance"));
+    assert_eq!(documents["steps/sponsor_actions/sponsorESDT_claims_esdt_prize.steps.json"]["name"], json!("sponsor ESDT claims egld prize"));
+    assert_eq!(documents["steps/query_getStatus/query_getStatus_[1]_[claimed].steps.json"]["steps"][0]["expect"]["status"], json!(""));
+    assert_eq!(documents["steps/query_getStatus/query_getStatus_[1]_[not_existing].steps.json"]["steps"][0]["expect"]["status"], json!("0"));
+    assert_eq!(documents["nom_prize_esdt.scen.json"]["steps"][6]["accounts"]["address:player1"]["esdt"], json!({ "str:MEX-abcdef": "1000" }));
+}
+
+#[test]
+fn write_canonical_layout() {
+    let value = json!({ "name": "new", "arguments": ["1", "2"], "out": [], "tx": {} });
+
+    // Tabs, empty arrays on two lines & empty objects on one line
+    assert_eq!(to_pretty_string(&value), "{\n\t\"name\": \"new\",\n\t\"arguments\": [\n\t\t\"1\",\n\t\t\"2\"\n\t],\n\t\"out\": [\n\t],\n\t\"tx\": {}\n}");
+}
+
+#[test]
+fn generate_play_step() {
+    let world = World::new();
+
+    match (Step::Play { player: 3, prize: PrizeKind::Nft }).generate(&world) {
+        GeneratedStep::External(steps_file) => {
+            assert_eq!(steps_file.path, "player_actions/player3_plays_to_nft_prize.steps.json");
+            assert_eq!(steps_file.steps[0]["tx"]["from"], json!("address:player3"));
+            assert_eq!(steps_file.steps[0]["tx"]["arguments"], json!(["3"]));
+            assert_eq!(steps_file.steps[0]["expect"]["status"], json!("0"));
+        },
+        GeneratedStep::Inline(_) => panic!("Play steps are shared"),
+    }
+}
+
+#[test]
+fn generate_time_jumps() {
+    let world = World::new();
+    let duration_in_s = world.prize(PrizeKind::Egld).duration_in_s;
+
+    assert_eq!(Moment::Mid.timestamp(duration_in_s), 30);
+    assert_eq!(Moment::BeforeEnd.timestamp(duration_in_s), 59);
+    assert_eq!(Moment::End.timestamp(duration_in_s), 61);
+    assert_eq!(amount(100 * EGLD), "100,000,000,000,000,000,000");
+    assert_eq!(amount(999), "999");
+}
+
+#[test]
+fn generate_new_scenario() {
+    let suite = Suite {
+        world: World::new(),
+        scenarios: vec![Scenario::new("nom_prize_esdt_player2", "Prize ESDT player2", "Make player2 winning the ESDT prize")
+            .forward(PrizeKind::Esdt, Moment::Mid)
+            .plays(&[2], PrizeKind::Esdt)
+            .forward(PrizeKind::Esdt, Moment::End)
+            .trigger(PrizeKind::Esdt)
+            .expect_winner(2, PrizeKind::Esdt)
+            .claim(Actor::Player(2), PrizeKind::Esdt)
+            .expect_status(2, InstanceStatus::Claimed)
+            .check_state("check balances following prize claim", vec![Check::Esdt(Actor::Player(2), "MEX-abcdef", 1_000)])],
+    };
+    let files = suite.files();
+
+    // Existing steps files are reused, missing ones are created
+    assert!(files.contains_key("steps/initial_setup.steps.json"));
+    assert!(files.contains_key("steps/player_actions/player2_claims_esdt_prize.steps.json"));
+    assert!(files.contains_key("steps/query_hasWon/player2_hasWon_esdt_prize_true.steps.json"));
+
+    let scenario: serde_json::Value = serde_json::from_str(&files["nom_prize_esdt_player2.scen.json"]).unwrap();
+    let steps = scenario["steps"].as_array().unwrap();
+    assert_eq!(steps.len(), 9);
+    assert_eq!(steps[0]["path"], json!("steps/initial_setup.steps.json"));
+    assert_eq!(steps[8]["accounts"]["address:player2"]["esdt"], json!({ "str:MEX-abcdef": "1,000" }));
+    assert_eq!(steps[8]["accounts"]["address:sponsorESDT"]["esdt"], json!("*"));
+}
@@ -0,0 +1,108 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use super::Ok_some;
+use super::require_with_opt;
+use super::instance;
+use super::instance::PrizeInfo;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Compact record of a purged instance
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct ArchiveInfo<M: ManagedTypeApi> {
+    pub iid: u32,
+    pub sponsor_address: ManagedAddress<M>,
+    pub prize_info: PrizeInfo<M>,
+    pub winner_address: ManagedAddress<M>,
+    pub ticket_number: usize,
+    pub number_of_players: usize,
+    pub trigger_timestamp: u64,
+    pub charity: bool,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait ArchiveModule:
+    instance::InstanceModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getArchive)]
+    fn get_archive(&self, iid: u32) -> MultiValue2<SCResult<()>, OptionalValue<ArchiveInfo<Self::Api>>> {
+        require_with_opt!(self.archive_mapper().contains_key(&iid), "Instance not archived");
+
+        Ok_some!(self.archive_mapper().get(&iid).unwrap());
+    }
+
+    #[view(getSponsorArchive)]
+    // Returns : next cursor (0 when all records have been returned), followed by up to <limit> archive records of the sponsor from <cursor>, in cleaning order
+    fn get_sponsor_archive(&self, sponsor_address: ManagedAddress, cursor: u32, limit: u32) -> MultiValue2<u32, MultiValueManagedVec<ArchiveInfo<Self::Api>>> {
+        require!(limit > 0, "Limit must be positive");
+
+        let mut archived_iids = MultiValueManagedVec::new();
+
+        // Archive index of the sponsor is walked from the cursor, until the page is full
+        let next_cursor: u32 = self.fill_page_from_index(&self.sponsor_archive_iids_mapper(&sponsor_address), cursor, limit, None, &mut archived_iids);
+
+        return MultiValue2((next_cursor, self.get_archive_records(&archived_iids)));
+    }
+
+    #[view(getWinnerArchive)]
+    // Returns : next cursor (0 when all records have been returned), followed by up to <limit> archive records won by the address from <cursor>, in cleaning order
+    fn get_winner_archive(&self, winner_address: ManagedAddress, cursor: u32, limit: u32) -> MultiValue2<u32, MultiValueManagedVec<ArchiveInfo<Self::Api>>> {
+        require!(limit > 0, "Limit must be positive");
+
+        let mut archived_iids = MultiValueManagedVec::new();
+
+        // Archive index of the winner is walked from the cursor, until the page is full
+        let next_cursor: u32 = self.fill_page_from_index(&self.winner_archive_iids_mapper(&winner_address), cursor, limit, None, &mut archived_iids);
+
+        return MultiValue2((next_cursor, self.get_archive_records(&archived_iids)));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+    // Instances are archived once, archive indexes are only appended
+    fn record_archive(&self, archive_info: ArchiveInfo<Self::Api>) {
+        self.sponsor_archive_iids_mapper(&archive_info.sponsor_address).push_back(archive_info.iid);
+        self.winner_archive_iids_mapper(&archive_info.winner_address).push_back(archive_info.iid);
+        self.archive_mapper().insert(archive_info.iid, archive_info);
+    }
+
+    fn get_archive_records(&self, iids: &MultiValueManagedVec<u32>) -> MultiValueManagedVec<ArchiveInfo<Self::Api>> {
+        let mut records: MultiValueManagedVec<ArchiveInfo<Self::Api>> = MultiValueManagedVec::new();
+
+        for iid in iids.iter() {
+            records.push(self.archive_mapper().get(&iid).unwrap());
+        }
+
+        return records;
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Archive records of purged instances
+    #[storage_mapper("archive")]
+    fn archive_mapper(&self) -> MapMapper<u32, ArchiveInfo<Self::Api>>;
+
+    // Archived instances IDs (per sponsor)
+    #[storage_mapper("sponsor_archive_iids")]
+    fn sponsor_archive_iids_mapper(&self, sponsor_address: &ManagedAddress) -> LinkedListMapper<u32>;
+
+    // Archived instances IDs (per winner)
+    #[storage_mapper("winner_archive_iids")]
+    fn winner_archive_iids_mapper(&self, winner_address: &ManagedAddress) -> LinkedListMapper<u32>;
+}
\ No newline at end of file
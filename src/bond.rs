@@ -0,0 +1,252 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use super::instance;
+use super::instance::InstanceStatus;
+use super::sponsor;
+use super::fee;
+use super::charity;
+use super::event;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Bond to be locked by sponsors before creating instances
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct BondInfo<M: ManagedTypeApi> {
+    pub token_identifier: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait BondModule:
+    instance::InstanceModule
+    +sponsor::SponsorModule
+    +fee::FeeModule
+    +charity::CharityModule
+    +event::EventModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+    #[only_owner]
+    #[endpoint(setBondPol)]
+    fn set_bond_policy(&self, token_identifier: TokenIdentifier, amount: BigUint) -> SCResult<()> {
+        require!(token_identifier.is_valid_esdt_identifier() || token_identifier.is_egld(), "Invalid bond token");
+
+        self.bond_policy_mapper().set(&BondInfo {
+            token_identifier: token_identifier.clone(),
+            amount: amount.clone(),
+        });
+
+        // Log event
+        self.event_wrapper_set_bond_policy(&token_identifier, &amount);
+
+        Ok(())
+    }
+
+    #[payable("*")]
+    #[endpoint(depositBond)]
+    fn deposit_bond(&self, #[payment_token] token_identifier: TokenIdentifier, #[payment_nonce] token_nonce: u64, #[payment_amount] token_amount: BigUint) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+
+        // Checks
+        require!(token_nonce == 0, "Bond must be a fungible token");
+        require!(token_identifier == self.bond_policy_mapper().get().token_identifier, "Wrong bond token");
+        require!(token_amount > 0, "Bond cannot be null");
+
+        let mut bond = self.get_sponsor_bond_info(&caller);
+        require!(bond.amount == BigUint::zero() || bond.token_identifier == token_identifier, "Previous bond must be withdrawn first");
+
+        // Lock bond
+        bond.token_identifier = token_identifier;
+        bond.amount += token_amount;
+        self.sponsor_bond_mapper(&caller).set(&bond);
+
+        // Log event
+        self.event_wrapper_deposit_bond(&caller, &bond.token_identifier, &bond.amount);
+
+        Ok(())
+    }
+
+    #[endpoint(withdrawBond)]
+    fn withdraw_bond(&self) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+        let bond = self.get_sponsor_bond_info(&caller);
+
+        // Checks
+        require!(bond.amount != BigUint::zero(), "No bond to withdraw");
+        require!(self.nb_instances_running_mapper(caller.clone()).get() == 0, "Sponsor has running instances");
+        require!(self.sponsor_nb_bond_locks_mapper(&caller).get() == 0, "Sponsor has unclaimed or disabled instances");
+
+        // Release bond
+        self.sponsor_bond_mapper(&caller).clear();
+        self.send().direct(&caller, &bond.token_identifier, 0, &bond.amount, b"Sponsor bond released");
+
+        // Log event
+        self.event_wrapper_withdraw_bond(&caller, &bond.token_identifier, &bond.amount);
+
+        Ok(())
+    }
+
+    #[only_owner]
+    #[endpoint(slashBond)]
+    // Slash the bond of the sponsor of a disabled instance, to the fee pool or to the charity pool
+    // Ticket pot of the instance follows the slashed bond, and the bond locked by the instance is released
+    // Instances disabled after their trigger cannot be slashed, their prize belongs to the winner
+    fn slash_bond(&self, iid: u32, slash_percent: u8, to_charity: bool) -> SCResult<()> {
+        require!(self.get_instance_status(iid) == InstanceStatus::Disabled, "Instance is not disabled");
+        require!(slash_percent > 0 && slash_percent <= 100, "Wrong value for slash percent");
+        require!(self.instance_bond_slashed_mapper(iid).get() == false, "Bond already slashed for this instance");
+        require!(self.instance_state_mapper().get(&iid).unwrap().winner_info.address.is_zero() == true, "Instance already has a winner");
+
+        let sponsor_address = self.instance_info_mapper().get(&iid).unwrap().sponsor_info.address;
+        let mut bond = self.get_sponsor_bond_info(&sponsor_address);
+        let pot: BigUint = self.instance_pot_mapper(iid).get();
+        require!(bond.amount != BigUint::zero() || pot != BigUint::zero(), "Nothing to slash");
+
+        // Compute slashed amount
+        let slashed_amount: BigUint = bond.amount.clone() * BigUint::from(slash_percent) / BigUint::from(100u8);
+        bond.amount -= slashed_amount.clone();
+        self.sponsor_bond_mapper(&sponsor_address).set(&bond);
+        self.instance_bond_slashed_mapper(iid).set(&true);
+
+        if slashed_amount != BigUint::zero() {
+            if bond.token_identifier.is_egld() {
+                self.add_slashed_egld(iid, &slashed_amount, to_charity);
+            }
+            else {
+                // Pools only hold EGLD, slashed ESDT is sent to the registered charity or to the SC owner
+                let charity_id: u32 = self.instance_charity_id_mapper(iid).get();
+                let destination_address = if to_charity == true && charity_id != 0 {self.charity_info_mapper().get(&charity_id).unwrap().address} else {self.blockchain().get_owner_address()};
+
+                self.send().direct(&destination_address, &bond.token_identifier, 0, &slashed_amount, b"Sponsor bond slashed");
+            }
+        }
+
+        // Ticket pot of a disabled instance cannot be won, players' fees are not left stranded in the contract
+        if pot != BigUint::zero() {
+            self.instance_pot_mapper(iid).clear();
+            self.add_slashed_egld(iid, &pot, to_charity);
+
+            // Log event
+            self.event_wrapper_pot_info(iid, &BigUint::zero());
+        }
+
+        self.release_instance_bond(iid);
+
+        // Log event
+        self.event_wrapper_slash_bond(iid, &sponsor_address, &bond.token_identifier, &slashed_amount, to_charity);
+
+        Ok(())
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getBondPol)]
+    fn get_bond_policy(&self) -> MultiValue2<TokenIdentifier, BigUint> {
+        let bond_policy = self.bond_policy_mapper().get();
+
+        return MultiValue2((bond_policy.token_identifier, bond_policy.amount));
+    }
+
+    #[view(getSponsorBond)]
+    fn get_sponsor_bond(&self, sponsor_address: ManagedAddress) -> MultiValue2<TokenIdentifier, BigUint> {
+        let bond = self.get_sponsor_bond_info(&sponsor_address);
+
+        return MultiValue2((bond.token_identifier, bond.amount));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+    fn init_bond_policy_if_empty(&self, token_identifier: TokenIdentifier, amount: BigUint) {
+        self.bond_policy_mapper().set_if_empty(&BondInfo {
+            token_identifier: token_identifier,
+            amount: amount,
+        });
+    }
+
+    fn get_sponsor_bond_info(&self, sponsor_address: &ManagedAddress) -> BondInfo<Self::Api> {
+        if self.sponsor_bond_mapper(sponsor_address).is_empty() {
+            return BondInfo {
+                token_identifier: self.bond_policy_mapper().get().token_identifier,
+                amount: BigUint::zero(),
+            };
+        }
+
+        return self.sponsor_bond_mapper(sponsor_address).get();
+    }
+
+    // Sponsor bond stays locked until the instance prize is claimed or the bond is slashed for this instance
+    fn lock_instance_bond(&self, iid: u32, sponsor_address: &ManagedAddress) {
+        if self.instance_bond_locked_mapper(iid).get() == false {
+            self.instance_bond_locked_mapper(iid).set(&true);
+            self.sponsor_nb_bond_locks_mapper(sponsor_address).update(|current| *current += 1);
+        }
+    }
+
+    fn release_instance_bond(&self, iid: u32) {
+        if self.instance_bond_locked_mapper(iid).get() == true {
+            let sponsor_address = self.instance_info_mapper().get(&iid).unwrap().sponsor_info.address;
+
+            self.instance_bond_locked_mapper(iid).clear();
+            self.sponsor_nb_bond_locks_mapper(&sponsor_address).update(|current| *current -= 1);
+        }
+    }
+
+    // Give slashed EGLD to the instance charity, or add it to the fee pool
+    fn add_slashed_egld(&self, iid: u32, amount: &BigUint, to_charity: bool) {
+        if to_charity == true {
+            self.add_donation(iid, amount);
+        }
+        else {
+            self.fee_pool_mapper().update(|current_fees| *current_fees += amount);
+
+            // Log event
+            self.event_wrapper_fee_pool_info(&self.fee_pool_mapper().get());
+        }
+    }
+
+    // Check sponsor has locked the bond required by the current bond policy
+    fn has_required_bond(&self, sponsor_address: &ManagedAddress) -> bool {
+        let bond_policy = self.bond_policy_mapper().get();
+
+        if bond_policy.amount == BigUint::zero() {
+            return true;
+        }
+
+        let bond = self.get_sponsor_bond_info(sponsor_address);
+
+        return bond.token_identifier == bond_policy.token_identifier && bond.amount >= bond_policy.amount;
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Bond required to create instances
+    #[storage_mapper("bond_policy")]
+    fn bond_policy_mapper(&self) -> SingleValueMapper<BondInfo<Self::Api>>;
+
+    // Bond locked by a sponsor
+    #[storage_mapper("sponsor_bond")]
+    fn sponsor_bond_mapper(&self, sponsor_address: &ManagedAddress) -> SingleValueMapper<BondInfo<Self::Api>>;
+
+    // Slashing status (per instance)
+    #[storage_mapper("instance_bond_slashed")]
+    fn instance_bond_slashed_mapper(&self, iid: u32) -> SingleValueMapper<bool>;
+
+    // Bond locking status (per instance)
+    #[storage_mapper("instance_bond_locked")]
+    fn instance_bond_locked_mapper(&self, iid: u32) -> SingleValueMapper<bool>;
+
+    // Number of instances locking the bond of a sponsor
+    #[storage_mapper("sponsor_nb_bond_locks")]
+    fn sponsor_nb_bond_locks_mapper(&self, sponsor_address: &ManagedAddress) -> SingleValueMapper<u32>;
+}
\ No newline at end of file
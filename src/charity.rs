@@ -1,30 +1,114 @@
 elrond_wasm::imports!();
 elrond_wasm::derive_imports!();
 
+use super::Ok_some;
+use super::require_with_opt;
+use super::stats;
 use super::event;
 
 /////////////////////////////////////////////////////////////////////
 // Types
 /////////////////////////////////////////////////////////////////////
 
+// Charity registered by the SC owner
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct CharityInfo<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub name: ManagedBuffer<M>,
+    pub url: ManagedBuffer<M>,
+    pub enabled: bool,
+}
+
+// Data format for charity view
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct GetCharityStruct<M: ManagedTypeApi> {
+    pub charity_id: u32,
+    pub charity_info: CharityInfo<M>,
+    pub balance: BigUint<M>,
+    pub lifetime_donations: BigUint<M>,
+}
 
 /////////////////////////////////////////////////////////////////////
 // Functions
 /////////////////////////////////////////////////////////////////////
 #[elrond_wasm::module]
 pub trait CharityModule:
-    event::EventModule {
+    stats::StatsModule
+    +event::EventModule {
 
     /////////////////////////////////////////////////////////////////////
     // Endpoints
     /////////////////////////////////////////////////////////////////////
     #[only_owner]
+    #[endpoint(addCharity)]
+    fn add_charity(&self, address: ManagedAddress, name: ManagedBuffer, url: ManagedBuffer) -> MultiValue2<SCResult<()>, OptionalValue<u32>> {
+        require_with_opt!(address.is_zero() == false, "Invalid charity address");
+
+        let charity_id = self.charity_id_counter_mapper().get() + 1;
+
+        // Record new charity
+        self.charity_id_counter_mapper().set(&charity_id);
+        self.charity_info_mapper().insert(charity_id, CharityInfo {
+            address: address.clone(),
+            name: name.clone(),
+            url: url,
+            enabled: true,
+        });
+
+        // Log event
+        self.event_wrapper_set_charity(charity_id, &address, &name, true);
+
+        Ok_some!(charity_id);
+    }
+
+    #[only_owner]
+    #[endpoint(updateCharity)]
+    fn update_charity(&self, charity_id: u32, address: ManagedAddress, name: ManagedBuffer, url: ManagedBuffer, enabled: bool) -> SCResult<()> {
+        require!(self.charity_info_mapper().contains_key(&charity_id), "Charity does not exist");
+        require!(address.is_zero() == false, "Invalid charity address");
+
+        // Update charity information, balance is kept and becomes claimable by the new address
+        self.charity_info_mapper().insert(charity_id, CharityInfo {
+            address: address.clone(),
+            name: name.clone(),
+            url: url,
+            enabled: enabled,
+        });
+
+        // Log event
+        self.event_wrapper_set_charity(charity_id, &address, &name, enabled);
+
+        Ok(())
+    }
+
+    #[endpoint(claimCharityDonations)]
+    fn claim_charity_donations(&self, charity_id: u32) -> SCResult<()> {
+        require!(self.charity_info_mapper().contains_key(&charity_id), "Charity does not exist");
+
+        let charity_address = self.charity_info_mapper().get(&charity_id).unwrap().address;
+        require!(self.blockchain().get_caller() == charity_address, "Donations can only be claimed by the charity");
+
+        let donations_amount: BigUint = self.charity_balance_mapper(charity_id).get();
+        require!(donations_amount != BigUint::zero(), "No donation to claim");
+
+        // Claim donations and clear the charity balance
+        self.send().direct_egld(&charity_address, &donations_amount, b"Donations claimed");
+        self.charity_balance_mapper(charity_id).clear();
+
+        // Log event
+        self.event_wrapper_claim_charity_donations(charity_id, &donations_amount);
+
+        Ok(())
+    }
+
+    // Donations recorded for instances created without a registered charity
+    #[only_owner]
     #[endpoint(claimDonations)]
     fn claim_donations(&self) -> SCResult<()> {
         require!(self.charity_pool_mapper().get() != BigUint::zero(), "No donation to claim");
 
         let donations_amount: BigUint = self.charity_pool_mapper().get();
-        
+
         // Claim donations and clear the pool
         self.send().direct_egld(&self.blockchain().get_owner_address(), &donations_amount, b"Donations from pool claimed");
         self.charity_pool_mapper().clear();
@@ -34,15 +118,50 @@ pub trait CharityModule:
 
         Ok(())
     }
-    
+
     /////////////////////////////////////////////////////////////////////
     // Queries
-    /////////////////////////////////////////////////////////////////////    
+    /////////////////////////////////////////////////////////////////////
     #[view(getCharityPool)]
     fn get_charity_pool(&self) -> BigUint {
-               
+
         // Get the current amount of donations in the pool
-        return self.charity_pool_mapper().get(); 
+        return self.charity_pool_mapper().get();
+    }
+
+    #[view(getCharity)]
+    fn get_charity(&self, charity_id: u32) -> MultiValue2<SCResult<()>, OptionalValue<GetCharityStruct<Self::Api>>> {
+        require_with_opt!(self.charity_info_mapper().contains_key(&charity_id), "Charity does not exist");
+
+        Ok_some!(GetCharityStruct {
+            charity_id: charity_id,
+            charity_info: self.charity_info_mapper().get(&charity_id).unwrap(),
+            balance: self.charity_balance_mapper(charity_id).get(),
+            lifetime_donations: self.charity_donations_mapper(charity_id).get(),
+        });
+    }
+
+    #[view(getCharities)]
+    fn get_charities(&self) -> MultiValueManagedVec<GetCharityStruct<Self::Api>> {
+        let mut charities: MultiValueManagedVec<GetCharityStruct<Self::Api>> = MultiValueManagedVec::new();
+
+        for (charity_id, charity_info) in self.charity_info_mapper().iter() {
+            charities.push(GetCharityStruct {
+                charity_id: charity_id,
+                charity_info: charity_info,
+                balance: self.charity_balance_mapper(charity_id).get(),
+                lifetime_donations: self.charity_donations_mapper(charity_id).get(),
+            });
+        }
+
+        return charities;
+    }
+
+    #[view(getInstanceDonations)]
+    fn get_instance_donations(&self, iid: u32) -> BigUint {
+
+        // Lifetime donations of the instance, kept after instance cleaning
+        return self.instance_donations_mapper(iid).get();
     }
 
     /////////////////////////////////////////////////////////////////////
@@ -50,13 +169,69 @@ pub trait CharityModule:
     /////////////////////////////////////////////////////////////////////
     fn init_donations_if_empty(&self) {
         self.charity_pool_mapper().set_if_empty(&BigUint::zero());
+        self.charity_id_counter_mapper().set_if_empty(&0u32);
+    }
+
+    fn is_charity_enabled(&self, charity_id: u32) -> bool {
+        match self.charity_info_mapper().get(&charity_id) {
+            None => return false,
+            Some(charity_info) => return charity_info.enabled,
+        }
+    }
+
+    fn add_donation(&self, iid: u32, amount: &BigUint) {
+        if *amount == BigUint::zero() {
+            return;
+        }
+
+        let charity_id: u32 = self.instance_charity_id_mapper(iid).get();
+
+        if charity_id == 0 {
+            // No registered charity for the instance, add donation to charity pool
+            self.charity_pool_mapper().update(|current_donations| *current_donations += amount);
+        }
+        else {
+            // Add donation to charity balance
+            self.charity_balance_mapper(charity_id).update(|current_donations| *current_donations += amount);
+            self.charity_donations_mapper(charity_id).update(|current_donations| *current_donations += amount);
+        }
+
+        self.instance_donations_mapper(iid).update(|current_donations| *current_donations += amount);
+        self.record_donation(amount);
+
+        // Log event
+        self.event_wrapper_add_donation(iid, charity_id, amount);
     }
 
     /////////////////////////////////////////////////////////////////////
     // Mappers
     /////////////////////////////////////////////////////////////////////
-    
-    // Charity pool 
+
+    // Charity pool for instances without registered charity
     #[storage_mapper("charity_pool")]
     fn charity_pool_mapper(&self) -> SingleValueMapper<BigUint>;
+
+    // Charity counter
+    #[storage_mapper("charity_id_counter")]
+    fn charity_id_counter_mapper(&self) -> SingleValueMapper<u32>;
+
+    // Charity registry
+    #[storage_mapper("charity_info")]
+    fn charity_info_mapper(&self) -> MapMapper<u32, CharityInfo<Self::Api>>;
+
+    // Donations not claimed yet (per charity)
+    #[storage_mapper("charity_balance")]
+    fn charity_balance_mapper(&self, charity_id: u32) -> SingleValueMapper<BigUint>;
+
+    // Lifetime donations (per charity)
+    #[storage_mapper("charity_donations")]
+    fn charity_donations_mapper(&self, charity_id: u32) -> SingleValueMapper<BigUint>;
+
+    // Charity chosen by the sponsor at instance creation, 0 if none
+    #[storage_mapper("instance_charity_id")]
+    fn instance_charity_id_mapper(&self, iid: u32) -> SingleValueMapper<u32>;
+
+    // Lifetime donations (per instance)
+    #[storage_mapper("instance_donations")]
+    fn instance_donations_mapper(&self, iid: u32) -> SingleValueMapper<BigUint>;
 }
\ No newline at end of file
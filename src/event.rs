@@ -1,4 +1,25 @@
 elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+
+// Version of the events layout, sent as event data
+// Topics : event identifier, indexed identifiers (iid, addresses, charity id), epoch, then event values
+pub const EVENT_SCHEMA_VERSION: u8 = 3;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum EventCategory {
+    Lifecycle,
+    Player,
+    Treasury,
+    PoolInfo,
+    Setup,
+}
 
 /////////////////////////////////////////////////////////////////////
 // Functions
@@ -11,8 +32,30 @@ pub trait EventModule {
     /////////////////////////////////////////////////////////////////////
     #[only_owner]
     #[endpoint(setLogEnableStatus)]
+    // Enable or disable all event categories, except lifecycle events which are always logged
     fn set_log_enable_status(&self, enable: bool) -> SCResult<()> {       
         self.log_enable_mapper().update(|current_value| *current_value = enable);
+
+        for category in self.get_toggleable_event_categories().iter() {
+            self.log_category_enable_mapper(category).set(&enable);
+        }
+
+        // Log event, whatever the log enable status
+        self.set_log_enable_status_event(self.blockchain().get_block_epoch(), enable, EVENT_SCHEMA_VERSION);
+
+        Ok(())
+    }
+
+    #[only_owner]
+    #[endpoint(setLogCategoryStatus)]
+    fn set_log_category_status(&self, category: EventCategory, enable: bool) -> SCResult<()> {       
+        require!(category != EventCategory::Lifecycle, "Lifecycle events cannot be disabled");
+
+        self.log_category_enable_mapper(category).set(&enable);
+
+        // Log event, whatever the log enable status
+        self.set_log_category_status_event(category, self.blockchain().get_block_epoch(), enable, EVENT_SCHEMA_VERSION);
+
         Ok(())
     }
 
@@ -24,11 +67,42 @@ pub trait EventModule {
         return self.log_enable_mapper().get(); 
     }
 
+    #[view(getLogCategoryStatus)]
+    fn get_log_category_status(&self, category: EventCategory) -> bool {        
+        if category == EventCategory::Lifecycle {
+            return true;
+        }
+
+        return self.log_category_enable_mapper(category).get(); 
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+
+    // Categories follow the global log enable status until set individually
+    // Lifecycle events are not part of the toggleable categories, off-chain consumers rebuild instances from them
+    fn init_log_categories_if_empty(&self) {
+        for category in self.get_toggleable_event_categories().iter() {
+            self.log_category_enable_mapper(category).set_if_empty(&self.log_enable_mapper().get());
+        }
+    }
+
+    fn get_toggleable_event_categories(&self) -> ManagedVec<EventCategory> {
+        let mut categories = ManagedVec::new();
+        categories.push(EventCategory::Player);
+        categories.push(EventCategory::Treasury);
+        categories.push(EventCategory::PoolInfo);
+        categories.push(EventCategory::Setup);
+
+        return categories;
+    }
+
     /////////////////////////////////////////////////////////////////////
     // Event wrappers
     /////////////////////////////////////////////////////////////////////
      
-    // Events occuring during instance lifecycle
+    // Events occuring during instance lifecycle, always logged
     fn event_wrapper_create_instance(
         &self,
         sponsor_address: &ManagedAddress,
@@ -37,20 +111,23 @@ pub trait EventModule {
         token_nonce: u64,
         token_amount: &BigUint,
         duration_in_s: u64,
-        pseudo: &ManagedBuffer
+        pseudo: &ManagedBuffer,
+        charity_id: u32,
+        premium: bool
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.create_instance_event(
-                self.blockchain().get_block_epoch(),
-                sponsor_address,
-                iid,
-                token_identifier,
-                token_nonce,
-                token_amount,
-                duration_in_s,
-                pseudo
-            );
-        }
+        self.create_instance_event(
+            iid,
+            sponsor_address,
+            self.blockchain().get_block_epoch(),
+            token_identifier,
+            token_nonce,
+            token_amount,
+            duration_in_s,
+            pseudo,
+            charity_id,
+            premium,
+            EVENT_SCHEMA_VERSION
+        );
     }
 
     fn event_wrapper_play(
@@ -60,15 +137,14 @@ pub trait EventModule {
         ticket_number: usize,
         fees: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.play_event(
-                self.blockchain().get_block_epoch(),
-                player_address,
-                iid,
-                ticket_number,
-                fees
-            );
-        }
+        self.play_event(
+            iid,
+            player_address,
+            self.blockchain().get_block_epoch(),
+            ticket_number,
+            fees,
+            EVENT_SCHEMA_VERSION
+        );
     }
 
     fn event_wrapper_trigger(
@@ -77,76 +153,291 @@ pub trait EventModule {
         ticket_number: usize,
         winner_address: &ManagedAddress
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.trigger_event(
-                self.blockchain().get_block_epoch(),
+        self.trigger_event(
+            iid,
+            winner_address,
+            self.blockchain().get_block_epoch(),
+            ticket_number,
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    fn event_wrapper_jackpot_won(
+        &self,
+        iid: u32,
+        winner_address: &ManagedAddress,
+        jackpot: &BigUint
+    ) {
+        self.jackpot_won_event(
+            iid,
+            winner_address,
+            self.blockchain().get_block_epoch(),
+            jackpot,
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    fn event_wrapper_auto_claim_prize(
+        &self,
+        iid: u32,
+        winner_address: &ManagedAddress
+    ) {
+        self.auto_claim_prize_event(
+            iid,
+            winner_address,
+            self.blockchain().get_block_epoch(),
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    fn event_wrapper_manual_claim_prize(
+        &self,
+        iid: u32,
+        winner_address: &ManagedAddress
+    ) {
+        self.manual_claim_prize_event(
+            iid,
+            winner_address,
+            self.blockchain().get_block_epoch(),
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    fn event_wrapper_clean_claim(
+        &self,
+        iid: u32,
+        sponsor_address: &ManagedAddress,
+        winner_address: &ManagedAddress,
+        ticket_number: usize,
+        number_of_players: usize,
+        token_identifier: &TokenIdentifier,
+        token_nonce: u64,
+        token_amount: &BigUint
+    ) {
+        self.clean_claim_event(
+            iid,
+            sponsor_address,
+            winner_address,
+            self.blockchain().get_block_epoch(),
+            ticket_number,
+            number_of_players,
+            token_identifier,
+            token_nonce,
+            token_amount,
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    // Administrator actions changing instance states or funds, always logged as well
+    fn event_wrapper_disable_instance(
+        &self,
+        iid: u32,
+        disable_status: bool
+    ) {
+        self.disable_instance_event(
+            iid,
+            self.blockchain().get_block_epoch(),
+            disable_status,
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    fn event_wrapper_slash_bond(
+        &self,
+        iid: u32,
+        sponsor_address: &ManagedAddress,
+        token_identifier: &TokenIdentifier,
+        slashed_amount: &BigUint,
+        to_charity: bool
+    ) {
+        self.slash_bond_event(
+            iid,
+            sponsor_address,
+            self.blockchain().get_block_epoch(),
+            token_identifier,
+            slashed_amount,
+            to_charity,
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    fn event_wrapper_migrate_indexes(
+        &self,
+        first_iid: u32,
+        next_iid: u32,
+        nb_plays: u32,
+        complete: bool
+    ) {
+        self.migrate_indexes_event(
+            self.blockchain().get_block_epoch(),
+            first_iid,
+            next_iid,
+            nb_plays,
+            complete,
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    fn event_wrapper_migrate_fee_policy(
+        &self,
+        iid: u32,
+        fee_amount_egld: &BigUint,
+        sponsor_reward_percent: u8,
+        link_reward_percent: u8,
+        link2_reward_percent: u8
+    ) {
+        self.migrate_fee_policy_event(
+            iid,
+            self.blockchain().get_block_epoch(),
+            fee_amount_egld,
+            sponsor_reward_percent,
+            link_reward_percent,
+            link2_reward_percent,
+            EVENT_SCHEMA_VERSION
+        );
+    }
+
+    // Events occuring during player & sponsor actions
+    fn event_wrapper_player_donation(
+        &self,
+        player_address: &ManagedAddress,
+        iid: u32,
+        donation: &BigUint
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Player).get() == true {
+            self.player_donation_event(
                 iid,
-                ticket_number,
-                winner_address
+                player_address,
+                self.blockchain().get_block_epoch(),
+                donation,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
-    fn event_wrapper_fee_pool_info(
+    fn event_wrapper_use_promo_code(
         &self,
-        fee_pool: &BigUint
+        iid: u32,
+        player_address: &ManagedAddress,
+        discount: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.fee_pool_info_event(
+        if self.log_category_enable_mapper(EventCategory::Player).get() == true {
+            self.use_promo_code_event(
+                iid,
+                player_address,
                 self.blockchain().get_block_epoch(),
-                fee_pool
+                discount,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
-    fn event_wrapper_reward_pool_info(
+    fn event_wrapper_add_promo_codes(
         &self,
         iid: u32,
-        reward_pool: &BigUint
+        nb_promo_codes: usize,
+        discount: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.reward_pool_info_event(
-                self.blockchain().get_block_epoch(),
+        if self.log_category_enable_mapper(EventCategory::Player).get() == true {
+            self.add_promo_codes_event(
                 iid,
-                reward_pool
+                self.blockchain().get_block_epoch(),
+                nb_promo_codes,
+                discount,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
-    fn event_wrapper_send_rewards(
+    fn event_wrapper_add_donation(
         &self,
         iid: u32,
-        rewards: &BigUint
+        charity_id: u32,
+        donation_amount: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.send_rewards_event(
-                self.blockchain().get_block_epoch(),
+        if self.log_category_enable_mapper(EventCategory::Player).get() == true {
+            self.add_donation_event(
                 iid,
-                rewards
+                charity_id,
+                self.blockchain().get_block_epoch(),
+                donation_amount,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
-    fn event_wrapper_auto_claim_prize(
+    fn event_wrapper_award_loyalty_points(
+        &self,
+        address: &ManagedAddress,
+        points: u64
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Player).get() == true {
+            self.award_loyalty_points_event(
+                address,
+                self.blockchain().get_block_epoch(),
+                points,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_redeem_loyalty_points(
         &self,
-        iid: u32
+        address: &ManagedAddress,
+        points: u64
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.auto_claim_prize_event(
+        if self.log_category_enable_mapper(EventCategory::Player).get() == true {
+            self.redeem_loyalty_points_event(
+                address,
                 self.blockchain().get_block_epoch(),
-                iid
+                points,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_set_referrer(
+        &self,
+        address: &ManagedAddress,
+        referrer_address: &ManagedAddress
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Player).get() == true {
+            self.set_referrer_event(
+                address,
+                referrer_address,
+                self.blockchain().get_block_epoch(),
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    // Events occuring when funds leave the SC
+    fn event_wrapper_send_rewards(
+        &self,
+        iid: u32,
+        rewards: &BigUint
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Treasury).get() == true {
+            self.send_rewards_event(
+                iid,
+                self.blockchain().get_block_epoch(),
+                rewards,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
     fn event_wrapper_claim_fees(
         &self,
-        fee_amount: &BigUint
+        fee_amount: &BigUint,
+        beneficiary_address: &ManagedAddress
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Treasury).get() == true {
             self.claim_fees_event(
+                beneficiary_address,
                 self.blockchain().get_block_epoch(),
-                fee_amount
+                fee_amount,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -156,11 +447,12 @@ pub trait EventModule {
         reward_amount: &BigUint,
         link_address: &ManagedAddress
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Treasury).get() == true {
             self.claim_link_rewards_event(
+                link_address,
                 self.blockchain().get_block_epoch(),
                 reward_amount,
-                link_address
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -169,34 +461,117 @@ pub trait EventModule {
         &self,
         donations_amount: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Treasury).get() == true {
             self.claim_donations_event(
                 self.blockchain().get_block_epoch(),
-                donations_amount
+                donations_amount,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
-    fn event_wrapper_manual_claim_prize(
+    fn event_wrapper_claim_charity_donations(
         &self,
-        iid: u32
+        charity_id: u32,
+        donations_amount: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.manual_claim_prize_event(
+        if self.log_category_enable_mapper(EventCategory::Treasury).get() == true {
+            self.claim_charity_donations_event(
+                charity_id,
                 self.blockchain().get_block_epoch(),
-                iid
+                donations_amount,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
-    fn event_wrapper_clean_claim(
+    fn event_wrapper_deposit_bond(
         &self,
-        iid: u32
+        sponsor_address: &ManagedAddress,
+        token_identifier: &TokenIdentifier,
+        bond_amount: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.clean_claim_event(
+        if self.log_category_enable_mapper(EventCategory::Treasury).get() == true {
+            self.deposit_bond_event(
+                sponsor_address,
                 self.blockchain().get_block_epoch(),
-                iid
+                token_identifier,
+                bond_amount,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_withdraw_bond(
+        &self,
+        sponsor_address: &ManagedAddress,
+        token_identifier: &TokenIdentifier,
+        bond_amount: &BigUint
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Treasury).get() == true {
+            self.withdraw_bond_event(
+                sponsor_address,
+                self.blockchain().get_block_epoch(),
+                token_identifier,
+                bond_amount,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    // Events reporting pools amounts
+    fn event_wrapper_fee_pool_info(
+        &self,
+        fees: &BigUint
+    ) {
+        if self.log_category_enable_mapper(EventCategory::PoolInfo).get() == true {
+            self.fee_pool_info_event(
+                self.blockchain().get_block_epoch(),
+                fees,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_jackpot_pool_info(
+        &self,
+        jackpot_pool: &BigUint
+    ) {
+        if self.log_category_enable_mapper(EventCategory::PoolInfo).get() == true {
+            self.jackpot_pool_info_event(
+                self.blockchain().get_block_epoch(),
+                jackpot_pool,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_pot_info(
+        &self,
+        iid: u32,
+        pot: &BigUint
+    ) {
+        if self.log_category_enable_mapper(EventCategory::PoolInfo).get() == true {
+            self.pot_info_event(
+                iid,
+                self.blockchain().get_block_epoch(),
+                pot,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_reward_pool_info(
+        &self,
+        iid: u32,
+        reward_pool: &BigUint
+    ) {
+        if self.log_category_enable_mapper(EventCategory::PoolInfo).get() == true {
+            self.reward_pool_info_event(
+                iid,
+                self.blockchain().get_block_epoch(),
+                reward_pool,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -207,25 +582,27 @@ pub trait EventModule {
         iid: u32,
         premium_status: bool
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.set_premium_event(
-                self.blockchain().get_block_epoch(),
                 iid,
-                premium_status
+                self.blockchain().get_block_epoch(),
+                premium_status,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
 
-    fn event_wrapper_disable_instance(
+    fn event_wrapper_set_bond_policy(
         &self,
-        iid: u32,
-        disable_status: bool
+        token_identifier: &TokenIdentifier,
+        bond_amount: &BigUint
     ) {
-        if self.log_enable_mapper().get() == true {
-            self.disable_instance_event(
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
+            self.set_bond_policy_event(
                 self.blockchain().get_block_epoch(),
-                iid,
-                disable_status
+                token_identifier,
+                bond_amount,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -234,14 +611,83 @@ pub trait EventModule {
         &self,
         fee_amount_egld: &BigUint,
         sponsor_reward_percent: u8,
-        link_reward_percent: u8
+        link_reward_percent: u8,
+        link2_reward_percent: u8
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.set_fee_policy_event(
                 self.blockchain().get_block_epoch(),
                 fee_amount_egld,
                 sponsor_reward_percent,
-                link_reward_percent
+                link_reward_percent,
+                link2_reward_percent,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_set_fee_beneficiary(
+        &self,
+        address: &ManagedAddress,
+        weight: u32
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
+            self.set_fee_beneficiary_event(
+                address,
+                self.blockchain().get_block_epoch(),
+                weight,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_set_charity(
+        &self,
+        charity_id: u32,
+        address: &ManagedAddress,
+        name: &ManagedBuffer,
+        enabled: bool
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
+            self.set_charity_event(
+                charity_id,
+                address,
+                self.blockchain().get_block_epoch(),
+                name,
+                enabled,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_set_jackpot_params(
+        &self,
+        fee_percent: u8,
+        chance: u32
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
+            self.set_jackpot_params_event(
+                self.blockchain().get_block_epoch(),
+                fee_percent,
+                chance,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_set_loyalty_policy(
+        &self,
+        points_per_egld: u64,
+        points_per_win: u64,
+        free_entry_cost: u64
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
+            self.set_loyalty_policy_event(
+                self.blockchain().get_block_epoch(),
+                points_per_egld,
+                points_per_win,
+                free_entry_cost,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -251,11 +697,12 @@ pub trait EventModule {
         duration_min: u64,
         duration_max: u64
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.set_param_duration_event(
                 self.blockchain().get_block_epoch(),
                 duration_min,
-                duration_max
+                duration_max,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -264,10 +711,11 @@ pub trait EventModule {
         &self,
         length_max: u32
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.set_param_sponsor_info_max_length_event(
                 self.blockchain().get_block_epoch(),
-                length_max
+                length_max,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -276,10 +724,24 @@ pub trait EventModule {
         &self,
         nb_instances_max: u32
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.set_param_nb_max_instances_per_sponsor_event(
                 self.blockchain().get_block_epoch(),
-                nb_instances_max
+                nb_instances_max,
+                EVENT_SCHEMA_VERSION
+            );
+        }
+    }
+
+    fn event_wrapper_set_param_pot_winner_percent(
+        &self,
+        winner_percent: u8
+    ) {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
+            self.set_param_pot_winner_percent_event(
+                self.blockchain().get_block_epoch(),
+                winner_percent,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -288,10 +750,11 @@ pub trait EventModule {
         &self,
         manual_claim: bool
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.set_param_manual_claim_event(
                 self.blockchain().get_block_epoch(),
-                manual_claim
+                manual_claim,
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -300,10 +763,11 @@ pub trait EventModule {
         &self,
         address: &ManagedAddress
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.add_addr_blacklist_event(
+                address,
                 self.blockchain().get_block_epoch(),
-                address
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -312,10 +776,11 @@ pub trait EventModule {
         &self,
         address: &ManagedAddress
     ) {
-        if self.log_enable_mapper().get() == true {
+        if self.log_category_enable_mapper(EventCategory::Setup).get() == true {
             self.rm_addr_blacklist_event(
+                address,
                 self.blockchain().get_block_epoch(),
-                address
+                EVENT_SCHEMA_VERSION
             );
         }
     }
@@ -324,120 +789,311 @@ pub trait EventModule {
     // Events
     /////////////////////////////////////////////////////////////////////
      
-    // Events occuring during instance lifecycle
+    // Events occuring when log status changes
+    #[event("set_log_enable_status")]
+    fn set_log_enable_status_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] enable: bool,
+        version: u8
+    ); 
+
+    #[event("set_log_category_status")]
+    fn set_log_category_status_event(
+        &self,
+        #[indexed] category: EventCategory,
+        #[indexed] epoch: u64,
+        #[indexed] enable: bool,
+        version: u8
+    ); 
+
+    // Events occuring during instance lifecycle, always logged
     #[event("create_instance")]
     fn create_instance_event(
         &self,
-        #[indexed] epoch: u64,
-        #[indexed] sponsor_address: &ManagedAddress,
         #[indexed] iid: u32,
+        #[indexed] sponsor_address: &ManagedAddress,
+        #[indexed] epoch: u64,
         #[indexed] token_identifier: &TokenIdentifier,
         #[indexed] token_nonce: u64,
         #[indexed] token_amount: &BigUint,
         #[indexed] duration_in_s: u64,
-        #[indexed] pseudo: &ManagedBuffer
+        #[indexed] pseudo: &ManagedBuffer,
+        #[indexed] charity_id: u32,
+        #[indexed] premium: bool,
+        version: u8
     ); 
 
     #[event("play")]
     fn play_event(
         &self,
-        #[indexed] epoch: u64,
-        #[indexed] player_address: &ManagedAddress,
         #[indexed] iid: u32,
+        #[indexed] player_address: &ManagedAddress,
+        #[indexed] epoch: u64,
         #[indexed] ticket_number: usize,
-        #[indexed] fees: &BigUint
+        #[indexed] fees: &BigUint,
+        version: u8
     ); 
 
     #[event("trigger")]
     fn trigger_event(
         &self,
-        #[indexed] epoch: u64,
         #[indexed] iid: u32,
+        #[indexed] winner_address: &ManagedAddress,
+        #[indexed] epoch: u64,
         #[indexed] ticket_number: usize,
-        #[indexed] winner_address: &ManagedAddress
+        version: u8
     ); 
 
-    #[event("fee_pool_info")]
-    fn fee_pool_info_event(
+    #[event("jackpot_won")]
+    fn jackpot_won_event(
         &self,
+        #[indexed] iid: u32,
+        #[indexed] winner_address: &ManagedAddress,
         #[indexed] epoch: u64,
-        #[indexed] fees: &BigUint
-    );
+        #[indexed] jackpot: &BigUint,
+        version: u8
+    ); 
 
-    #[event("reward_pool_info")]
-    fn reward_pool_info_event(
+    #[event("auto_claim_prize")]
+    fn auto_claim_prize_event(
         &self,
+        #[indexed] iid: u32,
+        #[indexed] winner_address: &ManagedAddress,
         #[indexed] epoch: u64,
+        version: u8
+    ); 
+
+    #[event("manual_claim_prize")]
+    fn manual_claim_prize_event(
+        &self,
         #[indexed] iid: u32,
-        #[indexed] reward_pool: &BigUint
-    );
+        #[indexed] winner_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        version: u8
+    ); 
 
-    #[event("send_rewards")]
-    fn send_rewards_event(
+    #[event("clean_claim")]
+    fn clean_claim_event(
         &self,
+        #[indexed] iid: u32,
+        #[indexed] sponsor_address: &ManagedAddress,
+        #[indexed] winner_address: &ManagedAddress,
         #[indexed] epoch: u64,
+        #[indexed] ticket_number: usize,
+        #[indexed] number_of_players: usize,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] token_nonce: u64,
+        #[indexed] token_amount: &BigUint,
+        version: u8
+    ); 
+
+    // Events occuring during player & sponsor actions
+    #[event("player_donation")]
+    fn player_donation_event(
+        &self,
         #[indexed] iid: u32,
-        #[indexed] rewards: &BigUint,
+        #[indexed] player_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] donation: &BigUint,
+        version: u8
     ); 
 
-    #[event("auto_claim_prize")]
-    fn auto_claim_prize_event(
+    #[event("use_promo_code")]
+    fn use_promo_code_event(
+        &self,
+        #[indexed] iid: u32,
+        #[indexed] player_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] discount: &BigUint,
+        version: u8
+    ); 
+
+    #[event("add_promo_codes")]
+    fn add_promo_codes_event(
+        &self,
+        #[indexed] iid: u32,
+        #[indexed] epoch: u64,
+        #[indexed] nb_promo_codes: usize,
+        #[indexed] discount: &BigUint,
+        version: u8
+    ); 
+
+    #[event("add_donation")]
+    fn add_donation_event(
+        &self,
+        #[indexed] iid: u32,
+        #[indexed] charity_id: u32,
+        #[indexed] epoch: u64,
+        #[indexed] donation_amount: &BigUint,
+        version: u8
+    ); 
+
+    #[event("award_loyalty_points")]
+    fn award_loyalty_points_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] points: u64,
+        version: u8
+    ); 
+
+    #[event("redeem_loyalty_points")]
+    fn redeem_loyalty_points_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] points: u64,
+        version: u8
+    ); 
+
+    #[event("set_referrer")]
+    fn set_referrer_event(
         &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] referrer_address: &ManagedAddress,
         #[indexed] epoch: u64,
-        #[indexed] iid: u32
+        version: u8
+    ); 
+
+    // Events occuring when funds leave the SC
+    #[event("send_rewards")]
+    fn send_rewards_event(
+        &self,
+        #[indexed] iid: u32,
+        #[indexed] epoch: u64,
+        #[indexed] rewards: &BigUint,
+        version: u8
     ); 
 
     #[event("claim_fees")]
     fn claim_fees_event(
         &self,
+        #[indexed] beneficiary_address: &ManagedAddress,
         #[indexed] epoch: u64,
-        #[indexed] fee_amount: &BigUint
+        #[indexed] fee_amount: &BigUint,
+        version: u8
     ); 
 
     #[event("claim_link_rewards")]
     fn claim_link_rewards_event(
         &self,
+        #[indexed] link_address: &ManagedAddress,
         #[indexed] epoch: u64,
         #[indexed] reward_amount: &BigUint,
-        #[indexed] link_address: &ManagedAddress
+        version: u8
     ); 
 
     #[event("claim_donations")]
     fn claim_donations_event(
         &self,
         #[indexed] epoch: u64,
-        #[indexed] donations_amount: &BigUint
+        #[indexed] donations_amount: &BigUint,
+        version: u8
     ); 
 
-    #[event("manual_claim_prize")]
-    fn manual_claim_prize_event(
+    #[event("claim_charity_donations")]
+    fn claim_charity_donations_event(
         &self,
+        #[indexed] charity_id: u32,
         #[indexed] epoch: u64,
-        #[indexed] iid: u32
+        #[indexed] donations_amount: &BigUint,
+        version: u8
     ); 
 
-    #[event("clean_claim")]
-    fn clean_claim_event(
+    #[event("deposit_bond")]
+    fn deposit_bond_event(
+        &self,
+        #[indexed] sponsor_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] bond_amount: &BigUint,
+        version: u8
+    ); 
+
+    #[event("withdraw_bond")]
+    fn withdraw_bond_event(
+        &self,
+        #[indexed] sponsor_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] bond_amount: &BigUint,
+        version: u8
+    ); 
+
+    // Events reporting pools amounts
+    #[event("fee_pool_info")]
+    fn fee_pool_info_event(
         &self,
         #[indexed] epoch: u64,
-        #[indexed] iid: u32
+        #[indexed] fees: &BigUint,
+        version: u8
+    ); 
+
+    #[event("jackpot_pool_info")]
+    fn jackpot_pool_info_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] jackpot_pool: &BigUint,
+        version: u8
+    ); 
+
+    #[event("pot_info")]
+    fn pot_info_event(
+        &self,
+        #[indexed] iid: u32,
+        #[indexed] epoch: u64,
+        #[indexed] pot: &BigUint,
+        version: u8
+    ); 
+
+    #[event("reward_pool_info")]
+    fn reward_pool_info_event(
+        &self,
+        #[indexed] iid: u32,
+        #[indexed] epoch: u64,
+        #[indexed] reward_pool: &BigUint,
+        version: u8
     ); 
 
     // Events occuring during setup
     #[event("set_premium")]
     fn set_premium_event(
         &self,
-        #[indexed] epoch: u64,
         #[indexed] iid: u32,
-        #[indexed] premium_status: bool
+        #[indexed] epoch: u64,
+        #[indexed] premium_status: bool,
+        version: u8
     ); 
 
     #[event("disable_instance")]
     fn disable_instance_event(
         &self,
+        #[indexed] iid: u32,
         #[indexed] epoch: u64,
+        #[indexed] disable_status: bool,
+        version: u8
+    ); 
+
+    #[event("slash_bond")]
+    fn slash_bond_event(
+        &self,
         #[indexed] iid: u32,
-        #[indexed] disable_status: bool
+        #[indexed] sponsor_address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] slashed_amount: &BigUint,
+        #[indexed] to_charity: bool,
+        version: u8
+    ); 
+
+    #[event("set_bond_policy")]
+    fn set_bond_policy_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] token_identifier: &TokenIdentifier,
+        #[indexed] bond_amount: &BigUint,
+        version: u8
     ); 
 
     #[event("set_fee_policy")]
@@ -446,7 +1102,71 @@ pub trait EventModule {
         #[indexed] epoch: u64,
         #[indexed] fee_amount_egld: &BigUint,
         #[indexed] sponsor_reward_percent: u8,
-        #[indexed] link_reward_percent: u8
+        #[indexed] link_reward_percent: u8,
+        #[indexed] link2_reward_percent: u8,
+        version: u8
+    ); 
+
+    #[event("migrate_indexes")]
+    fn migrate_indexes_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] first_iid: u32,
+        #[indexed] next_iid: u32,
+        #[indexed] nb_plays: u32,
+        #[indexed] complete: bool,
+        version: u8
+    ); 
+
+    #[event("migrate_fee_policy")]
+    fn migrate_fee_policy_event(
+        &self,
+        #[indexed] iid: u32,
+        #[indexed] epoch: u64,
+        #[indexed] fee_amount_egld: &BigUint,
+        #[indexed] sponsor_reward_percent: u8,
+        #[indexed] link_reward_percent: u8,
+        #[indexed] link2_reward_percent: u8,
+        version: u8
+    ); 
+
+    #[event("set_fee_beneficiary")]
+    fn set_fee_beneficiary_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] weight: u32,
+        version: u8
+    ); 
+
+    #[event("set_charity")]
+    fn set_charity_event(
+        &self,
+        #[indexed] charity_id: u32,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] name: &ManagedBuffer,
+        #[indexed] enabled: bool,
+        version: u8
+    ); 
+
+    #[event("set_jackpot_params")]
+    fn set_jackpot_params_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] fee_percent: u8,
+        #[indexed] chance: u32,
+        version: u8
+    ); 
+
+    #[event("set_loyalty_policy")]
+    fn set_loyalty_policy_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] points_per_egld: u64,
+        #[indexed] points_per_win: u64,
+        #[indexed] free_entry_cost: u64,
+        version: u8
     ); 
 
     #[event("set_param_duration")]
@@ -454,50 +1174,67 @@ pub trait EventModule {
         &self,
         #[indexed] epoch: u64,
         #[indexed] duration_min: u64,
-        #[indexed] duration_max: u64
+        #[indexed] duration_max: u64,
+        version: u8
     ); 
 
     #[event("set_param_sponsor_info_max_length")]
     fn set_param_sponsor_info_max_length_event(
         &self,
         #[indexed] epoch: u64,
-        #[indexed] length_max: u32
+        #[indexed] length_max: u32,
+        version: u8
     ); 
 
     #[event("set_param_nb_max_instances_per_sponsor")]
     fn set_param_nb_max_instances_per_sponsor_event(
         &self,
         #[indexed] epoch: u64,
-        #[indexed] nb_instances_max: u32
+        #[indexed] nb_instances_max: u32,
+        version: u8
+    ); 
+
+    #[event("set_param_pot_winner_percent")]
+    fn set_param_pot_winner_percent_event(
+        &self,
+        #[indexed] epoch: u64,
+        #[indexed] winner_percent: u8,
+        version: u8
     ); 
 
     #[event("set_param_manual_claim")]
     fn set_param_manual_claim_event(
         &self,
         #[indexed] epoch: u64,
-        #[indexed] manual_claim: bool
+        #[indexed] manual_claim: bool,
+        version: u8
     ); 
 
     #[event("add_addr_blacklist")]
     fn add_addr_blacklist_event(
         &self,
+        #[indexed] address: &ManagedAddress,
         #[indexed] epoch: u64,
-        #[indexed] address: &ManagedAddress
+        version: u8
     ); 
 
     #[event("rm_addr_blacklist")]
     fn rm_addr_blacklist_event(
         &self,
+        #[indexed] address: &ManagedAddress,
         #[indexed] epoch: u64,
-        #[indexed] address: &ManagedAddress
+        version: u8
     ); 
 
     /////////////////////////////////////////////////////////////////////
     // Mappers
     /////////////////////////////////////////////////////////////////////
     
-    // Log enable status
+    // Log enable status, last status set for all categories
     #[storage_mapper("log_enable")]
     fn log_enable_mapper(&self) -> SingleValueMapper<bool>;
-}
 
+    // Log enable status (per event category)
+    #[storage_mapper("log_category_enable")]
+    fn log_category_enable_mapper(&self, category: EventCategory) -> SingleValueMapper<bool>;
+}
@@ -2,13 +2,24 @@ elrond_wasm::imports!();
 elrond_wasm::derive_imports!();
 
 use super::event;
+use super::referral;
+use super::jackpot;
+use super::leaderboard;
+use super::leaderboard::LeaderboardKind;
+use super::stats;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const MAX_FEE_BENEFICIARIES: usize = 10;
 
 /////////////////////////////////////////////////////////////////////
 // Types
 /////////////////////////////////////////////////////////////////////
 
 // Fee policy
-#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+// Layout is the one of the deployed contract, level-2 link reward percent is stored apart
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub struct FeePolicy<M: ManagedTypeApi> {
     pub fee_amount_egld: BigUint<M>,
     pub sponsor_reward_percent: u8,
@@ -20,15 +31,19 @@ pub struct FeePolicy<M: ManagedTypeApi> {
 /////////////////////////////////////////////////////////////////////
 #[elrond_wasm::module]
 pub trait FeeModule:
-    event::EventModule {
+    referral::ReferralModule
+    +jackpot::JackpotModule
+    +leaderboard::LeaderboardModule
+    +stats::StatsModule
+    +event::EventModule {
 
     /////////////////////////////////////////////////////////////////////
     // Endpoints
     /////////////////////////////////////////////////////////////////////
     #[only_owner]
     #[endpoint(setFeePol)]
-    fn set_fee_policy(&self, fee_amount_egld: BigUint, sponsor_reward_percent: u8, link_reward_percent: u8) -> SCResult<()> {
-        require!((sponsor_reward_percent + link_reward_percent) <= 100, "Wrong value for rewards");
+    fn set_fee_policy(&self, fee_amount_egld: BigUint, sponsor_reward_percent: u8, link_reward_percent: u8, link2_reward_percent: u8) -> SCResult<()> {
+        require!((sponsor_reward_percent as u32 + link_reward_percent as u32 + link2_reward_percent as u32) <= 100, "Wrong value for rewards");
 
         // Save fee policy
         let fee_policy = FeePolicy {
@@ -38,26 +53,64 @@ pub trait FeeModule:
         };
 
         self.fee_policy_mapper().set(&fee_policy); 
+        self.link2_reward_percent_mapper().set(&link2_reward_percent);
 
         // Log event
-        self.event_wrapper_set_fee_policy(&fee_amount_egld, sponsor_reward_percent, link_reward_percent);
+        self.event_wrapper_set_fee_policy(&fee_amount_egld, sponsor_reward_percent, link_reward_percent, link2_reward_percent);
 
         Ok(())
     }
 
     #[only_owner]
+    #[endpoint(setFeeBeneficiary)]
+    fn set_fee_beneficiary(&self, address: ManagedAddress, weight: u32) -> SCResult<()> {
+        require!(address.is_zero() == false, "Invalid beneficiary address");
+        require!(weight == 0 || self.fee_beneficiaries_mapper().contains_key(&address) || self.fee_beneficiaries_mapper().len() < MAX_FEE_BENEFICIARIES, "Max beneficiaries reached");
+
+        // Fees collected so far are allocated with the previous weights
+        self.distribute_fee_pool();
+
+        // Update beneficiary weight, remove beneficiary if weight is null
+        if weight == 0 {
+            require!(self.fee_beneficiaries_mapper().remove(&address).is_some(), "Address is not a beneficiary");
+        }
+        else {
+            self.fee_beneficiaries_mapper().insert(address.clone(), weight);
+        }
+
+        // Log event
+        self.event_wrapper_set_fee_beneficiary(&address, weight);
+
+        Ok(())
+    }
+
     #[endpoint(claimFees)]
     fn claim_fees(&self) -> SCResult<()> {
-        let fee_amount: BigUint = self.fee_pool_mapper().get();
+        let caller = self.blockchain().get_caller();
 
-        require!(fee_amount != BigUint::zero(), "No fees to claim");
+        // Allocate pending fees to beneficiaries
+        self.distribute_fee_pool();
 
-        // Claim fees and clear the pool
-        self.send().direct_egld(&self.blockchain().get_owner_address(), &fee_amount, b"Fees from pool claimed");
-        self.fee_pool_mapper().clear();
+        require!(self.fee_balance_mapper(&caller).get() != BigUint::zero(), "No fees to claim");
 
-        // Log event
-        self.event_wrapper_claim_fees(&fee_amount);
+        self.send_fee_balance(&caller);
+
+        Ok(())
+    }
+
+    #[only_owner]
+    #[endpoint(pushFees)]
+    fn push_fees(&self) -> SCResult<()> {
+
+        // Allocate pending fees to beneficiaries
+        self.distribute_fee_pool();
+
+        // Send each beneficiary its balance, including the SC owner's balance allocated before beneficiaries were set
+        for address in self.get_fee_beneficiary_addresses().iter() {
+            if self.fee_balance_mapper(&address).get() != BigUint::zero() {
+                self.send_fee_balance(&address);
+            }
+        }
 
         Ok(())
     }
@@ -72,6 +125,7 @@ pub trait FeeModule:
         // Claim rewards and clear the pool
         self.send().direct_egld(&caller, &reward_amount, b"Link rewards claimed");
         self.link_reward_pool_mapper(&caller).clear();
+        self.record_link_rewards_paid(&reward_amount);
 
         // Log event
         self.event_wrapper_claim_link_rewards(&reward_amount, &caller);
@@ -83,10 +137,10 @@ pub trait FeeModule:
     // Queries
     /////////////////////////////////////////////////////////////////////
     #[view(getFeePol)]
-    fn get_fee_policy(&self) -> MultiValue3<BigUint, u8, u8> {        
+    fn get_fee_policy(&self) -> MultiValue4<BigUint, u8, u8, u8> {        
         let current_fee_policy: FeePolicy<Self::Api> = self.fee_policy_mapper().get();
 
-        return MultiValue3((current_fee_policy.fee_amount_egld, current_fee_policy.sponsor_reward_percent, current_fee_policy.link_reward_percent)); 
+        return MultiValue4((current_fee_policy.fee_amount_egld, current_fee_policy.sponsor_reward_percent, current_fee_policy.link_reward_percent, self.link2_reward_percent_mapper().get())); 
     }
     
     #[view(getFeePool)]
@@ -96,6 +150,40 @@ pub trait FeeModule:
         return self.fee_pool_mapper().get(); 
     }
 
+    #[view(getFeeBeneficiaries)]
+    fn get_fee_beneficiaries(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u32>> {
+        let mut beneficiaries = MultiValueEncoded::new();
+
+        // Return beneficiaries with their weights
+        for (address, weight) in self.fee_beneficiaries_mapper().iter() {
+            beneficiaries.push(MultiValue2((address, weight)));
+        }
+
+        return beneficiaries;
+    }
+
+    #[view(getFeeBalance)]
+    fn get_fee_balance(&self, address: ManagedAddress) -> BigUint {
+        let mut balance: BigUint = self.fee_balance_mapper(&address).get();
+
+        // Add the share of fees not yet allocated
+        let total_weight: u64 = self.get_fee_total_weight();
+        let fee_pool: BigUint = self.fee_pool_mapper().get();
+
+        if total_weight == 0 {
+            if address == self.blockchain().get_owner_address() {
+                balance += fee_pool;
+            }
+        }
+        else {
+            if let Some(weight) = self.fee_beneficiaries_mapper().get(&address) {
+                balance += fee_pool * BigUint::from(weight) / BigUint::from(total_weight);
+            }
+        }
+
+        return balance;
+    }
+
     #[view(getLinkRewardPool)]
     fn get_link_reward_pool(&self, link_address: ManagedAddress) -> BigUint {
                
@@ -106,7 +194,7 @@ pub trait FeeModule:
     /////////////////////////////////////////////////////////////////////
     // Internal SC functions
     /////////////////////////////////////////////////////////////////////
-    fn init_fees_if_empty(&self, fee_amount_egld: BigUint, sponsor_reward_percent: u8, link_reward_percent: u8) {
+    fn init_fees_if_empty(&self, fee_amount_egld: BigUint, sponsor_reward_percent: u8, link_reward_percent: u8, link2_reward_percent: u8) {
         self.fee_pool_mapper().set_if_empty(&BigUint::zero());
 
         self.fee_policy_mapper().set_if_empty(&FeePolicy {
@@ -114,43 +202,135 @@ pub trait FeeModule:
             sponsor_reward_percent : sponsor_reward_percent,
             link_reward_percent: link_reward_percent
         });
+        self.link2_reward_percent_mapper().set_if_empty(&link2_reward_percent);
+    }
+
+    fn get_fee_total_weight(&self) -> u64 {
+        let mut total_weight: u64 = 0;
+
+        for weight in self.fee_beneficiaries_mapper().values() {
+            total_weight += weight as u64;
+        }
+
+        return total_weight;
+    }
+
+    fn get_fee_beneficiary_addresses(&self) -> ManagedVec<ManagedAddress> {
+        let mut addresses = ManagedVec::new();
+        let owner = self.blockchain().get_owner_address();
+
+        // SC owner is the only beneficiary if none has been set
+        if self.fee_beneficiaries_mapper().is_empty() {
+            addresses.push(owner);
+        }
+        else {
+            for address in self.fee_beneficiaries_mapper().keys() {
+                addresses.push(address);
+            }
+
+            // SC owner keeps the fees allocated before the first beneficiary has been set
+            if self.fee_beneficiaries_mapper().contains_key(&owner) == false && self.fee_balance_mapper(&owner).get() != BigUint::zero() {
+                addresses.push(owner);
+            }
+        }
+
+        return addresses;
+    }
+
+    // Allocate the fee pool to beneficiaries according to their current weights
+    // Rounding remainder stays in the pool and is allocated on next distribution
+    fn distribute_fee_pool(&self) {
+        let fee_pool: BigUint = self.fee_pool_mapper().get();
+
+        if fee_pool == BigUint::zero() {
+            return;
+        }
+
+        let total_weight: u64 = self.get_fee_total_weight();
+        let mut distributed_fees: BigUint = BigUint::zero();
+
+        if total_weight == 0 {
+            // SC owner gets all fees if no beneficiary has been set
+            self.fee_balance_mapper(&self.blockchain().get_owner_address()).update(|current_balance| *current_balance += fee_pool.clone());
+            distributed_fees = fee_pool.clone();
+        }
+        else {
+            for (address, weight) in self.fee_beneficiaries_mapper().iter() {
+                let share: BigUint = fee_pool.clone() * BigUint::from(weight) / BigUint::from(total_weight);
+                distributed_fees += share.clone();
+                self.fee_balance_mapper(&address).update(|current_balance| *current_balance += share);
+            }
+        }
+
+        self.fee_pool_mapper().set(&(fee_pool - distributed_fees));
     }
 
-    fn update_fees_and_compute_rewards(&self, fees: BigUint, sponsor_reward_percent: u8, link_address: Option<ManagedAddress>) -> BigUint {
+    fn send_fee_balance(&self, address: &ManagedAddress) {
+        let fee_amount: BigUint = self.fee_balance_mapper(address).get();
+
+        // Claim fees and clear the balance
+        self.send().direct_egld(address, &fee_amount, b"Fees from pool claimed");
+        self.fee_balance_mapper(address).clear();
+        self.record_fees_claimed(&fee_amount);
+
+        // Log event
+        self.event_wrapper_claim_fees(&fee_amount, address);
+    }
+
+    fn update_fees_and_compute_rewards(&self, fees: BigUint, fee_policy: &FeePolicy<Self::Api>, fee_link2_reward_percent: u8) -> BigUint {
+        let sponsor_reward_percent: u8 = fee_policy.sponsor_reward_percent;
         let mut link_reward_percent: u8 = 0;
+        let mut link2_reward_percent: u8 = 0;
         let mut sponsor_reward_amount: BigUint = BigUint::zero();
-        let link_reward_amount: BigUint;
         let caller = self.blockchain().get_caller();
 
         // Capitalize fees and compute sponsor rewards
         if fees != BigUint::zero() {
 
-            // Apply link rewards only if an affiliation link address has been provided
+            // Referrers registered for the caller (level 1) and for its referrer (level 2)
+            // Referral registration prevents self-referral & cycles, so the caller can never be one of its referrers
+            let link_address: Option<ManagedAddress> = if self.referrer_mapper(&caller).is_empty() {None} else {Some(self.referrer_mapper(&caller).get())};
+            let mut link2_address: Option<ManagedAddress> = None;
+
+            // Apply link rewards only if a referrer is registered
             if link_address.is_some() == true {
-                // Link rewards are only applied if the player does not play wih its own affiliate link
-                if link_address.clone().unwrap() != caller {
-                    link_reward_percent = self.fee_policy_mapper().get().link_reward_percent;
-
-                    // Sponsor reward percent is the value at the lottery creation while Link reward percent is the current value
-                    // Ensure the sum of rewards does not overflow the fees (100 %); truncate link reward if so
-                    // This is a safeguard measure, this condition should never be true
-                    if (sponsor_reward_percent + link_reward_percent) > 100 {
-                        link_reward_percent = 100 - sponsor_reward_percent;
-                    }
+                link_reward_percent = fee_policy.link_reward_percent;
+
+                if self.referrer_mapper(link_address.as_ref().unwrap()).is_empty() == false {
+                    link2_address = Some(self.referrer_mapper(link_address.as_ref().unwrap()).get());
+                    link2_reward_percent = fee_link2_reward_percent;
+                }
+
+                // All reward percents come from the fee policy recorded for the instance
+                // Ensure the sum of rewards does not overflow the fees (100 %); truncate link rewards if so
+                // This is a safeguard measure, this condition should never be true
+                if (sponsor_reward_percent as u32 + link_reward_percent as u32) > 100 {
+                    link_reward_percent = 100 - sponsor_reward_percent;
+                }
+                if (sponsor_reward_percent as u32 + link_reward_percent as u32 + link2_reward_percent as u32) > 100 {
+                    link2_reward_percent = 100 - sponsor_reward_percent - link_reward_percent;
                 }
             };
             
             // Compute rewards
             sponsor_reward_amount = fees.clone() * BigUint::from(sponsor_reward_percent) / BigUint::from(100u8);
-            link_reward_amount = fees.clone() * BigUint::from(link_reward_percent) / BigUint::from(100u8);
-            let remaining_fees: BigUint = fees - sponsor_reward_amount.clone() - link_reward_amount.clone();
+            let link_reward_amount: BigUint = fees.clone() * BigUint::from(link_reward_percent) / BigUint::from(100u8);
+            let link2_reward_amount: BigUint = fees.clone() * BigUint::from(link2_reward_percent) / BigUint::from(100u8);
+            let mut remaining_fees: BigUint = fees.clone() - sponsor_reward_amount.clone() - link_reward_amount.clone() - link2_reward_amount.clone();
+
+            // Feed the jackpot from remaining fees
+            remaining_fees -= self.feed_jackpot(&fees, &remaining_fees);
 
             // Add fees to pool
             self.fee_pool_mapper().update(|current_fees| *current_fees += remaining_fees);
 
-            //Add link rewards to affiliation pool 
-            if link_address.is_some() == true && link_reward_amount > BigUint::zero() {
-                self.link_reward_pool_mapper(&link_address.unwrap()).update(|current_link_rewards| *current_link_rewards += link_reward_amount);
+            // Add link rewards to affiliation pools
+            if link_address.is_some() == true {
+                self.credit_link_rewards(&link_address.unwrap(), 1u8, link_reward_amount);
+            }
+
+            if link2_address.is_some() == true {
+                self.credit_link_rewards(&link2_address.unwrap(), 2u8, link2_reward_amount);
             }
 
             // Log event
@@ -161,6 +341,14 @@ pub trait FeeModule:
         return sponsor_reward_amount;
     }
 
+    fn credit_link_rewards(&self, link_address: &ManagedAddress, level: u8, reward_amount: BigUint) {
+        if reward_amount > BigUint::zero() {
+            self.link_reward_pool_mapper(link_address).update(|current_link_rewards| *current_link_rewards += reward_amount.clone());
+            self.referral_rewards_earned_mapper(link_address, level).update(|current_earnings| *current_earnings += reward_amount.clone());
+            self.increase_leaderboard_score(LeaderboardKind::AffiliateEarnings, link_address, &reward_amount);
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////
     // Mappers
     /////////////////////////////////////////////////////////////////////
@@ -169,11 +357,23 @@ pub trait FeeModule:
     #[storage_mapper("fee_policy")]
     fn fee_policy_mapper(&self) -> SingleValueMapper<FeePolicy<Self::Api>>;
 
-    // Fee pool for SC owner
+    // Level-2 link reward percent of the fee policy
+    #[storage_mapper("link2_reward_percent")]
+    fn link2_reward_percent_mapper(&self) -> SingleValueMapper<u8>;
+
+    // Fee pool, not yet allocated to beneficiaries
     #[storage_mapper("fee_pool")]
     fn fee_pool_mapper(&self) -> SingleValueMapper<BigUint>;
 
-    // Reward pool for affiliation links (per address)
+    // Fee beneficiaries with their weights
+    #[storage_mapper("fee_beneficiaries")]
+    fn fee_beneficiaries_mapper(&self) -> MapMapper<ManagedAddress, u32>;
+
+    // Fees allocated to a beneficiary and not claimed yet
+    #[storage_mapper("fee_balance")]
+    fn fee_balance_mapper(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Reward pool for affiliation links (per address, all levels)
     #[storage_mapper("link_reward_pool")]
     fn link_reward_pool_mapper(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
 }
\ No newline at end of file
@@ -10,6 +10,7 @@ use variant_count::VariantCount;
 use super::Ok_some;
 use super::require_with_opt;
 use super::event;
+use super::fee::FeePolicy;
 
 ////////////////////////////////////////////////////////////////////
 // Types
@@ -138,7 +139,7 @@ pub trait InstanceModule:
 
     #[view(hasStatus)]
     fn is_instance_with_status(&self, instance_status: InstanceStatus) -> bool {
-        let instances: MultiValueManagedVec<u32> = self.get_instance_ids(MultiValueManagedVec::from_single_item(instance_status));
+        let instances: MultiValueManagedVec<u32> = self.get_all_instance_ids(MultiValueManagedVec::from_single_item(instance_status));
         return instances.len() != 0;
     }
 
@@ -155,12 +156,21 @@ pub trait InstanceModule:
             }
             else {
                 // Return the total number of instances which meet the status filter provided in parameter
-                for iid in self.instance_info_mapper().keys() {
-                    for status in status_filter.iter() {
-                        if self.get_instance_status(iid) == status.clone() {
-                            nb_instances += 1;
-                            break;
-                        }   
+                for bucket in self.get_status_buckets().iter() {
+                    if self.is_bucket_in_status_filter(bucket, &status_filter) == true {
+
+                        if bucket != InstanceStatus::Running || (self.is_status_in_filter(InstanceStatus::Running, &status_filter) && self.is_status_in_filter(InstanceStatus::Ended, &status_filter)) {
+                            // All instances of the bucket meet the filter
+                            nb_instances += self.status_iids_mapper(bucket).len() as u32;
+                        }
+                        else {
+                            // Running & ended instances share the same bucket, status depends on the deadline
+                            for node in self.status_iids_mapper(bucket).iter() {
+                                if self.is_instance_in_status_filter(node.get_value_cloned(), &status_filter) == true {
+                                    nb_instances += 1;
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -186,30 +196,37 @@ pub trait InstanceModule:
     }
 
     #[view(getIDs)]
-    fn get_instance_ids(&self, #[var_args] status_filter: MultiValueManagedVec<InstanceStatus>) -> MultiValueManagedVec<u32> {
+    // Returns : 
+    //  - next cursor to provide to get the following page, 0 when all instances have been returned, followed by
+    //  - up to <limit> filtered instances IDs from <cursor> (0 to start from the first instance), ordered by status bucket then by indexing order
+    fn get_instance_ids(&self, cursor: u32, limit: u32, #[var_args] status_filter: MultiValueManagedVec<InstanceStatus>) -> MultiValue2<u32, MultiValueManagedVec<u32>> {
+        require!(limit > 0, "Limit must be positive");
 
         let mut instance_ids = MultiValueManagedVec::new();
-        // let mut status_filter_vec = status_filter.clone().into_vec().into_vec();
+        let mut next_cursor: u32 = 0;
 
         // Ensure at least one status is provided as filter, check also overflow regarding the maximum possible values for status
         if status_filter.len() >= 1 && status_filter.len() <= InstanceStatus::VARIANT_COUNT {
 
-            // Remove duplicates
-            // status_filter_vec.sort();
-            // status_filter_vec.dedup();
+            // Buckets of the status index are walked in order from the cursor, until the page is full
+            let (cursor_position, cursor_node_id) = self.split_status_cursor(cursor);
+            let mut position: u32 = 0;
 
-            // Return all instances IDs which meet the status filter provided in parameter
-            for iid in self.instance_info_mapper().keys() {
-                for status in status_filter.iter() {
-                    if self.get_instance_status(iid) == status.clone() {
-                        instance_ids.push(iid.clone());
+            for bucket in self.get_status_buckets().iter() {
+                if position >= cursor_position && self.is_bucket_in_status_filter(bucket, &status_filter) == true {
+                    let node_id: u32 = if position == cursor_position {cursor_node_id} else {0};
+                    let next_node_id: u32 = self.fill_page_from_index(&self.status_iids_mapper(bucket), node_id, limit, Some(&status_filter), &mut instance_ids);
+
+                    if next_node_id != 0 {
+                        next_cursor = self.get_status_cursor(position, next_node_id);
                         break;
-                    }   
+                    }
                 }
+                position += 1;
             }
         }
 
-        return instance_ids;
+        return MultiValue2((next_cursor, instance_ids));
     }
 
     #[view(hasWon)]
@@ -226,6 +243,161 @@ pub trait InstanceModule:
         Ok_some!(result)
     }
 
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+
+    // Unpaginated list of instances IDs meeting the status filter, for internal processing only
+    fn get_all_instance_ids(&self, status_filter: MultiValueManagedVec<InstanceStatus>) -> MultiValueManagedVec<u32> {
+        let mut instance_ids = MultiValueManagedVec::new();
+
+        // Ensure at least one status is provided as filter, check also overflow regarding the maximum possible values for status
+        if status_filter.len() >= 1 && status_filter.len() <= InstanceStatus::VARIANT_COUNT {
+
+            // Return all instances IDs which meet the status filter provided in parameter
+            for bucket in self.get_status_buckets().iter() {
+                if self.is_bucket_in_status_filter(bucket, &status_filter) == true {
+                    for node in self.status_iids_mapper(bucket).iter() {
+                        if self.is_instance_in_status_filter(node.get_value_cloned(), &status_filter) == true {
+                            instance_ids.push(node.get_value_cloned());
+                        }
+                    }
+                }
+            }
+        }
+
+        return instance_ids;
+    }
+
+    fn is_instance_in_status_filter(&self, iid: u32, status_filter: &MultiValueManagedVec<InstanceStatus>) -> bool {
+        if self.instance_info_mapper().contains_key(&iid) == false {
+            return false;
+        }
+
+        return self.is_status_in_filter(self.get_instance_status(iid), status_filter);
+    }
+
+    fn is_status_in_filter(&self, instance_status: InstanceStatus, status_filter: &MultiValueManagedVec<InstanceStatus>) -> bool {
+        for status in status_filter.iter() {
+            if instance_status == status {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    // Status index : instances are recorded in a bucket per persistent state
+    // Running and ended instances share the 'Running' bucket as the transition only depends on the deadline
+    fn get_status_buckets(&self) -> MultiValueManagedVec<InstanceStatus> {
+        let mut buckets = MultiValueManagedVec::new();
+        buckets.push(InstanceStatus::Running);
+        buckets.push(InstanceStatus::Triggered);
+        buckets.push(InstanceStatus::Claimed);
+        buckets.push(InstanceStatus::Disabled);
+
+        return buckets;
+    }
+
+    fn get_status_bucket(&self, instance_status: InstanceStatus) -> InstanceStatus {
+        if instance_status == InstanceStatus::Ended {
+            return InstanceStatus::Running;
+        }
+
+        return instance_status;
+    }
+
+    fn is_bucket_in_status_filter(&self, bucket: InstanceStatus, status_filter: &MultiValueManagedVec<InstanceStatus>) -> bool {
+        for status in status_filter.iter() {
+            if self.get_status_bucket(status) == bucket {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    // Move the instance to the bucket of its current status, instance is removed from the index when not existing anymore
+    // Instance keeps its position while its bucket does not change
+    fn refresh_status_index(&self, iid: u32) {
+        let instance_status = self.get_instance_status(iid);
+
+        if self.instance_status_node_mapper(iid).is_empty() == false {
+            let previous_bucket = self.instance_status_bucket_mapper(iid).get();
+
+            if instance_status != InstanceStatus::NotExisting && self.get_status_bucket(instance_status) == previous_bucket {
+                return;
+            }
+
+            self.remove_from_index(&mut self.status_iids_mapper(previous_bucket), &self.instance_status_node_mapper(iid));
+            self.instance_status_bucket_mapper(iid).clear();
+        }
+
+        if instance_status != InstanceStatus::NotExisting {
+            let bucket = self.get_status_bucket(instance_status);
+
+            self.insert_in_index(&mut self.status_iids_mapper(bucket), &self.instance_status_node_mapper(iid), iid);
+            self.instance_status_bucket_mapper(iid).set(&bucket);
+        }
+    }
+
+    // Cursor of the status index : node ID in the bucket, followed by the position of the bucket on 2 bits
+    fn get_status_cursor(&self, bucket_position: u32, node_id: u32) -> u32 {
+        return (node_id << 2) | bucket_position;
+    }
+
+    // Returns : bucket position, node ID in the bucket
+    fn split_status_cursor(&self, cursor: u32) -> (u32, u32) {
+        return (cursor & 3, cursor >> 2);
+    }
+
+    // Ordered indexes : IIDs are appended to a linked list, the node of each IID is recorded to remove it
+    fn insert_in_index(&self, index: &mut LinkedListMapper<Self::Api, u32>, node_mapper: &SingleValueMapper<Self::Api, u32>, iid: u32) {
+        if node_mapper.is_empty() == true {
+            let node = index.push_back(iid);
+            node_mapper.set(&node.get_node_id());
+        }
+    }
+
+    fn remove_from_index(&self, index: &mut LinkedListMapper<Self::Api, u32>, node_mapper: &SingleValueMapper<Self::Api, u32>) {
+        if node_mapper.is_empty() == false {
+            index.remove_node_by_id(node_mapper.get());
+            node_mapper.clear();
+        }
+    }
+
+    // Fill the page with the IIDs of the index from the node <cursor> (0 for the first node), optionally filtered by status
+    // Node IDs grow along the list as IIDs are only appended : a page resumes from the first node at or after the cursor, even if the cursor node has been removed
+    // Returns : node ID of the first IID left out of the full page, 0 when the end of the index has been reached
+    fn fill_page_from_index(&self, index: &LinkedListMapper<Self::Api, u32>, cursor: u32, limit: u32, status_filter: Option<&MultiValueManagedVec<InstanceStatus>>, page: &mut MultiValueManagedVec<u32>) -> u32 {
+        let nodes = if cursor != 0 && index.get_node_by_id(cursor).is_some() {index.iter_from_node_id(cursor)} else {index.iter()};
+
+        for node in nodes {
+            if node.get_node_id() >= cursor {
+                let iid: u32 = node.get_value_cloned();
+
+                if status_filter.is_none() || self.is_instance_in_status_filter(iid, status_filter.unwrap()) == true {
+                    if self.is_page_full(page, limit) == true {
+                        return node.get_node_id();
+                    }
+                    page.push(iid);
+                }
+            }
+        }
+
+        return 0;
+    }
+
+    // Instances existing at upgrade are migrated in IID order
+    fn is_instance_migrated(&self, iid: u32) -> bool {
+        return self.indexes_built_mapper().get() == true || iid < self.migration_next_iid_mapper().get() || iid > self.migration_last_iid_mapper().get();
+    }
+
+    // Pages are filled in index order, the next cursor is the node of the first IID left out of a full page (0 when all IIDs have been returned)
+    fn is_page_full(&self, page: &MultiValueManagedVec<u32>, limit: u32) -> bool {
+        return page.len() as u32 >= limit;
+    }
+
     /////////////////////////////////////////////////////////////////////
     // Mappers
     /////////////////////////////////////////////////////////////////////
@@ -241,4 +413,52 @@ pub trait InstanceModule:
     // Instance state
     #[storage_mapper("instance_state")]
     fn instance_state_mapper(&self) -> MapMapper<u32, InstanceState<Self::Api>>;
+
+    // Fee policy applicable to the instance, recorded at instance creation
+    #[storage_mapper("instance_fee_policy")]
+    fn instance_fee_policy_mapper(&self) -> MapMapper<u32, FeePolicy<Self::Api>>;
+
+    // Level-2 link reward percent applicable to the instance, recorded with the fee policy
+    #[storage_mapper("instance_link2_reward_percent")]
+    fn instance_link2_reward_percent_mapper(&self, iid: u32) -> SingleValueMapper<u8>;
+
+    // Percent of fees given to the winner for instances funded by tickets, 0 for instances with escrowed prize
+    #[storage_mapper("instance_pot_winner_percent")]
+    fn instance_pot_winner_percent_mapper(&self, iid: u32) -> SingleValueMapper<u8>;
+
+    // Ticket pot for instances funded by tickets
+    #[storage_mapper("instance_pot")]
+    fn instance_pot_mapper(&self, iid: u32) -> SingleValueMapper<BigUint>;
+
+    // Trigger date time (per instance)
+    #[storage_mapper("instance_trigger_timestamp")]
+    fn instance_trigger_timestamp_mapper(&self, iid: u32) -> SingleValueMapper<u64>;
+
+    // Instances IDs per status bucket
+    #[storage_mapper("status_iids")]
+    fn status_iids_mapper(&self, bucket: InstanceStatus) -> LinkedListMapper<u32>;
+
+    // Status bucket of the instance in the status index (per instance)
+    #[storage_mapper("instance_status_bucket")]
+    fn instance_status_bucket_mapper(&self, iid: u32) -> SingleValueMapper<InstanceStatus>;
+
+    // Node of the instance in its status bucket (per instance)
+    #[storage_mapper("instance_status_node")]
+    fn instance_status_node_mapper(&self, iid: u32) -> SingleValueMapper<u32>;
+
+    // Secondary indexes build status, for migration of instances created before indexes
+    #[storage_mapper("indexes_built")]
+    fn indexes_built_mapper(&self) -> SingleValueMapper<bool>;
+
+    // Next IID to migrate to the secondary indexes
+    #[storage_mapper("migration_next_iid")]
+    fn migration_next_iid_mapper(&self) -> SingleValueMapper<u32>;
+
+    // Last IID existing at upgrade, instances created afterwards are indexed at creation
+    #[storage_mapper("migration_last_iid")]
+    fn migration_last_iid_mapper(&self) -> SingleValueMapper<u32>;
+
+    // Fee policy at upgrade, recorded by the migration for the instances existing at upgrade
+    #[storage_mapper("migration_fee_policy")]
+    fn migration_fee_policy_mapper(&self) -> SingleValueMapper<FeePolicy<Self::Api>>;
 }
\ No newline at end of file
@@ -0,0 +1,133 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use super::event;
+use super::stats;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+
+// Jackpot chance is expressed in basis points (1/10000)
+const JACKPOT_CHANCE_DENOMINATOR: u32 = 10_000;
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait JackpotModule:
+    stats::StatsModule
+    +event::EventModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+    #[only_owner]
+    #[endpoint(setJackpotParams)]
+    fn set_jackpot_params(&self, fee_percent: u8, chance: u32) -> SCResult<()> {
+        require!(fee_percent <= 100, "Wrong value for jackpot fee percent");
+        require!(chance <= JACKPOT_CHANCE_DENOMINATOR, "Wrong value for jackpot chance");
+
+        self.jackpot_fee_percent_mapper().set(&fee_percent);
+        self.jackpot_chance_mapper().set(&chance);
+
+        // Log event
+        self.event_wrapper_set_jackpot_params(fee_percent, chance);
+
+        Ok(())
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getJackpotParams)]
+    // Returns : percent of fees feeding the jackpot, chance to win the jackpot at each trigger (basis points)
+    fn get_jackpot_params(&self) -> MultiValue2<u8, u32> {
+        return MultiValue2((self.jackpot_fee_percent_mapper().get(), self.jackpot_chance_mapper().get()));
+    }
+
+    #[view(getJackpotPool)]
+    fn get_jackpot_pool(&self) -> BigUint {
+
+        // Get the current amount of the jackpot
+        return self.jackpot_pool_mapper().get();
+    }
+
+    #[view(getJackpotWon)]
+    fn get_jackpot_won(&self, iid: u32) -> BigUint {
+
+        // Get the jackpot amount won by the instance winner, zero if not won
+        return self.instance_jackpot_won_mapper(iid).get();
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+    fn init_jackpot_if_empty(&self, fee_percent: u8, chance: u32) {
+        self.jackpot_pool_mapper().set_if_empty(&BigUint::zero());
+        self.jackpot_fee_percent_mapper().set_if_empty(&fee_percent);
+        self.jackpot_chance_mapper().set_if_empty(&chance);
+    }
+
+    // Take the jackpot share of fees from the available fees, returns the amount added to the jackpot
+    fn feed_jackpot(&self, fees: &BigUint, available_fees: &BigUint) -> BigUint {
+        let mut jackpot_amount: BigUint = fees.clone() * BigUint::from(self.jackpot_fee_percent_mapper().get()) / BigUint::from(100u8);
+
+        // Jackpot share cannot exceed fees remaining after rewards
+        if jackpot_amount > *available_fees {
+            jackpot_amount = available_fees.clone();
+        }
+
+        if jackpot_amount > BigUint::zero() {
+            self.jackpot_pool_mapper().update(|current_jackpot| *current_jackpot += jackpot_amount.clone());
+
+            // Log event
+            self.event_wrapper_jackpot_pool_info(&self.jackpot_pool_mapper().get());
+        }
+
+        return jackpot_amount;
+    }
+
+    // Draw the jackpot for the instance winner, jackpot rolls over if not won
+    fn draw_jackpot(&self, iid: u32, winner_address: &ManagedAddress, rand: &mut RandomnessSource<Self::Api>) {
+        let jackpot: BigUint = self.jackpot_pool_mapper().get();
+
+        if jackpot == BigUint::zero() {
+            return;
+        }
+
+        if rand.next_u32_in_range(0, JACKPOT_CHANCE_DENOMINATOR) < self.jackpot_chance_mapper().get() {
+
+            // Send jackpot to winner address and empty the pool
+            self.send().direct_egld(winner_address, &jackpot, b"Jackpot");
+            self.jackpot_pool_mapper().set(&BigUint::zero());
+            self.instance_jackpot_won_mapper(iid).set(&jackpot);
+
+            // Update statistics
+            self.record_prize_distributed(&TokenIdentifier::egld(), &jackpot);
+
+            // Log event
+            self.event_wrapper_jackpot_won(iid, winner_address, &jackpot);
+        }
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Jackpot pool
+    #[storage_mapper("jackpot_pool")]
+    fn jackpot_pool_mapper(&self) -> SingleValueMapper<BigUint>;
+
+    // Percent of fees feeding the jackpot
+    #[storage_mapper("jackpot_fee_percent")]
+    fn jackpot_fee_percent_mapper(&self) -> SingleValueMapper<u8>;
+
+    // Chance to win the jackpot at each trigger, in basis points
+    #[storage_mapper("jackpot_chance")]
+    fn jackpot_chance_mapper(&self) -> SingleValueMapper<u32>;
+
+    // Jackpot won (per instance)
+    #[storage_mapper("instance_jackpot_won")]
+    fn instance_jackpot_won_mapper(&self, iid: u32) -> SingleValueMapper<BigUint>;
+}
\ No newline at end of file
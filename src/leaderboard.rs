@@ -0,0 +1,122 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const LEADERBOARD_SIZE: usize = 20;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum LeaderboardKind {
+    SponsorInstances,
+    SponsorPrizes,
+    PlayerPlays,
+    PlayerWins,
+    AffiliatePlays,
+    AffiliateEarnings,
+    LoyaltyPoints,
+}
+
+// Leaderboard entry
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct LeaderboardEntry<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub score: BigUint<M>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait LeaderboardModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getLeaderboard)]
+    // Returns : up to <max_nb_entries> addresses with the highest scores for the leaderboard kind
+    // Prizes escrowed by sponsors only account for EGLD prizes
+    fn get_leaderboard(&self, kind: LeaderboardKind, max_nb_entries: usize) -> MultiValueManagedVec<LeaderboardEntry<Self::Api>> {
+        let mut leaderboard: MultiValueManagedVec<LeaderboardEntry<Self::Api>> = MultiValueManagedVec::new();
+
+        for entry in self.leaderboard_mapper(kind).get().iter() {
+            if leaderboard.len() >= max_nb_entries {
+                break;
+            }
+            leaderboard.push(entry);
+        }
+
+        return leaderboard;
+    }
+
+    #[view(getLeaderboardScore)]
+    fn get_leaderboard_score(&self, kind: LeaderboardKind, address: ManagedAddress) -> BigUint {
+
+        // Return the score of the address, whether ranked or not
+        return self.leaderboard_score_mapper(kind, &address).get();
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+    fn increase_leaderboard_score(&self, kind: LeaderboardKind, address: &ManagedAddress, amount: &BigUint) {
+        if *amount == BigUint::zero() {
+            return;
+        }
+
+        self.leaderboard_score_mapper(kind, address).update(|current_score| *current_score += amount);
+        self.update_leaderboard(kind, address, self.leaderboard_score_mapper(kind, address).get());
+    }
+
+    // Keep the leaderboard sorted by decreasing score, bounded to LEADERBOARD_SIZE entries
+    // Scores only increase, so the previous entry of the address can never be ranked before the new one
+    fn update_leaderboard(&self, kind: LeaderboardKind, address: &ManagedAddress, score: BigUint) {
+        let mut new_leaderboard: ManagedVec<LeaderboardEntry<Self::Api>> = ManagedVec::new();
+        let mut inserted: bool = false;
+
+        for entry in self.leaderboard_mapper(kind).get().iter() {
+            if new_leaderboard.len() >= LEADERBOARD_SIZE {
+                break;
+            }
+
+            if inserted == false && score > entry.score {
+                new_leaderboard.push(LeaderboardEntry { address: address.clone(), score: score.clone() });
+                inserted = true;
+
+                if new_leaderboard.len() >= LEADERBOARD_SIZE {
+                    break;
+                }
+            }
+
+            // Previous entry of the address is replaced
+            if entry.address != *address {
+                new_leaderboard.push(entry);
+            }
+        }
+
+        if inserted == false && new_leaderboard.len() < LEADERBOARD_SIZE {
+            new_leaderboard.push(LeaderboardEntry { address: address.clone(), score: score });
+        }
+
+        self.leaderboard_mapper(kind).set(&new_leaderboard);
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Addresses with the highest scores (per leaderboard kind)
+    #[storage_mapper("leaderboard")]
+    fn leaderboard_mapper(&self, kind: LeaderboardKind) -> SingleValueMapper<ManagedVec<LeaderboardEntry<Self::Api>>>;
+
+    // Score (per leaderboard kind & address)
+    #[storage_mapper("leaderboard_score")]
+    fn leaderboard_score_mapper(&self, kind: LeaderboardKind, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
\ No newline at end of file
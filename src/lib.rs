@@ -7,18 +7,40 @@ elrond_wasm::derive_imports!();
 ////////////////////////////////////////////////////////////////////
 // Modules & uses
 ////////////////////////////////////////////////////////////////////
-mod instance;
-mod sponsor;
-mod player;
-mod security;
-mod parameter;
-mod fee;
-mod charity;
-mod event;
+// Modules are public so that Rust tests can call module endpoints & read their storage
+pub mod instance;
+pub mod sponsor;
+pub mod player;
+pub mod security;
+pub mod parameter;
+pub mod fee;
+pub mod referral;
+pub mod jackpot;
+pub mod bond;
+pub mod promo;
+pub mod loyalty;
+pub mod leaderboard;
+pub mod stats;
+pub mod listing;
+pub mod archive;
+pub mod charity;
+pub mod event;
 mod macros;
 
 use instance::*;
 
+// Types shared with the off-chain tools
+pub use instance::{InstanceStatus, SponsorInfo, PrizeInfo, WinnerInfo};
+pub use fee::FeePolicy;
+pub use leaderboard::{LeaderboardKind, LeaderboardEntry};
+pub use listing::{InstanceFilter, InstanceSort, PrizeType};
+pub use archive::ArchiveInfo;
+pub use charity::{CharityInfo, GetCharityStruct};
+pub use bond::BondInfo;
+pub use referral::AffiliateInfo;
+pub use stats::GetStatsStruct;
+pub use event::{EventCategory, EVENT_SCHEMA_VERSION};
+
 ////////////////////////////////////////////////////////////////////
 // Types
 ////////////////////////////////////////////////////////////////////
@@ -31,12 +53,54 @@ pub struct GetInfoStruct<M: ManagedTypeApi> {
     pub number_of_players: usize,
     pub has_played: bool,
     pub has_won: bool,
+    pub has_donated: bool,
     pub winner_info: WinnerInfo<M>,
     pub sponsor_info: SponsorInfo<M>,
     pub prize_info: PrizeInfo<M>,
+    pub fee_policy: FeePolicy<M>,
+    pub link2_reward_percent: u8,
     pub premium: bool,
     pub charity: bool,
+    pub charity_id: u32,
     pub deadline: u64,
+    pub jackpot_pool: BigUint<M>,
+    pub jackpot_won: BigUint<M>,
+    pub pot: BigUint<M>,
+}
+
+// data format for summary view, sponsor information is limited to the address and the truncated pseudo
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, ManagedVecItem)]
+pub struct GetSummaryStruct<M: ManagedTypeApi> {
+    pub iid: u32,
+    pub instance_status: InstanceStatus,
+    pub deadline: u64,
+    pub number_of_players: usize,
+    pub prize_info: PrizeInfo<M>,
+    pub premium: bool,
+    pub charity: bool,
+    pub sponsor_address: ManagedAddress<M>,
+    pub pseudo: ManagedBuffer<M>,
+}
+
+// data format for player history view
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, ManagedVecItem)]
+pub struct GetPlayerHistoryStruct<M: ManagedTypeApi> {
+    pub iid: u32,
+    pub instance_status: InstanceStatus,
+    pub ticket_number: usize,
+    pub fees: BigUint<M>,
+    pub has_won: bool,
+    pub prize_info: PrizeInfo<M>,
+    pub claimed_status: bool,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct GetPlayerTotalsStruct<M: ManagedTypeApi> {
+    pub nb_plays: u32,
+    pub spent: BigUint<M>,
+    pub donations: BigUint<M>,
+    pub nb_wins: u32,
+    pub link_rewards: BigUint<M>,
 }
 
 ////////////////////////////////////////////////////////////////////
@@ -50,6 +114,15 @@ pub trait Prize:
     +security::SecurityModule 
     +parameter::ParameterModule
     +fee::FeeModule
+    +referral::ReferralModule
+    +jackpot::JackpotModule
+    +bond::BondModule
+    +promo::PromoModule
+    +loyalty::LoyaltyModule
+    +leaderboard::LeaderboardModule
+    +stats::StatsModule
+    +listing::ListingModule
+    +archive::ArchiveModule
     +charity::CharityModule
     +event::EventModule {
     
@@ -65,7 +138,15 @@ pub trait Prize:
         const DEFAULT_FEE_AMOUNT_EGLD: u32 = 0;
         const DEFAULT_SPONSOR_REWARD_PERCENT: u8 = 0;
         const DEFAULT_LINK_REWARD_PERCENT: u8 = 0;
+        const DEFAULT_LINK2_REWARD_PERCENT: u8 = 0;
         const DEFAULT_MAX_SPONSOR_INFO_LENGTH: u32 = 1000;
+        const DEFAULT_POT_WINNER_PERCENT: u8 = 50;
+        const DEFAULT_JACKPOT_FEE_PERCENT: u8 = 0;
+        const DEFAULT_JACKPOT_CHANCE: u32 = 0;
+        const DEFAULT_BOND_AMOUNT: u32 = 0;
+        const DEFAULT_LOYALTY_POINTS_PER_EGLD: u64 = 0;
+        const DEFAULT_LOYALTY_POINTS_PER_WIN: u64 = 0;
+        const DEFAULT_LOYALTY_FREE_ENTRY_COST: u64 = 0;
         
         // Initializations @ deployment only 
 
@@ -78,15 +159,37 @@ pub trait Prize:
         self.param_duration_min_mapper().set_if_empty(&DEFAULT_MIN_DURATION);              
         self.param_duration_max_mapper().set_if_empty(&DEFAULT_MAX_DURATION); 
         self.param_sponsor_info_max_length_mapper().set_if_empty(&DEFAULT_MAX_SPONSOR_INFO_LENGTH);
+        self.param_pot_winner_percent_mapper().set_if_empty(&DEFAULT_POT_WINNER_PERCENT);
 
         // Fees
-        self.init_fees_if_empty(BigUint::from(DEFAULT_FEE_AMOUNT_EGLD), DEFAULT_SPONSOR_REWARD_PERCENT, DEFAULT_LINK_REWARD_PERCENT);
+        self.init_fees_if_empty(BigUint::from(DEFAULT_FEE_AMOUNT_EGLD), DEFAULT_SPONSOR_REWARD_PERCENT, DEFAULT_LINK_REWARD_PERCENT, DEFAULT_LINK2_REWARD_PERCENT);
 
         // Charity
         self.init_donations_if_empty();
 
+        // Jackpot
+        self.init_jackpot_if_empty(DEFAULT_JACKPOT_FEE_PERCENT, DEFAULT_JACKPOT_CHANCE);
+
+        // Sponsor bond
+        self.init_bond_policy_if_empty(TokenIdentifier::egld(), BigUint::from(DEFAULT_BOND_AMOUNT));
+
+        // Loyalty
+        self.init_loyalty_if_empty(DEFAULT_LOYALTY_POINTS_PER_EGLD, DEFAULT_LOYALTY_POINTS_PER_WIN, DEFAULT_LOYALTY_FREE_ENTRY_COST);
+
         // Event
         self.log_enable_mapper().set_if_empty(&false);
+        self.init_log_categories_if_empty();
+
+        // Statistics, plays of instances existing at upgrade are counted by the migration
+        self.init_stats_if_empty(self.iid_counter_mapper().get(), 0);
+
+        // Secondary indexes of instances existing at upgrade are built by batches with the migrateIndexes endpoint
+        if self.indexes_built_mapper().get() == false && self.migration_next_iid_mapper().is_empty() {
+            self.migration_next_iid_mapper().set(&1u32);
+            self.migration_last_iid_mapper().set(&self.iid_counter_mapper().get());
+            self.migration_fee_policy_mapper().set(&self.fee_policy_mapper().get());
+            self.indexes_built_mapper().set(&(self.iid_counter_mapper().get() == 0));
+        }
 
         Ok(())
     }
@@ -94,14 +197,57 @@ pub trait Prize:
     /////////////////////////////////////////////////////////////////////
     // Administrator endpoints
     /////////////////////////////////////////////////////////////////////
+    #[only_owner]
+    #[endpoint(migrateIndexes)]
+    // Build the secondary indexes, play statistics & fee policies of up to <nb_instances> instances existing at upgrade, from the last migrated IID
+    // Instances existing at upgrade can be played once migrated
+    // Sponsor & player indexes are incomplete until the migration is complete
+    // Returns : Result, optional (next IID to migrate, 0 when the migration is complete)
+    fn migrate_indexes(&self, nb_instances: u32) -> MultiValue2<SCResult<()>, OptionalValue<u32>> {
+        require_with_opt!(self.indexes_built_mapper().get() == false, "Indexes already built");
+        require_with_opt!(nb_instances > 0, "Number of instances must be positive");
+
+        let last_iid: u32 = self.migration_last_iid_mapper().get();
+        let first_iid: u32 = self.migration_next_iid_mapper().get();
+        let batch_end_iid: u32 = first_iid.saturating_add(nb_instances);
+        let mut iid: u32 = first_iid;
+        let mut nb_plays: u32 = 0;
+
+        // Instances cleaned before upgrade are skipped
+        while iid <= last_iid && iid < batch_end_iid {
+            if self.instance_info_mapper().contains_key(&iid) {
+                self.index_instance(iid);
+                nb_plays += self.get_nb_players(iid) as u32;
+            }
+            iid += 1;
+        }
+
+        self.stats_nb_plays_mapper().update(|current_nb_plays| *current_nb_plays += nb_plays);
+        self.migration_next_iid_mapper().set(&iid);
+
+        // Log event
+        self.event_wrapper_migrate_indexes(first_iid, iid, nb_plays, iid > last_iid);
+
+        if iid > last_iid {
+            self.indexes_built_mapper().set(&true);
+            self.migration_fee_policy_mapper().clear();
+            Ok_some!(0u32);
+        }
+
+        Ok_some!(iid);
+    }
+
     #[only_owner]
     #[endpoint(cleanClaimed)]
     fn clean_claimed_instances(&self, #[var_args] iids: MultiValueManagedVec<u32>) -> SCResult<()> {   
+        // Plays of cleaned instances would not be counted anymore by the migration
+        require!(self.indexes_built_mapper().get() == true, "Indexes migration in progress");
+
         let claimed_instances: MultiValueManagedVec<u32>;
 
         if iids.len() == 0 {
             // Find all claimed instances if no IID is provided
-            claimed_instances = self.get_instance_ids(MultiValueManagedVec::from_single_item(InstanceStatus::Claimed));
+            claimed_instances = self.get_all_instance_ids(MultiValueManagedVec::from_single_item(InstanceStatus::Claimed));
         }
         else {
             // Use provided IIDs otherwise
@@ -112,86 +258,145 @@ pub trait Prize:
 
             if self.get_instance_status(iid) == InstanceStatus::Claimed {
 
+                // Keep a compact record of the instance before purging it
+                let instance_info = self.instance_info_mapper().get(&iid).unwrap();
+                let winner_info = self.instance_state_mapper().get(&iid).unwrap().winner_info;
+                let archive_info = ArchiveInfo {
+                    iid: iid,
+                    sponsor_address: instance_info.sponsor_info.address.clone(),
+                    prize_info: self.get_prize_info(iid),
+                    winner_address: winner_info.address,
+                    ticket_number: winner_info.ticket_number,
+                    number_of_players: self.get_nb_players(iid),
+                    trigger_timestamp: self.instance_trigger_timestamp_mapper(iid).get(),
+                    charity: instance_info.charity,
+                };
+
+                // Log event, before the instance data is purged
+                self.event_wrapper_clean_claim(
+                    iid.clone(),
+                    &archive_info.sponsor_address,
+                    &archive_info.winner_address,
+                    archive_info.ticket_number,
+                    archive_info.number_of_players,
+                    &archive_info.prize_info.token_identifier,
+                    archive_info.prize_info.token_nonce,
+                    &archive_info.prize_info.token_amount
+                );
+
+                self.record_archive(archive_info);
+
+                self.remove_sponsor_instance(&instance_info.sponsor_info.address, iid);
                 self.clear_players(iid.clone());
                 self.instance_info_mapper().remove(&iid);
                 self.instance_state_mapper().remove(&iid);
+                self.instance_fee_policy_mapper().remove(&iid);
+                self.instance_link2_reward_percent_mapper(iid).clear();
+                self.instance_charity_id_mapper(iid).clear();
+                self.instance_jackpot_won_mapper(iid).clear();
+                self.instance_pot_winner_percent_mapper(iid).clear();
+                self.instance_pot_mapper(iid).clear();
+                self.instance_bond_slashed_mapper(iid).clear();
+                self.instance_bond_locked_mapper(iid).clear();
+                self.instance_trigger_timestamp_mapper(iid).clear();
+                self.clear_promo_codes(iid);
+                self.refresh_status_index(iid);
+            }
+        }
+
+        Ok(())
+    }  
+
+    #[only_owner]
+    #[endpoint(migrateFeePol)]
+    fn migrate_fee_policy(&self, #[var_args] iids: MultiValueManagedVec<u32>) -> SCResult<()> {
+        let running_instances: MultiValueManagedVec<u32>;
+
+        if iids.len() == 0 {
+            // Status index is complete once migrated
+            require!(self.indexes_built_mapper().get() == true, "Indexes migration in progress");
+
+            // Find all running instances if no IID is provided
+            running_instances = self.get_all_instance_ids(MultiValueManagedVec::from_single_item(InstanceStatus::Running));
+        }
+        else {
+            // Use provided IIDs otherwise
+            running_instances = iids;
+        }
+
+        for iid in running_instances.iter() {
+
+            // Only running instances can still receive fees
+            if self.get_instance_status(iid) == InstanceStatus::Running {
+
+                // Apply the current fee policy to the instance
+                let fee_policy = self.fee_policy_mapper().get();
+                let link2_reward_percent: u8 = self.link2_reward_percent_mapper().get();
+                let mut instance_state = self.instance_state_mapper().get(&iid).unwrap();
+                instance_state.reward_info.percent = fee_policy.sponsor_reward_percent;
+                self.instance_state_mapper().insert(iid, instance_state);
 
                 // Log event
-                self.event_wrapper_clean_claim(iid.clone());
+                self.event_wrapper_migrate_fee_policy(iid, &fee_policy.fee_amount_egld, fee_policy.sponsor_reward_percent, fee_policy.link_reward_percent, link2_reward_percent);
+
+                self.instance_fee_policy_mapper().insert(iid, fee_policy);
+                self.instance_link2_reward_percent_mapper(iid).set(&link2_reward_percent);
             }
         }
 
         Ok(())
-    }  
+    }
 
     /////////////////////////////////////////////////////////////////////
     // DApp endpoints : sponsor API
     /////////////////////////////////////////////////////////////////////
     #[payable("*")]
     #[endpoint(create)]
-    fn create_instance(&self, #[payment_token] token_identifier: TokenIdentifier, #[payment_nonce] token_nonce: u64, #[payment_amount] token_amount: BigUint, duration_in_s: u64, pseudo: ManagedBuffer, url1: ManagedBuffer, url2: ManagedBuffer, url3: ManagedBuffer, reserved: ManagedBuffer, graphic: ManagedBuffer, logo_link: ManagedBuffer, free_text: ManagedBuffer, premium: bool, charity: bool) -> MultiValue2<SCResult<()>, OptionalValue<u32>> {
-        
-        let caller = self.blockchain().get_caller();
-        let sponsor_info_length = (pseudo.len() + url1.len() + url2.len() + url3.len() + reserved.len() + graphic.len() + logo_link.len() + free_text.len()) as u32;
-        
-        self.nb_instances_running_mapper(caller.clone()).set_if_empty(&0u32);
-        
+    fn create_instance(&self, #[payment_token] token_identifier: TokenIdentifier, #[payment_nonce] token_nonce: u64, #[payment_amount] token_amount: BigUint, duration_in_s: u64, pseudo: ManagedBuffer, url1: ManagedBuffer, url2: ManagedBuffer, url3: ManagedBuffer, reserved: ManagedBuffer, graphic: ManagedBuffer, logo_link: ManagedBuffer, free_text: ManagedBuffer, premium: bool, charity_id: u32) -> MultiValue2<SCResult<()>, OptionalValue<u32>> {
+
         // Check validity of parameters
-        require_with_opt!(self.address_blacklist_set_mapper().contains(&caller) == false, "Caller blacklisted");
-        require_with_opt!(self.nb_instances_running_mapper(caller.clone()).get() < self.get_param_nb_max_instances_per_sponsor(), "Max instances reached for this sponsor");
-        require_with_opt!(duration_in_s >= self.param_duration_min_mapper().get(), "Duration out of allowed range");
-        require_with_opt!(duration_in_s <= self.param_duration_max_mapper().get(), "Duration out of allowed range");
         require_with_opt!(token_amount > 0, "Prize cannot be null");
-        require_with_opt!(premium == false, "Premium is not allowed");
-        require_with_opt!(sponsor_info_length <= self.param_sponsor_info_max_length_mapper().get(), "Sponsor info length out of range");
 
-        // Compute next iid
-        let new_iid = self.iid_counter_mapper().get() + 1;
-
-        // Aggregate instance information
-        let instance_info = InstanceInfo {
-            sponsor_info: SponsorInfo {
-                address: caller.clone(),
-                pseudo: pseudo.clone(),
-                url1: url1.clone(),
-                url2: url2.clone(),
-                url3: url3.clone(),
-                reserved: reserved.clone(),
-                graphic: graphic.clone(),
-                logo_link: logo_link.clone(),
-                free_text: free_text.clone()},
-            prize_info: PrizeInfo {
-                token_identifier: token_identifier.clone(),
+        // Create instance with escrowed prize
+        return self.func_create_instance(
+            SponsorInfo {
+                address: self.blockchain().get_caller(),
+                pseudo: pseudo,
+                url1: url1,
+                url2: url2,
+                url3: url3,
+                reserved: reserved,
+                graphic: graphic,
+                logo_link: logo_link,
+                free_text: free_text},
+            PrizeInfo {
+                token_identifier: token_identifier,
                 token_nonce: token_nonce,
-                token_amount: token_amount.clone()},
-            premium: premium,
-            charity: charity,
-            deadline: self.blockchain().get_block_timestamp() + duration_in_s
-        };
-
-        // Initialize instance state
-        let instance_state = InstanceState {
-            claimed_status: false,
-            reward_info: RewardInfo {
-                percent: self.fee_policy_mapper().get().sponsor_reward_percent,
-                pool: BigUint::zero()},
-            winner_info: WinnerInfo {
-                ticket_number: 0usize,
-                address: ManagedAddress::zero()},
-            disabled: false,
-        };
-
-        // Record new instance
-        self.iid_counter_mapper().set(&new_iid);
-        self.instance_info_mapper().insert(new_iid, instance_info);
-        self.instance_state_mapper().insert(new_iid, instance_state);
-        self.nb_instances_running_mapper(caller.clone()).update(|current| *current += 1);
-
-        // Log event
-        self.event_wrapper_create_instance(&caller, new_iid, &token_identifier, token_nonce, &token_amount, duration_in_s, &pseudo);
+                token_amount: token_amount},
+            0u8, duration_in_s, premium, charity_id);
+    }
 
-        // Format result
-        Ok_some!(new_iid);
+    #[endpoint(createPot)]
+    // Instance without escrowed prize, the winner receives a share of the fees paid by players (ticket pot)
+    fn create_pot_instance(&self, duration_in_s: u64, pseudo: ManagedBuffer, url1: ManagedBuffer, url2: ManagedBuffer, url3: ManagedBuffer, reserved: ManagedBuffer, graphic: ManagedBuffer, logo_link: ManagedBuffer, free_text: ManagedBuffer, premium: bool, charity_id: u32) -> MultiValue2<SCResult<()>, OptionalValue<u32>> {
+
+        // Create instance with EGLD prize funded by the ticket pot
+        return self.func_create_instance(
+            SponsorInfo {
+                address: self.blockchain().get_caller(),
+                pseudo: pseudo,
+                url1: url1,
+                url2: url2,
+                url3: url3,
+                reserved: reserved,
+                graphic: graphic,
+                logo_link: logo_link,
+                free_text: free_text},
+            PrizeInfo {
+                token_identifier: TokenIdentifier::egld(),
+                token_nonce: 0u64,
+                token_amount: BigUint::zero()},
+            self.param_pot_winner_percent_mapper().get(), duration_in_s, premium, charity_id);
     }
 
     #[endpoint(prize)]
@@ -208,8 +413,8 @@ pub trait Prize:
         require!(caller == instance_info.sponsor_info.address.clone() || caller == self.blockchain().get_owner_address(), "Bad caller");
 
         if instance_info.charity == true {
-            // Add sponsor rewards to charity pool
-            self.charity_pool_mapper().update(|current_donations| *current_donations += instance_state.reward_info.pool.clone());
+            // Donate sponsor rewards to the instance charity
+            self.add_donation(iid.clone(), &instance_state.reward_info.pool);
         } 
         else {
             // Send rewards to sponsor
@@ -229,18 +434,29 @@ pub trait Prize:
             let winning_ticket = rand.next_usize_in_range(1, nb_players + 1);
             instance_state.winner_info.ticket_number = winning_ticket.clone();
             instance_state.winner_info.address = self.get_ticket_owner(iid.clone(), winning_ticket);
+
+            // Give a chance to the winner to also win the jackpot, same randomness source is used for both draws
+            self.draw_jackpot(iid.clone(), &instance_state.winner_info.address, &mut rand);
+
+            // Award loyalty points to the winner
+            self.award_win_points(&instance_state.winner_info.address);
+
+            // Update player lifetime totals & leaderboard
+            self.record_player_win(&instance_state.winner_info.address);
+            self.increase_leaderboard_score(LeaderboardKind::PlayerWins, &instance_state.winner_info.address, &BigUint::from(1u32));
         }
 
         // Auto-distribution of prize if enabled
         if self.param_manual_claim_mapper().get() == false {
             // Send prize to winner address
-            self.func_send_prize(&instance_info.prize_info, &instance_state.winner_info.address);
+            self.func_send_prize(iid.clone(), &self.get_prize_info(iid.clone()), &instance_state.winner_info);
 
-            // Update claimed status
+            // Update claimed status, sponsor bond is released
             instance_state.claimed_status = true;
+            self.release_instance_bond(iid.clone());
 
             // Log event
-            self.event_wrapper_auto_claim_prize(iid.clone());
+            self.event_wrapper_auto_claim_prize(iid.clone(), &instance_state.winner_info.address);
         }
         
         // Log event
@@ -248,6 +464,8 @@ pub trait Prize:
         
         // Record new instance state
         self.instance_state_mapper().insert(iid.clone(), instance_state);   
+        self.instance_trigger_timestamp_mapper(iid).set(&self.blockchain().get_block_timestamp());
+        self.refresh_status_index(iid);
 
         // Update nb of running instances for the sponsor
         self.nb_instances_running_mapper(instance_info.sponsor_info.address).update(|current| *current -= 1);
@@ -261,28 +479,33 @@ pub trait Prize:
     #[payable("EGLD")]
     #[endpoint(play)]
     // Returns : Result, optional (ticket number)  
-    fn play(&self, #[payment] fees: BigUint, iid: u32, #[var_args] link_address: OptionalValue<ManagedAddress>) -> MultiValue2<SCResult<()>, OptionalValue<usize>> {
+    fn play(&self, #[payment] payment: BigUint, iid: u32, #[var_args] link_address: OptionalValue<ManagedAddress>) -> MultiValue2<SCResult<()>, OptionalValue<usize>> {
+        return self.func_play(payment, iid, link_address.into_option(), BigUint::zero());
+    }
 
-        // Checks
-        let caller = self.blockchain().get_caller();
-        require_with_opt!(self.address_blacklist_set_mapper().contains(&caller) == false, "Caller blacklisted");
-        require_with_opt!(self.get_instance_status(iid) == InstanceStatus::Running, "Instance is not active");
-        require_with_opt!(self.has_played(iid, caller.clone()) == false, "Player has already played");
-        require_with_opt!(fees == self.fee_policy_mapper().get().fee_amount_egld, "Wrong fees amount");
+    #[payable("EGLD")]
+    #[endpoint(playWithCode)]
+    // Returns : Result, optional (ticket number)  
+    fn play_with_code(&self, #[payment] payment: BigUint, iid: u32, promo_code: ManagedBuffer, #[var_args] link_address: OptionalValue<ManagedAddress>) -> MultiValue2<SCResult<()>, OptionalValue<usize>> {
 
-        // Capitalize fees, sponsor rewards and optional link rewards
-        let mut instance_state = self.instance_state_mapper().get(&iid).unwrap();
-        instance_state.reward_info.pool += self.update_fees_and_compute_rewards(fees.clone(), instance_state.reward_info.percent, link_address.into_option());
-        self.event_wrapper_reward_pool_info(iid, &instance_state.reward_info.pool); 
-        self.instance_state_mapper().insert(iid, instance_state);
-        
-        // Add caller address to participants for this instance
-        let ticket_number: usize = self.add_player(iid, &caller);
+        // Promo code is invalidated once used
+        let discount: Option<BigUint> = self.use_promo_code(iid, &promo_code);
+        require_with_opt!(discount.is_some(), "Invalid promo code");
 
-        // Log event
-        self.event_wrapper_play(&caller, iid, ticket_number, &fees);
+        return self.func_play(payment, iid, link_address.into_option(), discount.unwrap());
+    }
 
-        Ok_some!(ticket_number);
+    #[payable("EGLD")]
+    #[endpoint(playWithPoints)]
+    // Returns : Result, optional (ticket number)  
+    fn play_with_points(&self, #[payment] payment: BigUint, iid: u32, #[var_args] link_address: OptionalValue<ManagedAddress>) -> MultiValue2<SCResult<()>, OptionalValue<usize>> {
+        require_with_opt!(self.get_instance_status(iid) == InstanceStatus::Running, "Instance is not active");
+        require_with_opt!(self.is_instance_migrated(iid) == true, "Instance migration in progress");
+
+        // Loyalty points are spent for a free entry
+        require_with_opt!(self.redeem_free_entry(&self.blockchain().get_caller()), "Not enough loyalty points");
+
+        return self.func_play(payment, iid, link_address.into_option(), self.get_instance_fee_policy(iid).fee_amount_egld);
     }
 
     #[endpoint(claimPrize)]
@@ -293,18 +516,20 @@ pub trait Prize:
         require!(self.blockchain().get_caller() == self.instance_state_mapper().get(&iid).unwrap().winner_info.address, "Prize can only be claimed by the winner");
 
         // Get prize info & instance state
-        let prize_info = self.instance_info_mapper().get(&iid).unwrap().prize_info;
+        let prize_info = self.get_prize_info(iid);
         let mut instance_state = self.instance_state_mapper().get(&iid).unwrap();
 
         // Send prize to winner address
-        self.func_send_prize(&prize_info, &instance_state.winner_info.address);
+        self.func_send_prize(iid, &prize_info, &instance_state.winner_info);
 
-        // Update claimed status
+        // Update claimed status, sponsor bond is released
         instance_state.claimed_status = true;
         self.instance_state_mapper().insert(iid, instance_state);
+        self.release_instance_bond(iid);
+        self.refresh_status_index(iid);
 
         // Log event
-        self.event_wrapper_manual_claim_prize(iid);
+        self.event_wrapper_manual_claim_prize(iid, &self.blockchain().get_caller());
 
         Ok(())
     }
@@ -317,6 +542,7 @@ pub trait Prize:
     fn get_instance_info(&self, iid: u32, player_address: ManagedAddress) -> MultiValue2<SCResult<()>, OptionalValue<GetInfoStruct<Self::Api>>> {
         //Checks
         require_with_opt!(self.get_instance_status(iid) != InstanceStatus::NotExisting, "Instance does not exist");
+        require_with_opt!(self.is_instance_migrated(iid) == true, "Instance migration in progress");
 
         // Instance information
         let instance_info = self.instance_info_mapper().get(&iid).unwrap();
@@ -327,10 +553,12 @@ pub trait Prize:
         // Played & won statuses
         let mut has_played: bool = false;
         let mut has_won: bool = false;
+        let mut has_donated: bool = false;
 
         if player_address.clone().is_zero() == false {
             has_played = self.has_played(iid, player_address.clone());
             has_won = if player_address == winner_info.address {true} else {false};
+            has_donated = self.has_donated(iid, player_address.clone());
         }
 
         // Return filled structure
@@ -340,97 +568,417 @@ pub trait Prize:
             number_of_players: self.get_nb_players(iid),
             has_played: has_played,
             has_won: has_won,
+            has_donated: has_donated,
             winner_info: winner_info,
             sponsor_info: instance_info.sponsor_info,
-            prize_info: instance_info.prize_info,
+            prize_info: self.get_prize_info(iid),
+            fee_policy: self.get_instance_fee_policy(iid),
+            link2_reward_percent: self.instance_link2_reward_percent_mapper(iid).get(),
             premium: instance_info.premium,
             charity: instance_info.charity,
-            deadline: instance_info.deadline})
+            charity_id: self.instance_charity_id_mapper(iid).get(),
+            deadline: instance_info.deadline,
+            jackpot_pool: self.jackpot_pool_mapper().get(),
+            jackpot_won: self.instance_jackpot_won_mapper(iid).get(),
+            pot: self.instance_pot_mapper(iid).get()})
     }   
             
+    #[view(getSummary)]
+    fn get_instance_summary(&self, iid: u32) -> MultiValue2<SCResult<()>, OptionalValue<GetSummaryStruct<Self::Api>>> {
+        const SUMMARY_PSEUDO_MAX_LENGTH: usize = 32;
+
+        //Checks
+        require_with_opt!(self.get_instance_status(iid) != InstanceStatus::NotExisting, "Instance does not exist");
+
+        // Instance information
+        let instance_info = self.instance_info_mapper().get(&iid).unwrap();
+
+        // Truncate pseudo, on a UTF-8 character boundary
+        let mut pseudo = instance_info.sponsor_info.pseudo;
+        if pseudo.len() > SUMMARY_PSEUDO_MAX_LENGTH {
+            let mut pseudo_bytes = [0u8; SUMMARY_PSEUDO_MAX_LENGTH + 1];
+            let _ = pseudo.load_slice(0, &mut pseudo_bytes);
+
+            // Continuation bytes (0b10xxxxxx) belong to the character started before them
+            let mut cut: usize = SUMMARY_PSEUDO_MAX_LENGTH;
+            while cut > 0 && (pseudo_bytes[cut] & 0xC0) == 0x80 {
+                cut -= 1;
+            }
+            pseudo = pseudo.copy_slice(0, cut).unwrap();
+        }
+
+        // Return filled structure
+        Ok_some!(GetSummaryStruct {
+            iid: iid,
+            instance_status: self.get_instance_status(iid),
+            deadline: instance_info.deadline,
+            number_of_players: self.get_nb_players(iid),
+            prize_info: self.get_prize_info(iid),
+            premium: instance_info.premium,
+            charity: instance_info.charity,
+            sponsor_address: instance_info.sponsor_info.address,
+            pseudo: pseudo})
+    }
+
+    #[view(getAllSummary)]
+    // Returns : 
+    //  - next cursor to provide to get the following page, 0 when all instances have been returned, followed by
+    //  - summary of up to <limit> filtered instances from <cursor> (0 to start from the first instance), in getIDs order
+    fn get_all_instance_summary(&self, cursor: u32, limit: u32, #[var_args] status_filter: MultiValueManagedVec<InstanceStatus>) -> MultiValue2<u32, MultiValueManagedVec<GetSummaryStruct<Self::Api>>> {
+
+        let mut instances: MultiValueManagedVec<GetSummaryStruct<Self::Api>> = MultiValueManagedVec::new();
+        let (next_cursor, instance_ids) = self.get_instance_ids(cursor, limit, status_filter).0;
+
+        for iid in instance_ids.iter() {
+            instances.push(self.get_instance_summary(iid).0.1.into_option().unwrap());
+        }
+
+        return MultiValue2((next_cursor, instances));
+    }
+
+    #[view(getSponsorInfo)]
+    // Detail view of the sponsor metadata, not part of the summary view
+    fn get_sponsor_info(&self, iid: u32) -> MultiValue2<SCResult<()>, OptionalValue<SponsorInfo<Self::Api>>> {
+        require_with_opt!(self.get_instance_status(iid) != InstanceStatus::NotExisting, "Instance does not exist");
+
+        Ok_some!(self.instance_info_mapper().get(&iid).unwrap().sponsor_info);
+    }
+
     #[view(getAllInfo)]
-    // Returns : total number of filtered instances followed by information of all filtered instances
-    fn get_all_instance_info(&self, player_address: ManagedAddress, #[var_args] status_filter: MultiValueManagedVec<InstanceStatus>) -> MultiValue2<usize, MultiValueManagedVec<GetInfoStruct<Self::Api>>> {
+    // Returns : 
+    //  - next cursor to provide to get the following page, 0 when all instances have been returned, followed by
+    //  - information of up to <limit> filtered instances from <cursor> (0 to start from the first instance), in getIDs order
+    fn get_all_instance_info(&self, player_address: ManagedAddress, cursor: u32, limit: u32, #[var_args] status_filter: MultiValueManagedVec<InstanceStatus>) -> MultiValue2<u32, MultiValueManagedVec<GetInfoStruct<Self::Api>>> {
 
         let mut instances: MultiValueManagedVec<GetInfoStruct<Self::Api>> = MultiValueManagedVec::new();
-        // let mut status_filter_vec = status_filter.clone().into_vec().into_vec();
-
-        // Ensure at least one status is provided as filter, check also overflow regarding the maximum possible values for status
-        if status_filter.len() >= 1 && status_filter.len() <= InstanceStatus::VARIANT_COUNT {
-
-            // Remove duplicates
-            // status_filter_vec.sort();
-            // status_filter_vec.dedup();
-
-            // Return all instances IDs which meet the status filter provided in parameter
-            for iid in self.instance_info_mapper().keys() {
-                for status in status_filter.iter() {
-                    if self.get_instance_status(iid) == status.clone() {
-                        instances.push(self.get_instance_info(iid, player_address.clone()).0.1.into_option().unwrap());
-                        break;
-                    }   
-                }
-            }
+        let (next_cursor, instance_ids) = self.get_instance_ids(cursor, limit, status_filter).0;
+
+        // Return information of instances which meet the status filter provided in parameter
+        for iid in instance_ids.iter() {
+            instances.push(self.get_instance_info(iid, player_address.clone()).0.1.into_option().unwrap());
         }
 
-        return MultiValue2((instances.len(), instances));
+        return MultiValue2((next_cursor, instances));
     }
 
     #[view(getAllInfoFrag)]
+    // Deprecated : kept for existing clients, use getAllInfo which returns the cursor of the next page
     // Returns : 
     //  - boolean indicating if the last filtered iid is part of the return instances, followed by
     //  - information of up to <max_nb_instances_returned> filtered instances from <iid_start> 
     fn get_all_instance_info_frag(&self, player_address: ManagedAddress, iid_start: u32, max_nb_instances_returned: u32, #[var_args] status_filter: MultiValueManagedVec<InstanceStatus>) -> MultiValue2<bool, MultiValueManagedVec<GetInfoStruct<Self::Api>>> {
 
         let mut instances: MultiValueManagedVec<GetInfoStruct<Self::Api>> = MultiValueManagedVec::new();
-        let mut instance_counter: u32 = 0;
-        let mut current_filtered_iid: u32 = iid_start;
-        let mut last_filtered_iid_returned: u32 = iid_start;
-        
-
-        // Ensure at least one status is provided as filter, check also overflow regarding the maximum possible values for status
-        if status_filter.len() >= 1 && status_filter.len() <= InstanceStatus::VARIANT_COUNT {
-
-            // Return all instances IDs which meet the status filter provided in parameter
-            for iid in self.instance_info_mapper().keys() {
-                if iid >= iid_start {
-                    for status in status_filter.iter() {
-                        if self.get_instance_status(iid) == status.clone() {
-                            
-                            // Track the current filtered iid
-                            current_filtered_iid = iid;
-
-                            if instance_counter < max_nb_instances_returned {
-                                instances.push(self.get_instance_info(iid, player_address.clone()).0.1.into_option().unwrap());
-                                instance_counter += 1;
-
-                                // Record the last 
-                                last_filtered_iid_returned = iid;
-                            }
-
-                            break;
-                        }   
-                    }
+        let mut is_last_returned: bool = false;
+
+        // Existing instances are scanned in IID order from <iid_start>, cursors of getAllInfo are not IIDs
+        // Instances existing at upgrade are returned once migrated, like with getAllInfo
+        for iid in self.instance_info_mapper().keys() {
+            if iid >= iid_start && self.is_instance_migrated(iid) == true && self.is_instance_in_status_filter(iid, &status_filter) == true {
+                is_last_returned = (instances.len() as u32) < max_nb_instances_returned;
+                if is_last_returned == true {
+                    instances.push(self.get_instance_info(iid, player_address.clone()).0.1.into_option().unwrap());
                 }
             }
         }
 
-        return MultiValue2(((instance_counter > 0) && (current_filtered_iid == last_filtered_iid_returned), instances));
+        return MultiValue2((instances.len() > 0 && is_last_returned == true, instances));
+    }
+
+    #[view(getInstances)]
+    // Returns : 
+    //  - next cursor to provide to get the following page, 0 when all instances have been returned, followed by
+    //  - information of up to <limit> instances meeting the filter, sorted as requested, from position <cursor> (0 for the first page)
+    // Limit must be between 1 and 50
+    fn get_instances(&self, player_address: ManagedAddress, filter: InstanceFilter<Self::Api>, sort: InstanceSort, cursor: u32, limit: u32) -> MultiValue2<u32, MultiValueManagedVec<GetInfoStruct<Self::Api>>> {
+
+        let mut instances: MultiValueManagedVec<GetInfoStruct<Self::Api>> = MultiValueManagedVec::new();
+        let (next_cursor, instance_ids) = self.get_listing_page(&filter, sort, cursor, limit);
+
+        for iid in instance_ids.iter() {
+            instances.push(self.get_instance_info(iid, player_address.clone()).0.1.into_option().unwrap());
+        }
+
+        return MultiValue2((next_cursor, instances));
+    }
+
+    #[view(getPlayerHistory)]
+    // Returns : 
+    //  - next cursor to provide to get the following page, 0 when all instances have been returned, followed by
+    //  - lifetime totals of the player, kept after instance cleaning, followed by
+    //  - history of up to <limit> instances played from <cursor> (0 to start from the first instance), in play order
+    fn get_player_history(&self, player_address: ManagedAddress, cursor: u32, limit: u32) -> MultiValue3<u32, GetPlayerTotalsStruct<Self::Api>, MultiValueManagedVec<GetPlayerHistoryStruct<Self::Api>>> {
+
+        let mut history: MultiValueManagedVec<GetPlayerHistoryStruct<Self::Api>> = MultiValueManagedVec::new();
+        let (next_cursor, player_iids) = self.get_player_instances(player_address.clone(), cursor, limit).0;
+
+        for iid in player_iids.iter() {
+            let instance_state = self.instance_state_mapper().get(&iid).unwrap();
+
+            history.push(GetPlayerHistoryStruct {
+                iid: iid,
+                instance_status: self.get_instance_status(iid),
+                ticket_number: self.instance_player_ticket_mapper(iid, &player_address).get(),
+                fees: self.instance_player_fees_mapper(iid, &player_address).get(),
+                has_won: instance_state.winner_info.address == player_address,
+                prize_info: self.get_prize_info(iid),
+                claimed_status: instance_state.claimed_status,
+            });
+        }
+
+        // Lifetime totals
+        let totals = GetPlayerTotalsStruct {
+            nb_plays: self.player_nb_plays_mapper(&player_address).get(),
+            spent: self.player_spent_mapper(&player_address).get(),
+            donations: self.player_donations_mapper(&player_address).get(),
+            nb_wins: self.player_nb_wins_mapper(&player_address).get(),
+            link_rewards: self.referral_rewards_earned_mapper(&player_address, 1u8).get() + self.referral_rewards_earned_mapper(&player_address, 2u8).get(),
+        };
+
+        return MultiValue3((next_cursor, totals, history));
     }
 
     /////////////////////////////////////////////////////////////////////
     // Internal SC functions
     /////////////////////////////////////////////////////////////////////
-    fn func_send_prize(&self, prize_info: &PrizeInfo<Self::Api>, winner_address: &ManagedAddress) {
+    fn func_create_instance(&self, sponsor_info: SponsorInfo<Self::Api>, prize_info: PrizeInfo<Self::Api>, pot_winner_percent: u8, duration_in_s: u64, premium: bool, charity_id: u32) -> MultiValue2<SCResult<()>, OptionalValue<u32>> {
+
+        let caller = sponsor_info.address.clone();
+        let sponsor_info_length = (sponsor_info.pseudo.len() + sponsor_info.url1.len() + sponsor_info.url2.len() + sponsor_info.url3.len() + sponsor_info.reserved.len() + sponsor_info.graphic.len() + sponsor_info.logo_link.len() + sponsor_info.free_text.len()) as u32;
+        
+        self.nb_instances_running_mapper(caller.clone()).set_if_empty(&0u32);
+        
+        // Check validity of parameters
+        require_with_opt!(self.address_blacklist_set_mapper().contains(&caller) == false, "Caller blacklisted");
+        require_with_opt!(self.nb_instances_running_mapper(caller.clone()).get() < self.get_param_nb_max_instances_per_sponsor(), "Max instances reached for this sponsor");
+        require_with_opt!(self.has_required_bond(&caller), "Sponsor bond required");
+        require_with_opt!(duration_in_s >= self.param_duration_min_mapper().get(), "Duration out of allowed range");
+        require_with_opt!(duration_in_s <= self.param_duration_max_mapper().get(), "Duration out of allowed range");
+        require_with_opt!(premium == false, "Premium is not allowed");
+        require_with_opt!(charity_id == 0 || self.is_charity_enabled(charity_id), "Charity not available");
+        require_with_opt!(sponsor_info_length <= self.param_sponsor_info_max_length_mapper().get(), "Sponsor info length out of range");
+
+        // Compute next iid
+        let new_iid = self.iid_counter_mapper().get() + 1;
+
+        // Snapshot the current fee policy, applicable for the whole instance lifecycle
+        let fee_policy = self.fee_policy_mapper().get();
+
+        // Log event
+        self.event_wrapper_create_instance(&caller, new_iid, &prize_info.token_identifier, prize_info.token_nonce, &prize_info.token_amount, duration_in_s, &sponsor_info.pseudo, charity_id, premium);
+
+        // Aggregate instance information
+        let instance_info = InstanceInfo {
+            sponsor_info: sponsor_info,
+            prize_info: prize_info,
+            premium: premium,
+            charity: charity_id != 0,
+            deadline: self.blockchain().get_block_timestamp() + duration_in_s
+        };
+
+        // Initialize instance state
+        let instance_state = InstanceState {
+            claimed_status: false,
+            reward_info: RewardInfo {
+                percent: fee_policy.sponsor_reward_percent,
+                pool: BigUint::zero()},
+            winner_info: WinnerInfo {
+                ticket_number: 0usize,
+                address: ManagedAddress::zero()},
+            disabled: false,
+        };
+
+        // Record new instance
+        self.iid_counter_mapper().set(&new_iid);
+        self.instance_info_mapper().insert(new_iid, instance_info);
+        self.instance_state_mapper().insert(new_iid, instance_state);
+        self.instance_fee_policy_mapper().insert(new_iid, fee_policy);
+        self.instance_link2_reward_percent_mapper(new_iid).set(&self.link2_reward_percent_mapper().get());
+        self.instance_charity_id_mapper(new_iid).set(&charity_id);
+        self.nb_instances_running_mapper(caller.clone()).update(|current| *current += 1);
+        self.lock_instance_bond(new_iid, &caller);
+
+        self.instance_pot_winner_percent_mapper(new_iid).set(&pot_winner_percent);
+
+        // Update statistics
+        self.record_instance_created();
+
+        // Update secondary indexes
+        self.add_sponsor_instance(&caller, new_iid);
+        self.refresh_status_index(new_iid);
+
+        // Update sponsor leaderboards, only EGLD prizes are accounted
+        let instance_prize_info = self.instance_info_mapper().get(&new_iid).unwrap().prize_info;
+        self.increase_leaderboard_score(LeaderboardKind::SponsorInstances, &caller, &BigUint::from(1u32));
+        if instance_prize_info.token_identifier.is_egld() {
+            self.increase_leaderboard_score(LeaderboardKind::SponsorPrizes, &caller, &instance_prize_info.token_amount);
+        }
+
+        // Format result
+        Ok_some!(new_iid);
+    }
+
+    // Record the instance in sponsor, player & status indexes, and its fee policy & player entries if missing
+    fn index_instance(&self, iid: u32) {
+        // Instances created before fee policies were recorded per instance keep the fee policy at upgrade, 
+        // with the sponsor reward percent recorded at creation
+        if self.instance_fee_policy_mapper().contains_key(&iid) == false {
+            let mut fee_policy = self.migration_fee_policy_mapper().get();
+            fee_policy.sponsor_reward_percent = self.instance_state_mapper().get(&iid).unwrap().reward_info.percent;
+
+            // Log event
+            self.event_wrapper_migrate_fee_policy(iid, &fee_policy.fee_amount_egld, fee_policy.sponsor_reward_percent, fee_policy.link_reward_percent, 0u8);
+
+            self.instance_fee_policy_mapper().insert(iid, fee_policy);
+        }
+
+        self.add_sponsor_instance(&self.instance_info_mapper().get(&iid).unwrap().sponsor_info.address, iid);
+
+        // Tickets & fees of players of older instances were not recorded when playing : 
+        // ticket number is the position in the players list, fees are the ones of the instance fee policy
+        let fee_amount_egld = self.get_instance_fee_policy(iid).fee_amount_egld;
+        let mut ticket_number: usize = 0;
+
+        for player_address in self.instance_players_vec_mapper(iid).iter() {
+            ticket_number += 1;
+            self.add_player_instance(&player_address, iid);
+
+            if self.instance_player_ticket_mapper(iid, &player_address).is_empty() == true {
+                self.instance_player_ticket_mapper(iid, &player_address).set(&ticket_number);
+                self.instance_player_fees_mapper(iid, &player_address).set(&fee_amount_egld);
+            }
+        }
+
+        self.refresh_status_index(iid);
+    }
+
+    fn get_prize_info(&self, iid: u32) -> PrizeInfo<Self::Api> {
+        let mut prize_info = self.instance_info_mapper().get(&iid).unwrap().prize_info;
+
+        // Prize of instances funded by tickets is the ticket pot, until it is paid
+        if self.instance_pot_winner_percent_mapper(iid).get() != 0 && self.instance_state_mapper().get(&iid).unwrap().claimed_status == false {
+            prize_info.token_amount = self.instance_pot_mapper(iid).get();
+        }
+
+        return prize_info;
+    }
+
+    fn func_play(&self, payment: BigUint, iid: u32, link_address: Option<ManagedAddress>, discount: BigUint) -> MultiValue2<SCResult<()>, OptionalValue<usize>> {
+
+        // Checks
+        let caller = self.blockchain().get_caller();
+        require_with_opt!(self.address_blacklist_set_mapper().contains(&caller) == false, "Caller blacklisted");
+        require_with_opt!(self.get_instance_status(iid) == InstanceStatus::Running, "Instance is not active");
+        require_with_opt!(self.has_played(iid, caller.clone()) == false, "Player has already played");
+        require_with_opt!(self.is_instance_migrated(iid) == true, "Instance migration in progress");
+
+        // Fees are ruled by the fee policy recorded for the instance
+        // Payment may exceed the fees for charity instances only, the surplus is a donation
+        // Optional discount reduces the fees, down to free entry
+        let fee_policy = self.get_instance_fee_policy(iid);
+        let mut fees: BigUint = fee_policy.fee_amount_egld.clone();
+        if discount >= fees {
+            fees = BigUint::zero();
+        }
+        else {
+            fees -= discount;
+        }
+        require_with_opt!(payment >= fees, "Wrong fees amount");
+        require_with_opt!(payment == fees || self.instance_info_mapper().get(&iid).unwrap().charity == true, "Wrong fees amount");
+        let donation: BigUint = payment - fees.clone();
+
+        // Register the optional affiliation link address as referrer of the caller (once, ignored if not valid)
+        if link_address.is_some() == true {
+            self.try_register_referrer(&caller, &link_address.unwrap());
+        }
+
+        // Capitalize the winner share of fees into the ticket pot for instances funded by tickets
+        let pot_winner_percent: u8 = self.instance_pot_winner_percent_mapper(iid).get();
+        let mut split_fees: BigUint = fees.clone();
+
+        if pot_winner_percent != 0 {
+            let pot_share: BigUint = fees.clone() * BigUint::from(pot_winner_percent) / BigUint::from(100u8);
+            self.instance_pot_mapper(iid).update(|current_pot| *current_pot += pot_share.clone());
+            split_fees -= pot_share;
+
+            // Log event
+            self.event_wrapper_pot_info(iid, &self.instance_pot_mapper(iid).get());
+        }
+
+        // Capitalize fees, sponsor rewards and link rewards of the registered referrers
+        let mut instance_state = self.instance_state_mapper().get(&iid).unwrap();
+        // Instances created before level-2 link rewards have none
+        instance_state.reward_info.pool += self.update_fees_and_compute_rewards(split_fees, &fee_policy, self.instance_link2_reward_percent_mapper(iid).get());
+        self.event_wrapper_reward_pool_info(iid, &instance_state.reward_info.pool); 
+        self.instance_state_mapper().insert(iid, instance_state);
+        
+        // Add caller address to participants for this instance
+        let ticket_number: usize = self.add_player(iid, &caller);
+
+        // Record fees paid by the player
+        self.record_player_entry(iid, &caller, ticket_number, &fees);
+        self.record_play(&fees, self.is_instance_migrated(iid));
+
+        // Update player & affiliate leaderboards
+        self.increase_leaderboard_score(LeaderboardKind::PlayerPlays, &caller, &BigUint::from(1u32));
+        if self.referrer_mapper(&caller).is_empty() == false {
+            self.increase_leaderboard_score(LeaderboardKind::AffiliatePlays, &self.referrer_mapper(&caller).get(), &BigUint::from(1u32));
+        }
+
+        // Award loyalty points for the fees paid
+        self.award_play_points(&caller, &fees);
+
+        // Give optional donation to the instance charity
+        if donation > BigUint::zero() {
+            self.add_donation(iid, &donation);
+            self.add_player_donation(iid, &caller, &donation);
+
+            // Log event
+            self.event_wrapper_player_donation(&caller, iid, &donation);
+        }
+
+        // Log event
+        self.event_wrapper_play(&caller, iid, ticket_number, &fees);
+
+        Ok_some!(ticket_number);
+    }
+
+    fn func_send_prize(&self, iid: u32, prize_info: &PrizeInfo<Self::Api>, winner_info: &WinnerInfo<Self::Api>) {
+
+        // Ticket pot may be empty
+        if prize_info.token_amount == BigUint::zero() {
+            return;
+        }
 
         // Send prize to winner address
         self.send().direct(
-            winner_address,
+            &winner_info.address,
             &prize_info.token_identifier,
             prize_info.token_nonce,
             &prize_info.token_amount,
             b"Send prize",
         );
+
+        // Paid ticket pot is emptied, and kept as the instance prize for the views & the archive
+        if self.instance_pot_winner_percent_mapper(iid).get() != 0 {
+            self.instance_pot_mapper(iid).clear();
+            let mut instance_info = self.instance_info_mapper().get(&iid).unwrap();
+            instance_info.prize_info.token_amount = prize_info.token_amount.clone();
+            self.instance_info_mapper().insert(iid, instance_info);
+
+            // Log event
+            self.event_wrapper_pot_info(iid, &BigUint::zero());
+        }
+
+        // Update statistics, prizes given back to the sponsor of instances without player are not distributed
+        if winner_info.ticket_number != 0 {
+            self.record_prize_distributed(&prize_info.token_identifier, &prize_info.token_amount);
+        }
+    }
+
+    // Fee policy of instances existing at upgrade is recorded by the migration
+    fn get_instance_fee_policy(&self, iid: u32) -> FeePolicy<Self::Api> {
+        return self.instance_fee_policy_mapper().get(&iid).unwrap();
     }
 
     fn pay_rewards_to_sponsor(&self, iid: u32, sponsor_address: ManagedAddress, rewards: BigUint) {
@@ -0,0 +1,247 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use super::instance;
+use super::instance::InstanceStatus;
+use super::instance::PrizeInfo;
+use super::sponsor;
+use super::player;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+
+// Sorting scans all candidate instances and keeps the first <cursor + limit> in a sorted listing : O(n * (cursor + limit))
+const MAX_LISTING_LIMIT: u32 = 50;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum PrizeType {
+    Egld,
+    Esdt,
+    Sft,
+    Nft,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum InstanceSort {
+    Creation,
+    DeadlineSoonest,
+    NbPlayers,
+}
+
+// Listing filter, each criterion is ignored when not set
+//  - status_filter : empty to accept any status
+//  - token_identifier : prize token identifier, or collection for NFT & SFT prizes
+//  - deadline_min, deadline_max : 0 for no bound
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct InstanceFilter<M: ManagedTypeApi> {
+    pub status_filter: ManagedVec<M, InstanceStatus>,
+    pub token_identifier: Option<TokenIdentifier<M>>,
+    pub prize_type: Option<PrizeType>,
+    pub premium: Option<bool>,
+    pub charity: Option<bool>,
+    pub sponsor_address: Option<ManagedAddress<M>>,
+    pub deadline_min: u64,
+    pub deadline_max: u64,
+    pub min_nb_players: u32,
+}
+
+// Sort key of a listed instance
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct ListingEntry {
+    pub sort_key: u64,
+    pub iid: u32,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait ListingModule:
+    instance::InstanceModule
+    +sponsor::SponsorModule
+    +player::PlayerModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+
+    // Returns : next cursor (position in the sorted listing, 0 when all instances have been returned), up to <limit> sorted IIDs from position <cursor>
+    // Limit is capped to MAX_LISTING_LIMIT, deep pages still cost a scan of the first <cursor + limit> entries
+    fn get_listing_page(&self, filter: &InstanceFilter<Self::Api>, sort: InstanceSort, cursor: u32, limit: u32) -> (u32, ManagedVec<u32>) {
+        require!(limit > 0 && limit <= MAX_LISTING_LIMIT, "Limit out of range");
+
+        let mut listing: ManagedVec<ListingEntry> = ManagedVec::new();
+        let mut nb_matching_instances: u32 = 0;
+        let listing_size: u32 = cursor.saturating_add(limit);
+
+        // Candidates come from the sponsor index when filtered by sponsor, from the status index otherwise
+        let mut candidate_iids: ManagedVec<u32> = ManagedVec::new();
+
+        if filter.sponsor_address.is_some() == true {
+            for node in self.sponsor_iids_mapper(filter.sponsor_address.as_ref().unwrap()).iter() {
+                candidate_iids.push(node.get_value_cloned());
+            }
+        }
+        else {
+            for bucket in self.get_status_buckets().iter() {
+                if self.is_bucket_in_listing_filter(bucket, filter) == true {
+                    for node in self.status_iids_mapper(bucket).iter() {
+                        candidate_iids.push(node.get_value_cloned());
+                    }
+                }
+            }
+        }
+
+        for iid in candidate_iids.iter() {
+            if self.is_instance_in_filter(iid, filter) == true {
+                nb_matching_instances += 1;
+                self.insert_in_listing(&mut listing, ListingEntry { sort_key: self.get_sort_key(iid, sort), iid: iid }, listing_size);
+            }
+        }
+
+        // Return the requested page of the sorted listing
+        let mut page: ManagedVec<u32> = ManagedVec::new();
+        let mut position: u32 = 0;
+
+        for entry in listing.iter() {
+            if position >= cursor {
+                page.push(entry.iid);
+            }
+            position += 1;
+        }
+
+        let next_cursor: u32 = if nb_matching_instances > listing_size {listing_size} else {0};
+
+        return (next_cursor, page);
+    }
+
+    fn is_instance_in_filter(&self, iid: u32, filter: &InstanceFilter<Self::Api>) -> bool {
+        let instance_info = match self.instance_info_mapper().get(&iid) {
+            None => return false,
+            Some(instance_info) => instance_info,
+        };
+
+        if self.is_status_in_listing_filter(self.get_instance_status(iid), filter) == false {
+            return false;
+        }
+
+        if filter.token_identifier.is_some() == true && *filter.token_identifier.as_ref().unwrap() != instance_info.prize_info.token_identifier {
+            return false;
+        }
+
+        if filter.prize_type.is_some() == true && filter.prize_type.unwrap() != self.get_prize_type(&instance_info.prize_info) {
+            return false;
+        }
+
+        if filter.premium.is_some() == true && filter.premium.unwrap() != instance_info.premium {
+            return false;
+        }
+
+        if filter.charity.is_some() == true && filter.charity.unwrap() != instance_info.charity {
+            return false;
+        }
+
+        if filter.sponsor_address.is_some() == true && *filter.sponsor_address.as_ref().unwrap() != instance_info.sponsor_info.address {
+            return false;
+        }
+
+        if (filter.deadline_min != 0 && instance_info.deadline < filter.deadline_min) || (filter.deadline_max != 0 && instance_info.deadline > filter.deadline_max) {
+            return false;
+        }
+
+        return self.get_nb_players(iid) as u32 >= filter.min_nb_players;
+    }
+
+    fn is_status_in_listing_filter(&self, instance_status: InstanceStatus, filter: &InstanceFilter<Self::Api>) -> bool {
+        if filter.status_filter.len() == 0 {
+            return true;
+        }
+
+        for status in filter.status_filter.iter() {
+            if instance_status == status {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    fn is_bucket_in_listing_filter(&self, bucket: InstanceStatus, filter: &InstanceFilter<Self::Api>) -> bool {
+        if filter.status_filter.len() == 0 {
+            return true;
+        }
+
+        for status in filter.status_filter.iter() {
+            if self.get_status_bucket(status) == bucket {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    // Token properties are not available on-chain once the prize is sent, NFT are told apart from SFT by the amount escrowed
+    fn get_prize_type(&self, prize_info: &PrizeInfo<Self::Api>) -> PrizeType {
+        if prize_info.token_identifier.is_egld() {
+            return PrizeType::Egld;
+        }
+
+        if prize_info.token_nonce == 0 {
+            return PrizeType::Esdt;
+        }
+
+        if prize_info.token_amount == BigUint::from(1u32) {
+            return PrizeType::Nft;
+        }
+
+        return PrizeType::Sft;
+    }
+
+    // Listing is sorted by increasing sort key, then by IID
+    fn get_sort_key(&self, iid: u32, sort: InstanceSort) -> u64 {
+        match sort {
+            InstanceSort::Creation => return iid as u64,
+            InstanceSort::DeadlineSoonest => return self.instance_info_mapper().get(&iid).unwrap().deadline,
+            InstanceSort::NbPlayers => return u64::MAX - self.get_nb_players(iid) as u64,
+        }
+    }
+
+    // Insert the entry in the sorted listing, the listing is bounded to <listing_size> entries
+    fn insert_in_listing(&self, listing: &mut ManagedVec<ListingEntry>, new_entry: ListingEntry, listing_size: u32) {
+
+        // Entries sorted after the last entry of a full listing are left out without rebuilding it
+        if listing.len() as u32 >= listing_size {
+            if let Some(last_entry) = listing.iter().last() {
+                if new_entry.sort_key > last_entry.sort_key || (new_entry.sort_key == last_entry.sort_key && new_entry.iid > last_entry.iid) {
+                    return;
+                }
+            }
+        }
+
+        let mut new_listing: ManagedVec<ListingEntry> = ManagedVec::new();
+        let mut inserted: bool = false;
+
+        for entry in listing.iter() {
+            if inserted == false && (new_entry.sort_key < entry.sort_key || (new_entry.sort_key == entry.sort_key && new_entry.iid < entry.iid)) {
+                if (new_listing.len() as u32) < listing_size {
+                    new_listing.push(ListingEntry { sort_key: new_entry.sort_key, iid: new_entry.iid });
+                }
+                inserted = true;
+            }
+
+            if (new_listing.len() as u32) < listing_size {
+                new_listing.push(entry);
+            }
+        }
+
+        if inserted == false && (new_listing.len() as u32) < listing_size {
+            new_listing.push(new_entry);
+        }
+
+        *listing = new_listing;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,141 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use super::event;
+use super::leaderboard;
+use super::leaderboard::{LeaderboardKind, LeaderboardEntry};
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const EGLD_DECIMALS_FACTOR: u64 = 1_000_000_000_000_000_000;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Loyalty policy
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct LoyaltyPolicy {
+    pub points_per_egld: u64,
+    pub points_per_win: u64,
+    pub free_entry_cost: u64,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait LoyaltyModule:
+    event::EventModule
+    +leaderboard::LeaderboardModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+    #[only_owner]
+    #[endpoint(setLoyaltyPol)]
+    // Free entry cost set to 0 disables points redemption
+    fn set_loyalty_policy(&self, points_per_egld: u64, points_per_win: u64, free_entry_cost: u64) -> SCResult<()> {
+        self.loyalty_policy_mapper().set(&LoyaltyPolicy {
+            points_per_egld: points_per_egld,
+            points_per_win: points_per_win,
+            free_entry_cost: free_entry_cost,
+        });
+
+        // Log event
+        self.event_wrapper_set_loyalty_policy(points_per_egld, points_per_win, free_entry_cost);
+
+        Ok(())
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getLoyaltyPol)]
+    fn get_loyalty_policy(&self) -> MultiValue3<u64, u64, u64> {
+        let loyalty_policy = self.loyalty_policy_mapper().get();
+
+        return MultiValue3((loyalty_policy.points_per_egld, loyalty_policy.points_per_win, loyalty_policy.free_entry_cost));
+    }
+
+    #[view(getLoyaltyPoints)]
+    // Returns : current balance of points, lifetime points
+    fn get_loyalty_points(&self, address: ManagedAddress) -> MultiValue2<u64, u64> {
+        return MultiValue2((self.loyalty_points_mapper(&address).get(), self.loyalty_lifetime_points_mapper(&address).get()));
+    }
+
+    #[view(getLoyaltyRanking)]
+    // Returns : up to <max_nb_ranks> addresses with the highest lifetime points
+    fn get_loyalty_ranking(&self, max_nb_ranks: usize) -> MultiValueManagedVec<LeaderboardEntry<Self::Api>> {
+        return self.get_leaderboard(LeaderboardKind::LoyaltyPoints, max_nb_ranks);
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+    fn init_loyalty_if_empty(&self, points_per_egld: u64, points_per_win: u64, free_entry_cost: u64) {
+        self.loyalty_policy_mapper().set_if_empty(&LoyaltyPolicy {
+            points_per_egld: points_per_egld,
+            points_per_win: points_per_win,
+            free_entry_cost: free_entry_cost,
+        });
+    }
+
+    fn award_play_points(&self, player_address: &ManagedAddress, fees: &BigUint) {
+        let points_per_egld = self.loyalty_policy_mapper().get().points_per_egld;
+        let points: u64 = (fees.clone() * BigUint::from(points_per_egld) / BigUint::from(EGLD_DECIMALS_FACTOR)).to_u64().unwrap_or(u64::MAX);
+
+        self.award_loyalty_points(player_address, points);
+    }
+
+    fn award_win_points(&self, winner_address: &ManagedAddress) {
+        self.award_loyalty_points(winner_address, self.loyalty_policy_mapper().get().points_per_win);
+    }
+
+    // Spend points for a free entry, returns false if redemption is disabled or the balance is too low
+    fn redeem_free_entry(&self, player_address: &ManagedAddress) -> bool {
+        let free_entry_cost = self.loyalty_policy_mapper().get().free_entry_cost;
+
+        if free_entry_cost == 0 || self.loyalty_points_mapper(player_address).get() < free_entry_cost {
+            return false;
+        }
+
+        self.loyalty_points_mapper(player_address).update(|current_points| *current_points -= free_entry_cost);
+
+        // Log event
+        self.event_wrapper_redeem_loyalty_points(player_address, free_entry_cost);
+
+        return true;
+    }
+
+    fn award_loyalty_points(&self, address: &ManagedAddress, points: u64) {
+        if points == 0 {
+            return;
+        }
+
+        self.loyalty_points_mapper(address).update(|current_points| *current_points = current_points.saturating_add(points));
+        self.loyalty_lifetime_points_mapper(address).update(|current_points| *current_points = current_points.saturating_add(points));
+
+        self.increase_leaderboard_score(LeaderboardKind::LoyaltyPoints, address, &BigUint::from(points));
+
+        // Log event
+        self.event_wrapper_award_loyalty_points(address, points);
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Loyalty policy
+    #[storage_mapper("loyalty_policy")]
+    fn loyalty_policy_mapper(&self) -> SingleValueMapper<LoyaltyPolicy>;
+
+    // Balance of points (per address)
+    #[storage_mapper("loyalty_points")]
+    fn loyalty_points_mapper(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Lifetime points (per address)
+    #[storage_mapper("loyalty_lifetime_points")]
+    fn loyalty_lifetime_points_mapper(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+}
\ No newline at end of file
@@ -59,6 +59,19 @@ pub trait ParameterModule:
         Ok(())
     }
 
+    #[only_owner]
+    #[endpoint(setParamPotWinnerPercent)]
+    fn set_param_pot_winner_percent(&self, winner_percent: u8) -> SCResult<()> {
+        require!(winner_percent > 0 && winner_percent <= 100, "Wrong value for winner percent");
+
+        self.param_pot_winner_percent_mapper().update(|current_value| *current_value = winner_percent);
+
+        // Log event
+        self.event_wrapper_set_param_pot_winner_percent(winner_percent);
+
+        Ok(())
+    }
+
     /////////////////////////////////////////////////////////////////////
     // Queries
     /////////////////////////////////////////////////////////////////////
@@ -82,6 +95,11 @@ pub trait ParameterModule:
         return self.param_sponsor_info_max_length_mapper().get(); 
     }
 
+    #[view(getParamPotWinnerPercent)]
+    fn get_param_pot_winner_percent(&self) -> u8 {        
+        return self.param_pot_winner_percent_mapper().get(); 
+    }
+
     /////////////////////////////////////////////////////////////////////
     // Mappers
     /////////////////////////////////////////////////////////////////////
@@ -99,4 +117,7 @@ pub trait ParameterModule:
 
     #[storage_mapper("param_sponsor_info_max_length")]
     fn param_sponsor_info_max_length_mapper(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("param_pot_winner_percent")]
+    fn param_pot_winner_percent_mapper(&self) -> SingleValueMapper<u8>;
 }
\ No newline at end of file
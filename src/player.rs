@@ -1,6 +1,10 @@
 elrond_wasm::imports!();
 
+use super::Ok_some;
+use super::require_with_opt;
 use super::instance;
+use super::instance::InstanceStatus;
+use super::stats;
 use super::event;
 
 ////////////////////////////////////////////////////////////////////
@@ -9,6 +13,7 @@ use super::event;
 #[elrond_wasm::module]
 pub trait PlayerModule: 
     instance::InstanceModule
+    +stats::StatsModule
     +event::EventModule {
 
     /////////////////////////////////////////////////////////////////////
@@ -20,30 +25,94 @@ pub trait PlayerModule:
     /////////////////////////////////////////////////////////////////////
     #[view(getTotalPlayers)]
     fn get_total_players(&self) -> usize {
-        let mut total_players: usize = 0;
 
-        // Compute sum of players of all lotteries
-        for iid in self.instance_info_mapper().keys() {
-            total_players += self.get_nb_players(iid);
-        }
-
-        return total_players;
+        // Lifetime number of plays of all lotteries, kept after instance cleaning
+        return self.stats_nb_plays_mapper().get() as usize;
     }
 
     #[view(getPlayerIDs)]
-    fn get_player_instances(&self, player_address: ManagedAddress) -> MultiValueManagedVec<u32> {
+    // Returns : next cursor (0 when all instances have been returned), followed by up to <limit> instances IDs played by the player from <cursor>, in play order
+    fn get_player_instances(&self, player_address: ManagedAddress, cursor: u32, limit: u32) -> MultiValue2<u32, MultiValueManagedVec<u32>> {
+        require!(limit > 0, "Limit must be positive");
+
         let mut player_iids = MultiValueManagedVec::new();
 
-        // Return all instances IDs to which player address provided in parameter has played
-        for iid in self.instance_info_mapper().keys() {
-            if self.has_played(iid, player_address.clone()) == true {
-                player_iids.push(iid.clone());
+        // Return instances IDs to which player address provided in parameter has played
+        // Player index is walked from the cursor, until the page is full
+        let next_cursor: u32 = self.fill_page_from_index(&self.player_iids_mapper(&player_address), cursor, limit, None, &mut player_iids);
+
+        return MultiValue2((next_cursor, player_iids));
+    }
+    
+    #[view(getPlayerDonations)]
+    fn get_player_donations(&self, player_address: ManagedAddress) -> BigUint {
+
+        // Return lifetime donations of the player, given on top of the fees
+        return self.player_donations_mapper(&player_address).get();
+    }
+
+    #[view(hasDonated)]
+    fn has_donated(&self, iid: u32, player_address: ManagedAddress) -> bool {
+        // Return true if player_address provided in parameter gave a donation when playing to the specified instance ID
+        return self.instance_donors_set_mapper(iid).contains(&player_address);
+    }
+
+    #[view(getTickets)]
+    // Returns : next cursor (0 when all tickets have been returned), followed by up to <limit> (ticket number, address) from ticket number <cursor>
+    fn get_tickets(&self, iid: u32, cursor: usize, limit: usize) -> MultiValue2<usize, MultiValueEncoded<MultiValue2<usize, ManagedAddress>>> {
+        require!(limit > 0, "Limit must be positive");
+
+        let mut tickets: MultiValueEncoded<MultiValue2<usize, ManagedAddress>> = MultiValueEncoded::new();
+        let nb_players: usize = self.get_nb_players(iid);
+
+        // Ticket numbers start from 1, cursor 0 means first page
+        let mut ticket_number: usize = if cursor == 0 {1} else {cursor};
+        let mut nb_tickets: usize = 0;
+
+        while ticket_number <= nb_players && nb_tickets < limit {
+            tickets.push(MultiValue2((ticket_number, self.get_ticket_owner(iid, ticket_number))));
+            ticket_number += 1;
+            nb_tickets += 1;
+        }
+
+        let next_cursor: usize = if ticket_number <= nb_players {ticket_number} else {0};
+
+        return MultiValue2((next_cursor, tickets));
+    }
+
+    #[view(getTicketNumber)]
+    fn get_ticket_number(&self, iid: u32, player_address: ManagedAddress) -> MultiValue2<SCResult<()>, OptionalValue<usize>> {
+        require_with_opt!(self.get_instance_status(iid) != InstanceStatus::NotExisting, "Instance does not exist");
+        require_with_opt!(self.has_played(iid, player_address.clone()) == true, "Player has not played");
+
+        // Ticket number is recorded when playing, search the players list for players of older instances
+        if self.instance_player_ticket_mapper(iid, &player_address).is_empty() == false {
+            Ok_some!(self.instance_player_ticket_mapper(iid, &player_address).get());
+        }
+
+        let mut ticket_number: usize = 0;
+
+        for ticket_owner in self.instance_players_vec_mapper(iid).iter() {
+            ticket_number += 1;
+            if ticket_owner == player_address {
+                break;
             }
         }
 
-        return player_iids;
+        Ok_some!(ticket_number);
     }
-    
+
+    #[view(getWinningTicket)]
+    // Returns : Result, optional (winning ticket number, ticket owner address), ticket number is 0 if the prize went back to the sponsor
+    fn get_winning_ticket(&self, iid: u32) -> MultiValue2<SCResult<()>, OptionalValue<MultiValue2<usize, ManagedAddress>>> {
+        require_with_opt!(self.get_instance_status(iid) != InstanceStatus::NotExisting, "Instance does not exist");
+
+        let winner_info = self.instance_state_mapper().get(&iid).unwrap().winner_info;
+        require_with_opt!(winner_info.address != ManagedAddress::zero(), "Instance has not been triggered");
+
+        Ok_some!(MultiValue2((winner_info.ticket_number, winner_info.address)));
+    }
+
     #[view(hasPlayed)]
     fn has_played(&self, iid: u32, player_address: ManagedAddress) -> bool {
         // Return true is player_address provided in parameter is part of the SetMapper for the specified instance ID
@@ -56,11 +125,30 @@ pub trait PlayerModule:
     fn add_player(&self, iid: u32, player_address: &ManagedAddress) -> usize {
         self.instance_players_set_mapper(iid).insert(player_address.clone());
         self.instance_players_vec_mapper(iid).push(player_address);
+        self.add_player_instance(player_address, iid);
 
         // Return number of players as a ticket number
         return self.get_nb_players(iid);
     }
 
+    fn record_player_entry(&self, iid: u32, player_address: &ManagedAddress, ticket_number: usize, fees: &BigUint) {
+        self.instance_player_ticket_mapper(iid, player_address).set(&ticket_number);
+        self.instance_player_fees_mapper(iid, player_address).set(fees);
+
+        // Lifetime totals
+        self.player_nb_plays_mapper(player_address).update(|current_nb_plays| *current_nb_plays += 1);
+        self.player_spent_mapper(player_address).update(|current_spent| *current_spent += fees);
+    }
+
+    fn record_player_win(&self, player_address: &ManagedAddress) {
+        self.player_nb_wins_mapper(player_address).update(|current_nb_wins| *current_nb_wins += 1);
+    }
+
+    fn add_player_donation(&self, iid: u32, player_address: &ManagedAddress, donation: &BigUint) {
+        self.instance_donors_set_mapper(iid).insert(player_address.clone());
+        self.player_donations_mapper(player_address).update(|current_donations| *current_donations += donation);
+    }
+
     fn get_nb_players(&self, iid: u32) -> usize {
 
         // Return number of players
@@ -73,9 +161,20 @@ pub trait PlayerModule:
         return self.instance_players_vec_mapper(iid).get(ticket_number);
     }
 
+    fn add_player_instance(&self, player_address: &ManagedAddress, iid: u32) {
+        self.insert_in_index(&mut self.player_iids_mapper(player_address), &self.player_iid_node_mapper(player_address, iid), iid);
+    }
+
     fn clear_players(&self, iid: u32) {
+        for player_address in self.instance_players_vec_mapper(iid).iter() {
+            self.remove_from_index(&mut self.player_iids_mapper(&player_address), &self.player_iid_node_mapper(&player_address, iid));
+            self.instance_player_ticket_mapper(iid, &player_address).clear();
+            self.instance_player_fees_mapper(iid, &player_address).clear();
+        }
+
         self.instance_players_set_mapper(iid).clear();
         self.instance_players_vec_mapper(iid).clear();
+        self.instance_donors_set_mapper(iid).clear();
     }
 
     /////////////////////////////////////////////////////////////////////
@@ -86,5 +185,39 @@ pub trait PlayerModule:
 
     #[storage_mapper("instance_players_vec")]
     fn instance_players_vec_mapper(&self, iid: u32) -> VecMapper<ManagedAddress>;
+
+    #[storage_mapper("instance_donors_set")]
+    fn instance_donors_set_mapper(&self, iid: u32) -> SetMapper<ManagedAddress>;
+
+    #[storage_mapper("player_donations")]
+    fn player_donations_mapper(&self, player_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Instances IDs (per player)
+    #[storage_mapper("player_iids")]
+    fn player_iids_mapper(&self, player_address: &ManagedAddress) -> LinkedListMapper<u32>;
+
+    // Node of the instance in the player index (per player & instance)
+    #[storage_mapper("player_iid_node")]
+    fn player_iid_node_mapper(&self, player_address: &ManagedAddress, iid: u32) -> SingleValueMapper<u32>;
+
+    // Ticket number (per instance & player)
+    #[storage_mapper("instance_player_ticket")]
+    fn instance_player_ticket_mapper(&self, iid: u32, player_address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    // Fees paid (per instance & player)
+    #[storage_mapper("instance_player_fees")]
+    fn instance_player_fees_mapper(&self, iid: u32, player_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Lifetime number of plays (per player)
+    #[storage_mapper("player_nb_plays")]
+    fn player_nb_plays_mapper(&self, player_address: &ManagedAddress) -> SingleValueMapper<u32>;
+
+    // Lifetime fees paid (per player)
+    #[storage_mapper("player_spent")]
+    fn player_spent_mapper(&self, player_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    // Lifetime number of wins (per player)
+    #[storage_mapper("player_nb_wins")]
+    fn player_nb_wins_mapper(&self, player_address: &ManagedAddress) -> SingleValueMapper<u32>;
     
 }
\ No newline at end of file
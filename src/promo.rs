@@ -0,0 +1,89 @@
+elrond_wasm::imports!();
+
+use super::instance;
+use super::instance::InstanceStatus;
+use super::event;
+
+////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////
+const PROMO_CODE_HASH_LENGTH: usize = 32;
+
+////////////////////////////////////////////////////////////////////
+// Functions
+////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait PromoModule:
+    instance::InstanceModule
+    +event::EventModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+    #[endpoint(addPromoCodes)]
+    // Promo codes are registered as keccak256 hashes, discount equal or greater than the fees gives a free entry
+    fn add_promo_codes(&self, iid: u32, discount: BigUint, #[var_args] promo_code_hashes: MultiValueManagedVec<ManagedBuffer>) -> SCResult<()> {
+        require!(self.get_instance_status(iid) == InstanceStatus::Running, "Instance is not active");
+        require!(self.blockchain().get_caller() == self.instance_info_mapper().get(&iid).unwrap().sponsor_info.address, "Bad caller");
+        require!(discount > 0, "Discount cannot be null");
+        require!(promo_code_hashes.len() > 0, "No promo code provided");
+
+        for promo_code_hash in promo_code_hashes.iter() {
+            require!(promo_code_hash.len() == PROMO_CODE_HASH_LENGTH, "Wrong promo code hash length");
+            require!(self.instance_promo_codes_mapper(iid).contains_key(&promo_code_hash) == false, "Promo code already registered");
+
+            self.instance_promo_codes_mapper(iid).insert(promo_code_hash, discount.clone());
+        }
+
+        // Log event
+        self.event_wrapper_add_promo_codes(iid, promo_code_hashes.len(), &discount);
+
+        Ok(())
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getNbPromoCodes)]
+    fn get_nb_promo_codes(&self, iid: u32) -> usize {
+
+        // Return the number of promo codes not used yet
+        return self.instance_promo_codes_mapper(iid).len();
+    }
+
+    #[view(getPromoCodeDiscount)]
+    fn get_promo_code_discount(&self, iid: u32, promo_code_hash: ManagedBuffer) -> BigUint {
+
+        // Return the discount of a promo code not used yet, zero otherwise
+        return self.instance_promo_codes_mapper(iid).get(&promo_code_hash).unwrap_or_else(|| BigUint::zero());
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+
+    // Consume the promo code, returns the discount if the promo code is valid
+    fn use_promo_code(&self, iid: u32, promo_code: &ManagedBuffer) -> Option<BigUint> {
+        let promo_code_hash: ManagedBuffer = self.crypto().keccak256(promo_code).as_managed_buffer().clone();
+        let discount: Option<BigUint> = self.instance_promo_codes_mapper(iid).remove(&promo_code_hash);
+
+        if discount.is_some() == true {
+            // Log event
+            self.event_wrapper_use_promo_code(iid, &self.blockchain().get_caller(), discount.as_ref().unwrap());
+        }
+
+        return discount;
+    }
+
+    fn clear_promo_codes(&self, iid: u32) {
+        self.instance_promo_codes_mapper(iid).clear();
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Promo codes not used yet (per instance) : keccak256 hash of the promo code -> discount
+    #[storage_mapper("instance_promo_codes")]
+    fn instance_promo_codes_mapper(&self, iid: u32) -> MapMapper<ManagedBuffer, BigUint>;
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use super::event;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+
+// Maximum number of referrers walked through when checking a new referral, bounds the registration cost
+const MAX_UPLINE_DEPTH: u32 = 32;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Affiliate information (downline & earnings)
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AffiliateInfo<M: ManagedTypeApi> {
+    pub referrer: ManagedAddress<M>,
+    pub nb_referrals_level1: u32,
+    pub nb_referrals_level2: u32,
+    pub rewards_earned_level1: BigUint<M>,
+    pub rewards_earned_level2: BigUint<M>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait ReferralModule:
+    event::EventModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+    #[endpoint(setReferrer)]
+    fn set_referrer(&self, referrer_address: ManagedAddress) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+
+        // Checks
+        require!(self.referrer_mapper(&caller).is_empty() == true, "Referrer already registered");
+        require!(referrer_address.is_zero() == false, "Invalid referrer address");
+        require!(referrer_address != caller, "Self-referral is not allowed");
+        require!(self.is_in_upline(&referrer_address, &caller) == false, "Referral cycle or too long referral chain");
+
+        self.register_referrer(&caller, &referrer_address);
+
+        Ok(())
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getReferrer)]
+    fn get_referrer(&self, address: ManagedAddress) -> ManagedAddress {
+
+        // Return the registered referrer, zero address if none
+        if self.referrer_mapper(&address).is_empty() {
+            return ManagedAddress::zero();
+        }
+
+        return self.referrer_mapper(&address).get();
+    }
+
+    #[view(getAffiliateInfo)]
+    fn get_affiliate_info(&self, address: ManagedAddress) -> AffiliateInfo<Self::Api> {
+        return AffiliateInfo {
+            referrer: self.get_referrer(address.clone()),
+            nb_referrals_level1: self.nb_referrals_mapper(&address, 1u8).get(),
+            nb_referrals_level2: self.nb_referrals_mapper(&address, 2u8).get(),
+            rewards_earned_level1: self.referral_rewards_earned_mapper(&address, 1u8).get(),
+            rewards_earned_level2: self.referral_rewards_earned_mapper(&address, 2u8).get(),
+        };
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+
+    // Register referrer if the address has none yet and if the referral is valid, returns true if registered
+    fn try_register_referrer(&self, address: &ManagedAddress, referrer_address: &ManagedAddress) -> bool {
+        if self.referrer_mapper(address).is_empty() == false
+            || referrer_address.is_zero() == true
+            || referrer_address == address
+            || self.is_in_upline(referrer_address, address) == true {
+            return false;
+        }
+
+        self.register_referrer(address, referrer_address);
+
+        return true;
+    }
+
+    fn register_referrer(&self, address: &ManagedAddress, referrer_address: &ManagedAddress) {
+        self.referrer_mapper(address).set(referrer_address);
+
+        // Address becomes a level 1 referral of its referrer
+        self.nb_referrals_mapper(referrer_address, 1u8).update(|current| *current += 1);
+
+        // Address and its own level 1 referrals become level 2 referrals of the upper level
+        let nb_level1_referrals: u32 = self.nb_referrals_mapper(address, 1u8).get();
+        self.nb_referrals_mapper(referrer_address, 2u8).update(|current| *current += nb_level1_referrals);
+
+        if self.referrer_mapper(referrer_address).is_empty() == false {
+            self.nb_referrals_mapper(&self.referrer_mapper(referrer_address).get(), 2u8).update(|current| *current += 1);
+        }
+
+        // Log event
+        self.event_wrapper_set_referrer(address, referrer_address);
+    }
+
+    // Check if target address is part of the referrer chain starting from the address (address included)
+    // Chains longer than MAX_UPLINE_DEPTH cannot be checked at a bounded cost, they are treated as cycles so that the referral is refused
+    fn is_in_upline(&self, address: &ManagedAddress, target_address: &ManagedAddress) -> bool {
+        let mut current_address = address.clone();
+
+        for _ in 0..=MAX_UPLINE_DEPTH {
+            if current_address == *target_address {
+                return true;
+            }
+
+            if self.referrer_mapper(&current_address).is_empty() {
+                return false;
+            }
+
+            current_address = self.referrer_mapper(&current_address).get();
+        }
+
+        return true;
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Referrer of an address, registered once
+    #[storage_mapper("referrer")]
+    fn referrer_mapper(&self, address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    // Number of referrals per affiliate and per level (1 or 2)
+    #[storage_mapper("nb_referrals")]
+    fn nb_referrals_mapper(&self, address: &ManagedAddress, level: u8) -> SingleValueMapper<u32>;
+
+    // Lifetime referral rewards per affiliate and per level (1 or 2)
+    #[storage_mapper("referral_rewards_earned")]
+    fn referral_rewards_earned_mapper(&self, address: &ManagedAddress, level: u8) -> SingleValueMapper<BigUint>;
+}
\ No newline at end of file
@@ -2,6 +2,7 @@ elrond_wasm::imports!();
 
 use super::instance;
 use super::instance::InstanceStatus;
+use super::bond;
 
 use super::event;
 
@@ -11,6 +12,7 @@ use super::event;
 #[elrond_wasm::module]
 pub trait SecurityModule:
     instance::InstanceModule
+    +bond::BondModule
     +event::EventModule {
 
     /////////////////////////////////////////////////////////////////////
@@ -25,8 +27,30 @@ pub trait SecurityModule:
         let mut instance_state = self.instance_state_mapper().get(&iid).unwrap();
         
         if instance_state.disabled != disable_status {
+            let sponsor_address = self.instance_info_mapper().get(&iid).unwrap().sponsor_info.address;
+
+            // Disabled instances cannot be triggered, they are not counted in the running instances of their sponsor
+            if instance_state.winner_info.address.is_zero() == true {
+                if disable_status == true {
+                    self.nb_instances_running_mapper(sponsor_address.clone()).update(|current| *current -= 1);
+                }
+                else {
+                    self.nb_instances_running_mapper(sponsor_address.clone()).update(|current| *current += 1);
+                }
+            }
+
+            // Sponsor bond stays locked while the instance is disabled, until it is slashed
+            // A re-enabled instance locks the bond again and can be slashed again if disabled later
+            if disable_status == false {
+                self.instance_bond_slashed_mapper(iid).clear();
+                if instance_state.claimed_status == false {
+                    self.lock_instance_bond(iid, &sponsor_address);
+                }
+            }
+
             instance_state.disabled = disable_status;
             self.instance_state_mapper().insert(iid, instance_state);
+            self.refresh_status_index(iid);
 
             // Log event
             self.event_wrapper_disable_instance(iid, disable_status);
@@ -72,15 +96,33 @@ pub trait SecurityModule:
     // Queries
     /////////////////////////////////////////////////////////////////////  
     #[view(getAddrBlacklist)]
-    fn get_address_blacklist(&self) ->  MultiValueManagedVec<ManagedAddress>  {
-               
+    // Returns : next cursor (0 address when the whole blacklist has been returned), followed by up to <limit> addresses from address <cursor> (0 address to start from the first one)
+    // Cursor is a blacklisted address, so that pages are not shifted by addresses removed in between
+    // A cursor address removed from the blacklist in between is rejected, listing must restart from the first page
+    fn get_address_blacklist(&self, cursor: ManagedAddress, limit: u32) -> MultiValue2<ManagedAddress, MultiValueManagedVec<ManagedAddress>> {
+        require!(limit > 0, "Limit must be positive");
+        require!(cursor.is_zero() == true || self.address_blacklist_set_mapper().contains(&cursor) == true, "Cursor address is not blacklisted anymore");
+
         let mut address_blacklist: MultiValueManagedVec<ManagedAddress> = MultiValueManagedVec::new();
+        let mut next_cursor: ManagedAddress = ManagedAddress::zero();
+        let mut is_cursor_reached: bool = cursor.is_zero();
 
+        // Blacklist is ordered by insertion, addresses are returned from the cursor until the page is full
         for addr in self.address_blacklist_set_mapper().iter() {
-            address_blacklist.push(addr);
+            if is_cursor_reached == false {
+                is_cursor_reached = addr == cursor;
+            }
+
+            if is_cursor_reached == true {
+                if address_blacklist.len() as u32 >= limit {
+                    next_cursor = addr;
+                    break;
+                }
+                address_blacklist.push(addr);
+            }
         }
 
-        return address_blacklist;
+        return MultiValue2((next_cursor, address_blacklist));
     }
 
     /////////////////////////////////////////////////////////////////////
@@ -21,17 +21,17 @@ pub trait SponsorModule:
     // Queries
     /////////////////////////////////////////////////////////////////////
     #[view(getSponsorIDs)]
-    fn get_sponsor_instances(&self, sponsor_address: ManagedAddress) -> MultiValueManagedVec<u32> {
+    // Returns : next cursor (0 when all instances have been returned), followed by up to <limit> instances IDs of the sponsor from <cursor>, in creation order
+    fn get_sponsor_instances(&self, sponsor_address: ManagedAddress, cursor: u32, limit: u32) -> MultiValue2<u32, MultiValueManagedVec<u32>> {
+        require!(limit > 0, "Limit must be positive");
+
         let mut sponsor_iids = MultiValueManagedVec::new();
 
-        // Return all instances IDs with sponsor address matching the one provided in parameter
-        for (iid, instance_info) in self.instance_info_mapper().iter() {
-            if instance_info.sponsor_info.address.clone() == sponsor_address {
-                sponsor_iids.push(iid);
-            }
-        }
+        // Return instances IDs recorded for the sponsor address provided in parameter
+        // Sponsor index is walked from the cursor, until the page is full
+        let next_cursor: u32 = self.fill_page_from_index(&self.sponsor_iids_mapper(&sponsor_address), cursor, limit, None, &mut sponsor_iids);
 
-        return sponsor_iids;
+        return MultiValue2((next_cursor, sponsor_iids));
     }
 
     #[view(getNbSponsorRunning)]
@@ -39,9 +39,8 @@ pub trait SponsorModule:
         let mut nb_instances: u32 = 0;
 
         // Compute number of running instances for a specific sponsor
-        for (iid, instance_info) in self.instance_info_mapper().iter() {
-           
-            if instance_info.sponsor_info.address.clone() == sponsor_address && self.get_instance_status(iid) == InstanceStatus::Running {
+        for node in self.sponsor_iids_mapper(&sponsor_address).iter() {
+            if self.get_instance_status(node.get_value_cloned()) == InstanceStatus::Running {
                 nb_instances += 1;
             }
         }
@@ -52,6 +51,13 @@ pub trait SponsorModule:
     /////////////////////////////////////////////////////////////////////
     // Internal SC functions
     /////////////////////////////////////////////////////////////////////
+    fn add_sponsor_instance(&self, sponsor_address: &ManagedAddress, iid: u32) {
+        self.insert_in_index(&mut self.sponsor_iids_mapper(sponsor_address), &self.sponsor_iid_node_mapper(sponsor_address, iid), iid);
+    }
+
+    fn remove_sponsor_instance(&self, sponsor_address: &ManagedAddress, iid: u32) {
+        self.remove_from_index(&mut self.sponsor_iids_mapper(sponsor_address), &self.sponsor_iid_node_mapper(sponsor_address, iid));
+    }
 
     /////////////////////////////////////////////////////////////////////
     // Mappers
@@ -60,5 +66,13 @@ pub trait SponsorModule:
     // Number of instances with status 'Running' (or 'Ended') for one sponsor
     #[storage_mapper("nb_instances_running")]
     fn nb_instances_running_mapper(&self, sponsor_address: ManagedAddress) -> SingleValueMapper<u32>;
+
+    // Instances IDs (per sponsor)
+    #[storage_mapper("sponsor_iids")]
+    fn sponsor_iids_mapper(&self, sponsor_address: &ManagedAddress) -> LinkedListMapper<u32>;
+
+    // Node of the instance in the sponsor index (per sponsor & instance)
+    #[storage_mapper("sponsor_iid_node")]
+    fn sponsor_iid_node_mapper(&self, sponsor_address: &ManagedAddress, iid: u32) -> SingleValueMapper<u32>;
     
 }
\ No newline at end of file
@@ -0,0 +1,124 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+
+// Data format for statistics view
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct GetStatsStruct<M: ManagedTypeApi> {
+    pub nb_instances_created: u32,
+    pub nb_plays: u32,
+    pub fees_collected: BigUint<M>,
+    pub fees_claimed: BigUint<M>,
+    pub link_rewards_paid: BigUint<M>,
+    pub donations: BigUint<M>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+#[elrond_wasm::module]
+pub trait StatsModule {
+
+    /////////////////////////////////////////////////////////////////////
+    // Endpoints
+    /////////////////////////////////////////////////////////////////////
+
+    /////////////////////////////////////////////////////////////////////
+    // Queries
+    /////////////////////////////////////////////////////////////////////
+    #[view(getStats)]
+    // Returns : lifetime statistics, followed by prizes distributed per token identifier
+    fn get_stats(&self) -> MultiValue2<GetStatsStruct<Self::Api>, MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>>> {
+        let mut prizes_distributed: MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>> = MultiValueEncoded::new();
+
+        for (token_identifier, amount) in self.stats_prizes_distributed_mapper().iter() {
+            prizes_distributed.push(MultiValue2((token_identifier, amount)));
+        }
+
+        let stats = GetStatsStruct {
+            nb_instances_created: self.stats_nb_instances_created_mapper().get(),
+            nb_plays: self.stats_nb_plays_mapper().get(),
+            fees_collected: self.stats_fees_collected_mapper().get(),
+            fees_claimed: self.stats_fees_claimed_mapper().get(),
+            link_rewards_paid: self.stats_link_rewards_paid_mapper().get(),
+            donations: self.stats_donations_mapper().get(),
+        };
+
+        return MultiValue2((stats, prizes_distributed));
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Internal SC functions
+    /////////////////////////////////////////////////////////////////////
+
+    // Counters of instances & plays are initialized from the instances existing at upgrade
+    fn init_stats_if_empty(&self, nb_instances_created: u32, nb_plays: u32) {
+        self.stats_nb_instances_created_mapper().set_if_empty(&nb_instances_created);
+        self.stats_nb_plays_mapper().set_if_empty(&nb_plays);
+    }
+
+    fn record_instance_created(&self) {
+        self.stats_nb_instances_created_mapper().update(|current_nb_instances| *current_nb_instances += 1);
+    }
+
+    // Plays of instances not migrated yet are counted by the migration
+    fn record_play(&self, fees: &BigUint, is_play_counted: bool) {
+        if is_play_counted == true {
+            self.stats_nb_plays_mapper().update(|current_nb_plays| *current_nb_plays += 1);
+        }
+        self.stats_fees_collected_mapper().update(|current_fees| *current_fees += fees);
+    }
+
+    fn record_fees_claimed(&self, amount: &BigUint) {
+        self.stats_fees_claimed_mapper().update(|current_fees| *current_fees += amount);
+    }
+
+    fn record_link_rewards_paid(&self, amount: &BigUint) {
+        self.stats_link_rewards_paid_mapper().update(|current_rewards| *current_rewards += amount);
+    }
+
+    fn record_donation(&self, amount: &BigUint) {
+        self.stats_donations_mapper().update(|current_donations| *current_donations += amount);
+    }
+
+    fn record_prize_distributed(&self, token_identifier: &TokenIdentifier, amount: &BigUint) {
+        let mut current_amount: BigUint = self.stats_prizes_distributed_mapper().get(token_identifier).unwrap_or_else(|| BigUint::zero());
+        current_amount += amount;
+        self.stats_prizes_distributed_mapper().insert(token_identifier.clone(), current_amount);
+    }
+
+    /////////////////////////////////////////////////////////////////////
+    // Mappers
+    /////////////////////////////////////////////////////////////////////
+
+    // Lifetime number of instances created
+    #[storage_mapper("stats_nb_instances_created")]
+    fn stats_nb_instances_created_mapper(&self) -> SingleValueMapper<u32>;
+
+    // Lifetime number of plays
+    #[storage_mapper("stats_nb_plays")]
+    fn stats_nb_plays_mapper(&self) -> SingleValueMapper<u32>;
+
+    // Lifetime fees paid by players
+    #[storage_mapper("stats_fees_collected")]
+    fn stats_fees_collected_mapper(&self) -> SingleValueMapper<BigUint>;
+
+    // Lifetime fees claimed by fee beneficiaries
+    #[storage_mapper("stats_fees_claimed")]
+    fn stats_fees_claimed_mapper(&self) -> SingleValueMapper<BigUint>;
+
+    // Lifetime link rewards claimed by referrers
+    #[storage_mapper("stats_link_rewards_paid")]
+    fn stats_link_rewards_paid_mapper(&self) -> SingleValueMapper<BigUint>;
+
+    // Lifetime donations
+    #[storage_mapper("stats_donations")]
+    fn stats_donations_mapper(&self) -> SingleValueMapper<BigUint>;
+
+    // Lifetime prizes & jackpots sent to winners (per token identifier), prizes given back to sponsors excluded
+    #[storage_mapper("stats_prizes_distributed")]
+    fn stats_prizes_distributed_mapper(&self) -> MapMapper<TokenIdentifier, BigUint>;
+}
\ No newline at end of file
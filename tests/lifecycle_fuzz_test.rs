@@ -0,0 +1,505 @@
+use elrond_wasm::types::{Address, BigUint, ManagedAddress, ManagedBuffer, MultiValueManagedVec, OptionalValue, TokenIdentifier};
+use elrond_wasm_debug::num_bigint::BigUint as RustBigUint;
+use elrond_wasm_debug::testing_framework::{BlockchainStateWrapper, ContractObjWrapper, StateChange};
+use elrond_wasm_debug::DebugApi;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use prize::charity::CharityModule;
+use prize::fee::FeeModule;
+use prize::instance::InstanceModule;
+use prize::jackpot::JackpotModule;
+use prize::parameter::ParameterModule;
+use prize::player::PlayerModule;
+use prize::security::SecurityModule;
+use prize::sponsor::SponsorModule;
+use prize::Prize;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const MILLI_EGLD: u64 = 1_000_000_000_000_000;
+const ESDT_TOKEN: &[u8] = b"MEX-abcdef";
+const SFT_TOKEN: &[u8] = b"SFT-123456";
+const SFT_NONCE: u64 = 1;
+const NB_SPONSORS: usize = 2;
+const NB_PLAYERS: usize = 4;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+type PrizeContract = ContractObjWrapper<prize::ContractObj<DebugApi>, fn() -> prize::ContractObj<DebugApi>>;
+
+#[derive(Debug, Clone, Copy)]
+enum PrizeToken {
+    Egld,
+    Esdt,
+    Sft,
+    // Prize funded by the ticket pot
+    Pot,
+}
+
+// Instances & callers are picked by index, modulo the existing ones, so that shrinking keeps sequences valid
+#[derive(Debug, Clone)]
+enum Action {
+    Create { sponsor: usize, token: PrizeToken, amount: u64, duration_in_s: u64, charity: bool },
+    Play { player: usize, target: usize, link: Option<usize>, donation: u64 },
+    Forward { seconds: u64 },
+    Trigger { caller: usize, target: usize, seed: u64 },
+    ClaimPrize { caller: usize, target: usize },
+    Disable { target: usize, disable_status: bool },
+    SetFeePol { fee: u64, sponsor_reward_percent: u8, link_reward_percent: u8, link2_reward_percent: u8 },
+    ClaimFees,
+    ClaimLinkRewards { caller: usize },
+    ClaimDonations,
+    ClaimCharityDonations,
+    CleanClaimed,
+}
+
+// Contract storage converted to plain Rust types
+struct InstanceSnapshot {
+    iid: u32,
+    sponsor: Address,
+    triggered: bool,
+    claimed: bool,
+    disabled: bool,
+    reward_pool: RustBigUint,
+    prize_token: Vec<u8>,
+    prize_nonce: u64,
+    prize_amount: RustBigUint,
+    winner: Address,
+    winning_ticket: usize,
+    tickets: Vec<Address>,
+}
+
+struct ContractSnapshot {
+    instances: Vec<InstanceSnapshot>,
+    // Fee, link reward, jackpot & charity pools
+    pools: RustBigUint,
+    nb_instances_running: Vec<(Address, u32)>,
+}
+
+struct Harness {
+    blockchain: BlockchainStateWrapper,
+    contract: PrizeContract,
+    owner: Address,
+    sponsors: Vec<Address>,
+    players: Vec<Address>,
+    charity: Address,
+    timestamp: u64,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+fn to_rust_biguint(amount: &BigUint<DebugApi>) -> RustBigUint {
+    return RustBigUint::from_bytes_be(amount.to_bytes_be().as_slice());
+}
+
+fn egld(milli_egld: u64) -> RustBigUint {
+    return RustBigUint::from(milli_egld) * RustBigUint::from(MILLI_EGLD);
+}
+
+fn state_change<T, E>(result: Result<T, E>, committed: &mut bool) -> StateChange {
+    *committed = result.is_ok();
+
+    if *committed == true {
+        return StateChange::Commit;
+    }
+    return StateChange::Revert;
+}
+
+fn empty_buffer() -> ManagedBuffer<DebugApi> {
+    return ManagedBuffer::new();
+}
+
+fn contract_snapshot(sc: &prize::ContractObj<DebugApi>, addresses: &[Address], sponsors: &[Address]) -> ContractSnapshot {
+    let mut instances: Vec<InstanceSnapshot> = Vec::new();
+
+    for (iid, instance_info) in sc.instance_info_mapper().iter() {
+        let instance_state = sc.instance_state_mapper().get(&iid).unwrap();
+        let prize_info = sc.get_prize_info(iid);
+
+        instances.push(InstanceSnapshot {
+            iid: iid,
+            sponsor: instance_info.sponsor_info.address.to_address(),
+            triggered: instance_state.winner_info.address.is_zero() == false,
+            claimed: instance_state.claimed_status,
+            disabled: instance_state.disabled,
+            reward_pool: to_rust_biguint(&instance_state.reward_info.pool),
+            prize_token: prize_info.token_identifier.as_managed_buffer().to_boxed_bytes().into_vec(),
+            prize_nonce: prize_info.token_nonce,
+            prize_amount: to_rust_biguint(&prize_info.token_amount),
+            winner: instance_state.winner_info.address.to_address(),
+            winning_ticket: instance_state.winner_info.ticket_number,
+            tickets: sc.instance_players_vec_mapper(iid).iter().map(|player_address| player_address.to_address()).collect(),
+        });
+    }
+
+    let mut pools: RustBigUint = to_rust_biguint(&sc.fee_pool_mapper().get());
+    pools += to_rust_biguint(&sc.jackpot_pool_mapper().get());
+    pools += to_rust_biguint(&sc.charity_pool_mapper().get());
+    for charity_id in 1..=sc.charity_id_counter_mapper().get() {
+        pools += to_rust_biguint(&sc.charity_balance_mapper(charity_id).get());
+    }
+    for address in addresses.iter() {
+        let managed_address = ManagedAddress::<DebugApi>::from_address(address);
+        pools += to_rust_biguint(&sc.fee_balance_mapper(&managed_address).get());
+        pools += to_rust_biguint(&sc.link_reward_pool_mapper(&managed_address).get());
+    }
+
+    let nb_instances_running: Vec<(Address, u32)> = sponsors
+        .iter()
+        .map(|sponsor| (sponsor.clone(), sc.nb_instances_running_mapper(ManagedAddress::from_address(sponsor)).get()))
+        .collect();
+
+    return ContractSnapshot { instances: instances, pools: pools, nb_instances_running: nb_instances_running };
+}
+
+impl Harness {
+    fn new(manual_claim: bool) -> Self {
+        let mut blockchain = BlockchainStateWrapper::new();
+        let owner = blockchain.create_user_account(&egld(100_000));
+        let sponsors: Vec<Address> = (0..NB_SPONSORS).map(|_| blockchain.create_user_account(&egld(100_000))).collect();
+        let players: Vec<Address> = (0..NB_PLAYERS).map(|_| blockchain.create_user_account(&egld(100_000))).collect();
+        let charity = blockchain.create_user_account(&RustBigUint::from(0u32));
+        let contract: PrizeContract = blockchain.create_sc_account(&RustBigUint::from(0u32), Some(&owner), prize::contract_obj as fn() -> prize::ContractObj<DebugApi>, "output/prize.wasm");
+
+        for sponsor in sponsors.iter() {
+            blockchain.set_esdt_balance(sponsor, ESDT_TOKEN, &RustBigUint::from(1_000_000u32));
+            blockchain.set_nft_balance(sponsor, SFT_TOKEN, SFT_NONCE, &RustBigUint::from(1_000u32), &0u64);
+        }
+
+        // Deploy with fees, a charity & a jackpot, so that every pool gets fed
+        let mut harness = Harness { blockchain: blockchain, contract: contract, owner: owner, sponsors: sponsors, players: players, charity: charity, timestamp: 0 };
+        let charity_address = harness.charity.clone();
+        let mut committed = false;
+        let _ = harness.blockchain.execute_tx(&harness.owner, &harness.contract, &RustBigUint::from(0u32), |sc| {
+            let mut result = sc.init();
+            if result.is_ok() {
+                result = sc.set_fee_policy(BigUint::from(10 * MILLI_EGLD), 10, 10, 5);
+            }
+            if result.is_ok() {
+                result = sc.set_jackpot_params(10, 2_500);
+            }
+            if result.is_ok() {
+                result = sc.set_param_manual_claim(manual_claim);
+            }
+            if result.is_ok() {
+                result = sc.add_charity(ManagedAddress::from_address(&charity_address), ManagedBuffer::from(&b"charity"[..]), empty_buffer()).into_tuple().0;
+            }
+            return state_change(result, &mut committed);
+        });
+        assert!(committed == true, "Contract setup failed");
+
+        return harness;
+    }
+
+    // Owner, sponsors, players then charity
+    fn addresses(&self) -> Vec<Address> {
+        let mut addresses: Vec<Address> = vec![self.owner.clone()];
+        addresses.extend(self.sponsors.iter().cloned());
+        addresses.extend(self.players.iter().cloned());
+        addresses.push(self.charity.clone());
+        return addresses;
+    }
+
+    fn snapshot(&mut self) -> ContractSnapshot {
+        let addresses = self.addresses();
+        let sponsors = self.sponsors.clone();
+        let mut snapshot: Option<ContractSnapshot> = None;
+
+        let _ = self.blockchain.execute_query(&self.contract, |sc| {
+            snapshot = Some(contract_snapshot(&sc, &addresses, &sponsors));
+        });
+
+        return snapshot.unwrap();
+    }
+
+    // Returns : IID picked among the created instances, or the next IID so that missing instances are also covered
+    fn pick_iid(&mut self, target: usize) -> u32 {
+        let mut iid_counter: u32 = 0;
+
+        let _ = self.blockchain.execute_query(&self.contract, |sc| {
+            iid_counter = sc.iid_counter_mapper().get();
+        });
+
+        return (target % (iid_counter as usize + 1)) as u32 + 1;
+    }
+
+    // Returns : true if the transaction has been committed
+    fn apply(&mut self, action: &Action) -> bool {
+        let mut committed = false;
+        let zero = RustBigUint::from(0u32);
+
+        match action.clone() {
+            Action::Create { sponsor, token, amount, duration_in_s, charity } => {
+                let sponsor = self.sponsors[sponsor % NB_SPONSORS].clone();
+                let charity_id: u32 = if charity == true { 1 } else { 0 };
+                let create = |sc: prize::ContractObj<DebugApi>, token_identifier: TokenIdentifier<DebugApi>, nonce: u64, token_amount: BigUint<DebugApi>, committed: &mut bool| {
+                    let result = sc.create_instance(token_identifier, nonce, token_amount, duration_in_s, ManagedBuffer::from(&b"sponsor"[..]), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), false, charity_id);
+                    return state_change(result.into_tuple().0, committed);
+                };
+
+                match token {
+                    PrizeToken::Egld => {
+                        let _ = self.blockchain.execute_tx(&sponsor, &self.contract, &egld(amount), |sc| {
+                            return create(sc, TokenIdentifier::egld(), 0, BigUint::from(amount) * BigUint::from(MILLI_EGLD), &mut committed);
+                        });
+                    },
+                    PrizeToken::Esdt => {
+                        let _ = self.blockchain.execute_esdt_transfer(&sponsor, &self.contract, ESDT_TOKEN, 0, &RustBigUint::from(amount), |sc| {
+                            return create(sc, TokenIdentifier::from(ESDT_TOKEN), 0, BigUint::from(amount), &mut committed);
+                        });
+                    },
+                    PrizeToken::Sft => {
+                        let _ = self.blockchain.execute_esdt_transfer(&sponsor, &self.contract, SFT_TOKEN, SFT_NONCE, &RustBigUint::from(amount), |sc| {
+                            return create(sc, TokenIdentifier::from(SFT_TOKEN), SFT_NONCE, BigUint::from(amount), &mut committed);
+                        });
+                    },
+                    PrizeToken::Pot => {
+                        let _ = self.blockchain.execute_tx(&sponsor, &self.contract, &zero, |sc| {
+                            let result = sc.create_pot_instance(duration_in_s, ManagedBuffer::from(&b"sponsor"[..]), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), false, charity_id);
+                            return state_change(result.into_tuple().0, &mut committed);
+                        });
+                    },
+                }
+            },
+            Action::Play { player, target, link, donation } => {
+                let iid = self.pick_iid(target);
+                let player_address = self.players[player % NB_PLAYERS].clone();
+                let link_address: Option<Address> = link.map(|link| self.players[link % NB_PLAYERS].clone());
+
+                // Pay the fees of the instance fee policy plus the optional donation
+                let mut fee_amount = zero.clone();
+                let _ = self.blockchain.execute_query(&self.contract, |sc| {
+                    if let Some(fee_policy) = sc.instance_fee_policy_mapper().get(&iid) {
+                        fee_amount = to_rust_biguint(&fee_policy.fee_amount_egld);
+                    }
+                });
+                let payment: RustBigUint = fee_amount + egld(donation);
+
+                let _ = self.blockchain.execute_tx(&player_address, &self.contract, &payment, |sc| {
+                    let link_address = match link_address {
+                        Some(address) => OptionalValue::Some(ManagedAddress::from_address(&address)),
+                        None => OptionalValue::None,
+                    };
+                    let result = sc.play(BigUint::from_bytes_be(payment.to_bytes_be().as_slice()), iid, link_address);
+                    return state_change(result.into_tuple().0, &mut committed);
+                });
+            },
+            Action::Forward { seconds } => {
+                self.timestamp += seconds;
+                self.blockchain.set_block_timestamp(self.timestamp);
+                committed = true;
+            },
+            Action::Trigger { caller, target, seed } => {
+                let iid = self.pick_iid(target);
+                let caller = self.addresses()[caller % (1 + NB_SPONSORS + NB_PLAYERS)].clone();
+
+                let mut random_seed = [0u8; 48];
+                for (index, byte) in random_seed.iter_mut().enumerate() {
+                    *byte = seed.rotate_left(index as u32 * 8) as u8;
+                }
+                self.blockchain.set_block_random_seed(Box::new(random_seed));
+
+                let _ = self.blockchain.execute_tx(&caller, &self.contract, &zero, |sc| {
+                    return state_change(sc.trigger(iid), &mut committed);
+                });
+            },
+            Action::ClaimPrize { caller, target } => {
+                let iid = self.pick_iid(target);
+                let caller = self.addresses()[caller % (1 + NB_SPONSORS + NB_PLAYERS)].clone();
+
+                let _ = self.blockchain.execute_tx(&caller, &self.contract, &zero, |sc| {
+                    return state_change(sc.claim_prize(iid), &mut committed);
+                });
+            },
+            Action::Disable { target, disable_status } => {
+                let iid = self.pick_iid(target);
+
+                let _ = self.blockchain.execute_tx(&self.owner, &self.contract, &zero, |sc| {
+                    return state_change(sc.disable_instance(iid, disable_status), &mut committed);
+                });
+            },
+            Action::SetFeePol { fee, sponsor_reward_percent, link_reward_percent, link2_reward_percent } => {
+                let _ = self.blockchain.execute_tx(&self.owner, &self.contract, &zero, |sc| {
+                    let fee_amount = BigUint::from(fee) * BigUint::from(MILLI_EGLD);
+                    return state_change(sc.set_fee_policy(fee_amount, sponsor_reward_percent, link_reward_percent, link2_reward_percent), &mut committed);
+                });
+            },
+            Action::ClaimFees => {
+                let _ = self.blockchain.execute_tx(&self.owner, &self.contract, &zero, |sc| {
+                    return state_change(sc.claim_fees(), &mut committed);
+                });
+            },
+            Action::ClaimLinkRewards { caller } => {
+                let caller = self.players[caller % NB_PLAYERS].clone();
+
+                let _ = self.blockchain.execute_tx(&caller, &self.contract, &zero, |sc| {
+                    return state_change(sc.claim_link_rewards(), &mut committed);
+                });
+            },
+            Action::ClaimDonations => {
+                let _ = self.blockchain.execute_tx(&self.owner, &self.contract, &zero, |sc| {
+                    return state_change(sc.claim_donations(), &mut committed);
+                });
+            },
+            Action::ClaimCharityDonations => {
+                let charity = self.charity.clone();
+
+                let _ = self.blockchain.execute_tx(&charity, &self.contract, &zero, |sc| {
+                    return state_change(sc.claim_charity_donations(1), &mut committed);
+                });
+            },
+            Action::CleanClaimed => {
+                let _ = self.blockchain.execute_tx(&self.owner, &self.contract, &zero, |sc| {
+                    return state_change(sc.clean_claimed_instances(MultiValueManagedVec::new()), &mut committed);
+                });
+            },
+        }
+
+        return committed;
+    }
+
+    fn check_invariants(&mut self, step: &str) {
+        let snapshot = self.snapshot();
+        let contract_address = self.contract.address_ref().clone();
+
+        // Contract balances equal escrowed prizes plus the fee, link, reward & charity pools
+        let mut expected_egld: RustBigUint = snapshot.pools.clone();
+        let mut expected_esdt = RustBigUint::from(0u32);
+        let mut expected_sft = RustBigUint::from(0u32);
+
+        for instance in snapshot.instances.iter() {
+            // Reward pool is paid to the sponsor or donated at trigger
+            if instance.triggered == false {
+                expected_egld += instance.reward_pool.clone();
+            }
+
+            // Prize is escrowed until claimed, including while the instance is disabled
+            if instance.claimed == false {
+                match (instance.prize_token.as_slice(), instance.prize_nonce) {
+                    (b"EGLD", 0) => expected_egld += instance.prize_amount.clone(),
+                    (ESDT_TOKEN, 0) => expected_esdt += instance.prize_amount.clone(),
+                    (SFT_TOKEN, SFT_NONCE) => expected_sft += instance.prize_amount.clone(),
+                    (token, nonce) => panic!("Unexpected prize token {:?}-{} after {}", token, nonce, step),
+                }
+            }
+        }
+
+        assert_eq!(self.blockchain.get_egld_balance(&contract_address), expected_egld, "EGLD balance mismatch after {}", step);
+        assert_eq!(self.blockchain.get_esdt_balance(&contract_address, ESDT_TOKEN, 0), expected_esdt, "ESDT balance mismatch after {}", step);
+        assert_eq!(self.blockchain.get_esdt_balance(&contract_address, SFT_TOKEN, SFT_NONCE), expected_sft, "SFT balance mismatch after {}", step);
+
+        // Running instances are counted from creation to trigger, disabled instances excluded
+        for (sponsor, nb_instances_running) in snapshot.nb_instances_running.iter() {
+            let nb_not_triggered = snapshot.instances.iter().filter(|instance| instance.sponsor == *sponsor && instance.triggered == false && instance.disabled == false).count() as u32;
+            assert_eq!(*nb_instances_running, nb_not_triggered, "nb_instances_running mismatch after {}", step);
+        }
+
+        // Exactly one winner per triggered instance
+        for instance in snapshot.instances.iter() {
+            if instance.triggered == false {
+                assert!(instance.claimed == false && instance.winning_ticket == 0, "Instance {} has a winner but is not triggered after {}", instance.iid, step);
+            }
+            else if instance.tickets.is_empty() {
+                assert!(instance.winner == instance.sponsor && instance.winning_ticket == 0, "Instance {} without player is not won by its sponsor after {}", instance.iid, step);
+            }
+            else {
+                assert!(instance.winning_ticket >= 1 && instance.winning_ticket <= instance.tickets.len(), "Instance {} has an invalid winning ticket after {}", instance.iid, step);
+                assert!(instance.tickets[instance.winning_ticket - 1] == instance.winner, "Instance {} winner does not own the winning ticket after {}", instance.iid, step);
+                assert_eq!(instance.tickets.iter().filter(|player| **player == instance.winner).count(), 1, "Instance {} winner holds several tickets after {}", instance.iid, step);
+            }
+        }
+    }
+
+    fn run(&mut self, actions: &[Action]) {
+        self.check_invariants("setup");
+
+        for action in actions.iter() {
+            self.apply(action);
+            self.check_invariants(&format!("{:?}", action));
+        }
+    }
+}
+
+fn prize_token() -> impl Strategy<Value = PrizeToken> {
+    return prop_oneof![Just(PrizeToken::Egld), Just(PrizeToken::Esdt), Just(PrizeToken::Sft), Just(PrizeToken::Pot)];
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    return prop_oneof![
+        3 => (any::<usize>(), prize_token(), 1..5u64, 60..300u64, any::<bool>())
+            .prop_map(|(sponsor, token, amount, duration_in_s, charity)| Action::Create { sponsor: sponsor, token: token, amount: amount, duration_in_s: duration_in_s, charity: charity }),
+        6 => (any::<usize>(), any::<usize>(), proptest::option::of(any::<usize>()), prop_oneof![Just(0u64), 1..20u64])
+            .prop_map(|(player, target, link, donation)| Action::Play { player: player, target: target, link: link, donation: donation }),
+        3 => (1..200u64).prop_map(|seconds| Action::Forward { seconds: seconds }),
+        3 => (any::<usize>(), any::<usize>(), any::<u64>()).prop_map(|(caller, target, seed)| Action::Trigger { caller: caller, target: target, seed: seed }),
+        2 => (any::<usize>(), any::<usize>()).prop_map(|(caller, target)| Action::ClaimPrize { caller: caller, target: target }),
+        1 => (any::<usize>(), any::<bool>()).prop_map(|(target, disable_status)| Action::Disable { target: target, disable_status: disable_status }),
+        1 => (0..50u64, 0..60u8, 0..40u8, 0..20u8).prop_map(|(fee, sponsor_reward_percent, link_reward_percent, link2_reward_percent)| Action::SetFeePol {
+            fee: fee,
+            sponsor_reward_percent: sponsor_reward_percent,
+            link_reward_percent: link_reward_percent,
+            link2_reward_percent: link2_reward_percent
+        }),
+        1 => Just(Action::ClaimFees),
+        1 => any::<usize>().prop_map(|caller| Action::ClaimLinkRewards { caller: caller }),
+        1 => Just(Action::ClaimDonations),
+        1 => Just(Action::ClaimCharityDonations),
+        1 => Just(Action::CleanClaimed),
+    ];
+}
+
+// Every endpoint of the lifecycle succeeds at least once, the harness does not only exercise failing calls
+#[test]
+fn lifecycle_endpoints_succeed() {
+    let mut harness = Harness::new(true);
+    let actions: Vec<Action> = vec![
+        Action::Create { sponsor: 0, token: PrizeToken::Egld, amount: 2, duration_in_s: 60, charity: false },
+        Action::Create { sponsor: 1, token: PrizeToken::Pot, amount: 0, duration_in_s: 60, charity: true },
+        Action::Play { player: 0, target: 0, link: None, donation: 0 },
+        Action::Play { player: 1, target: 0, link: Some(0), donation: 0 },
+        Action::Play { player: 2, target: 0, link: Some(1), donation: 0 },
+        Action::Play { player: 3, target: 1, link: None, donation: 5 },
+        Action::Forward { seconds: 61 },
+        Action::Trigger { caller: 0, target: 0, seed: 7 },
+        Action::Trigger { caller: 2, target: 1, seed: 11 },
+        Action::ClaimFees,
+        Action::ClaimLinkRewards { caller: 0 },
+        Action::ClaimCharityDonations,
+        Action::SetFeePol { fee: 1, sponsor_reward_percent: 0, link_reward_percent: 0, link2_reward_percent: 0 },
+        Action::Create { sponsor: 0, token: PrizeToken::Esdt, amount: 100, duration_in_s: 60, charity: false },
+        Action::Disable { target: 2, disable_status: true },
+        Action::Disable { target: 2, disable_status: false },
+    ];
+
+    harness.check_invariants("setup");
+    for action in actions.iter() {
+        assert!(harness.apply(action), "{:?} failed", action);
+        harness.check_invariants(&format!("{:?}", action));
+    }
+
+    // Winners claim their prize, then claimed instances are cleaned
+    let snapshot = harness.snapshot();
+    for instance in snapshot.instances.iter().filter(|instance| instance.triggered == true) {
+        let caller = harness.addresses().iter().position(|address| *address == instance.winner).unwrap();
+        let claim = Action::ClaimPrize { caller: caller, target: instance.iid as usize - 1 };
+        assert!(harness.apply(&claim), "{:?} failed", claim);
+        harness.check_invariants(&format!("{:?}", claim));
+    }
+    assert!(harness.apply(&Action::CleanClaimed));
+    harness.check_invariants("CleanClaimed");
+    assert_eq!(harness.snapshot().instances.len(), 1);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    // Randomized sequences of lifecycle calls, failing calls are reverted and must keep the invariants too
+    #[test]
+    fn lifecycle_keeps_invariants(manual_claim in any::<bool>(), actions in vec(action(), 1..80)) {
+        Harness::new(manual_claim).run(&actions);
+    }
+}
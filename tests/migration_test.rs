@@ -0,0 +1,113 @@
+use elrond_wasm::types::{Address, BigUint, ManagedAddress, ManagedBuffer, OptionalValue, TokenIdentifier};
+use elrond_wasm_debug::num_bigint::BigUint as RustBigUint;
+use elrond_wasm_debug::testing_framework::{BlockchainStateWrapper, ContractObjWrapper, StateChange};
+use elrond_wasm_debug::DebugApi;
+
+use prize::fee::FeeModule;
+use prize::instance::InstanceModule;
+use prize::player::PlayerModule;
+use prize::Prize;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const MILLI_EGLD: u64 = 1_000_000_000_000_000;
+const FEES_MILLI_EGLD: u64 = 10;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+type PrizeContract = ContractObjWrapper<prize::ContractObj<DebugApi>, fn() -> prize::ContractObj<DebugApi>>;
+
+struct LegacySetup {
+    blockchain: BlockchainStateWrapper,
+    contract: PrizeContract,
+    owner: Address,
+    players: Vec<Address>,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+fn egld(milli_egld: u64) -> RustBigUint {
+    return RustBigUint::from(milli_egld) * RustBigUint::from(MILLI_EGLD);
+}
+
+fn empty_buffer() -> ManagedBuffer<DebugApi> {
+    return ManagedBuffer::new();
+}
+
+fn commit_if_ok<T, E>(result: Result<T, E>) -> StateChange {
+    assert!(result.is_ok(), "Transaction failed");
+    return StateChange::Commit;
+}
+
+// Instance #1 played by 2 players, then stored as by the contract before the upgrade :
+// no fee policy, tickets & fees recorded for the instance, and no player index
+fn legacy_setup() -> LegacySetup {
+    let mut blockchain = BlockchainStateWrapper::new();
+    let owner = blockchain.create_user_account(&egld(100_000));
+    let sponsor = blockchain.create_user_account(&egld(100_000));
+    let players: Vec<Address> = (0..2).map(|_| blockchain.create_user_account(&egld(100_000))).collect();
+    let contract: PrizeContract = blockchain.create_sc_account(&RustBigUint::from(0u32), Some(&owner), prize::contract_obj as fn() -> prize::ContractObj<DebugApi>, "output/prize.wasm");
+
+    let _ = blockchain.execute_tx(&owner, &contract, &RustBigUint::from(0u32), |sc| {
+        let _ = commit_if_ok(sc.init());
+        return commit_if_ok(sc.set_fee_policy(BigUint::from(FEES_MILLI_EGLD * MILLI_EGLD), 10, 10, 5));
+    });
+    let _ = blockchain.execute_tx(&sponsor, &contract, &egld(1_000), |sc| {
+        let result = sc.create_instance(TokenIdentifier::egld(), 0, BigUint::from(1_000u64) * BigUint::from(MILLI_EGLD), 3_600, ManagedBuffer::from(&b"sponsor"[..]), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), false, 0);
+        return commit_if_ok(result.into_tuple().0);
+    });
+    for player in players.iter() {
+        let _ = blockchain.execute_tx(player, &contract, &egld(FEES_MILLI_EGLD), |sc| {
+            let result = sc.play(BigUint::from(FEES_MILLI_EGLD * MILLI_EGLD), 1, OptionalValue::None);
+            return commit_if_ok(result.into_tuple().0);
+        });
+    }
+
+    let legacy_players = players.clone();
+    let _ = blockchain.execute_tx(&owner, &contract, &RustBigUint::from(0u32), |sc| {
+        sc.instance_fee_policy_mapper().remove(&1);
+        for player in legacy_players.iter() {
+            let player_address = ManagedAddress::from_address(player);
+            sc.remove_from_index(&mut sc.player_iids_mapper(&player_address), &sc.player_iid_node_mapper(&player_address, 1));
+            sc.instance_player_ticket_mapper(1, &player_address).clear();
+            sc.instance_player_fees_mapper(1, &player_address).clear();
+        }
+
+        // Upgrade state, with the migration left to do
+        sc.indexes_built_mapper().set(&false);
+        sc.migration_next_iid_mapper().set(&1u32);
+        sc.migration_last_iid_mapper().set(&1u32);
+        sc.migration_fee_policy_mapper().set(&sc.fee_policy_mapper().get());
+        return StateChange::Commit;
+    });
+
+    return LegacySetup { blockchain: blockchain, contract: contract, owner: owner, players: players };
+}
+
+#[test]
+fn player_history_of_legacy_instance() {
+    let mut setup = legacy_setup();
+
+    let _ = setup.blockchain.execute_tx(&setup.owner, &setup.contract, &RustBigUint::from(0u32), |sc| {
+        let (result, next_iid) = sc.migrate_indexes(10).into_tuple();
+        assert!(next_iid.into_option() == Some(0u32), "Migration is not complete");
+        return commit_if_ok(result);
+    });
+
+    // Tickets follow the play order, fees are the ones of the instance fee policy
+    for (index, player) in setup.players.iter().enumerate() {
+        let _ = setup.blockchain.execute_query(&setup.contract, |sc| {
+            let (next_cursor, _, history) = sc.get_player_history(ManagedAddress::from_address(player), 0, 10).into_tuple();
+            assert_eq!(next_cursor, 0);
+            assert_eq!(history.len(), 1);
+
+            let entry = history.iter().next().unwrap();
+            assert_eq!(entry.iid, 1);
+            assert_eq!(entry.ticket_number, index + 1);
+            assert!(entry.fees == BigUint::from(FEES_MILLI_EGLD * MILLI_EGLD), "Wrong fees for ticket {}", index + 1);
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use elrond_wasm::types::{Address, BigUint, ManagedBuffer, OptionalValue, TokenIdentifier};
+use elrond_wasm_debug::num_bigint::BigUint as RustBigUint;
+use elrond_wasm_debug::testing_framework::{BlockchainStateWrapper, ContractObjWrapper, StateChange};
+use elrond_wasm_debug::DebugApi;
+
+use prize::fee::FeeModule;
+use prize::jackpot::JackpotModule;
+use prize::stats::StatsModule;
+use prize::Prize;
+
+/////////////////////////////////////////////////////////////////////
+// Constants
+/////////////////////////////////////////////////////////////////////
+const MILLI_EGLD: u64 = 1_000_000_000_000_000;
+const FEES_MILLI_EGLD: u64 = 10;
+const PRIZE_MILLI_EGLD: u64 = 1_000;
+const DURATION_IN_S: u64 = 60;
+
+/////////////////////////////////////////////////////////////////////
+// Types
+/////////////////////////////////////////////////////////////////////
+type PrizeContract = ContractObjWrapper<prize::ContractObj<DebugApi>, fn() -> prize::ContractObj<DebugApi>>;
+
+struct StatsSetup {
+    blockchain: BlockchainStateWrapper,
+    contract: PrizeContract,
+    owner: Address,
+    player: Address,
+}
+
+/////////////////////////////////////////////////////////////////////
+// Functions
+/////////////////////////////////////////////////////////////////////
+fn egld(milli_egld: u64) -> RustBigUint {
+    return RustBigUint::from(milli_egld) * RustBigUint::from(MILLI_EGLD);
+}
+
+fn empty_buffer() -> ManagedBuffer<DebugApi> {
+    return ManagedBuffer::new();
+}
+
+fn commit_if_ok<T, E>(result: Result<T, E>) -> StateChange {
+    assert!(result.is_ok(), "Transaction failed");
+    return StateChange::Commit;
+}
+
+// EGLD instance #1 with fees & a jackpot fed by half of them, won at each trigger when <jackpot_chance> is 10000
+fn stats_setup(jackpot_chance: u32) -> StatsSetup {
+    let mut blockchain = BlockchainStateWrapper::new();
+    let owner = blockchain.create_user_account(&egld(100_000));
+    let sponsor = blockchain.create_user_account(&egld(100_000));
+    let player = blockchain.create_user_account(&egld(100_000));
+    let contract: PrizeContract = blockchain.create_sc_account(&RustBigUint::from(0u32), Some(&owner), prize::contract_obj as fn() -> prize::ContractObj<DebugApi>, "output/prize.wasm");
+
+    let _ = blockchain.execute_tx(&owner, &contract, &RustBigUint::from(0u32), |sc| {
+        let _ = commit_if_ok(sc.init());
+        let _ = commit_if_ok(sc.set_fee_policy(BigUint::from(FEES_MILLI_EGLD * MILLI_EGLD), 0, 0, 0));
+        return commit_if_ok(sc.set_jackpot_params(50, jackpot_chance));
+    });
+    let _ = blockchain.execute_tx(&sponsor, &contract, &egld(PRIZE_MILLI_EGLD), |sc| {
+        let result = sc.create_instance(TokenIdentifier::egld(), 0, BigUint::from(PRIZE_MILLI_EGLD * MILLI_EGLD), DURATION_IN_S, ManagedBuffer::from(&b"sponsor"[..]), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), empty_buffer(), false, 0);
+        return commit_if_ok(result.into_tuple().0);
+    });
+
+    return StatsSetup { blockchain: blockchain, contract: contract, owner: owner, player: player };
+}
+
+impl StatsSetup {
+    fn play(&mut self) {
+        let _ = self.blockchain.execute_tx(&self.player, &self.contract, &egld(FEES_MILLI_EGLD), |sc| {
+            let result = sc.play(BigUint::from(FEES_MILLI_EGLD * MILLI_EGLD), 1, OptionalValue::None);
+            return commit_if_ok(result.into_tuple().0);
+        });
+    }
+
+    // Prize is sent at trigger, manual claim being disabled by default
+    fn trigger(&mut self) {
+        self.blockchain.set_block_timestamp(DURATION_IN_S + 1);
+
+        let _ = self.blockchain.execute_tx(&self.owner, &self.contract, &RustBigUint::from(0u32), |sc| {
+            return commit_if_ok(sc.trigger(1));
+        });
+    }
+
+    // Returns : EGLD prizes distributed, in milli EGLD
+    fn egld_prizes_distributed(&mut self) -> u64 {
+        let mut prizes_distributed: u64 = 0;
+
+        let _ = self.blockchain.execute_query(&self.contract, |sc| {
+            if let Some(amount) = sc.stats_prizes_distributed_mapper().get(&TokenIdentifier::egld()) {
+                prizes_distributed = (amount / BigUint::from(MILLI_EGLD)).to_u64().unwrap();
+            }
+        });
+
+        return prizes_distributed;
+    }
+}
+
+#[test]
+fn count_prize_sent_to_winner() {
+    let mut setup = stats_setup(0);
+    setup.play();
+    setup.trigger();
+
+    assert_eq!(setup.egld_prizes_distributed(), PRIZE_MILLI_EGLD);
+}
+
+#[test]
+fn skip_prize_given_back_to_sponsor() {
+    let mut setup = stats_setup(0);
+    setup.trigger();
+
+    assert_eq!(setup.egld_prizes_distributed(), 0);
+}
+
+#[test]
+fn count_jackpot_sent_to_winner() {
+    let mut setup = stats_setup(10_000);
+    setup.play();
+    setup.trigger();
+
+    // Jackpot is fed by half of the fees
+    assert_eq!(setup.egld_prizes_distributed(), PRIZE_MILLI_EGLD + FEES_MILLI_EGLD / 2);
+}
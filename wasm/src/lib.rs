@@ -8,47 +8,102 @@ elrond_wasm_node::wasm_endpoints! {
     prize
     (
         addAddrBlacklist
+        addCharity
+        addPromoCodes
+        claimCharityDonations
         claimDonations
         claimFees
         claimLinkRewards
         claimPrize
         cleanClaimed
         create
+        createPot
+        depositBond
         disable
         getAddrBlacklist
+        getAffiliateInfo
         getAllInfo
         getAllInfoFrag
+        getAllSummary
+        getArchive
+        getBondPol
+        getCharities
+        getCharity
         getCharityPool
+        getFeeBalance
+        getFeeBeneficiaries
         getFeePol
         getFeePool
         getIDs
         getInfo
+        getInstanceDonations
+        getInstances
+        getJackpotParams
+        getJackpotPool
+        getJackpotWon
+        getLeaderboard
+        getLeaderboardScore
         getLinkRewardPool
+        getLogCategoryStatus
         getLogEnableStatus
+        getLoyaltyPoints
+        getLoyaltyPol
+        getLoyaltyRanking
         getNb
+        getNbPromoCodes
         getNbSponsorRunning
         getParamDuration
         getParamManClaim
         getParamNbMaxInstancesPerSponsor
+        getParamPotWinnerPercent
         getParamSponsorInfoMaxLength
+        getPlayerDonations
+        getPlayerHistory
         getPlayerIDs
+        getPromoCodeDiscount
+        getReferrer
         getRemainingTime
+        getSponsorArchive
+        getSponsorBond
         getSponsorIDs
+        getSponsorInfo
+        getStats
         getStatus
+        getSummary
+        getTicketNumber
+        getTickets
         getTotalPlayers
+        getWinnerArchive
+        getWinningTicket
+        hasDonated
         hasPlayed
         hasStatus
         hasWon
+        migrateFeePol
+        migrateIndexes
         play
+        playWithCode
+        playWithPoints
         prize
+        pushFees
         rmAddrBlacklist
+        setBondPol
+        setFeeBeneficiary
         setFeePol
+        setJackpotParams
+        setLogCategoryStatus
         setLogEnableStatus
+        setLoyaltyPol
         setParamDuration
         setParamManClaim
         setParamNbMaxInstancesPerSponsor
+        setParamPotWinnerPercent
         setParamSponsorInfoMaxLength
         setPremium
+        setReferrer
+        slashBond
+        updateCharity
+        withdrawBond
     )
 }
 